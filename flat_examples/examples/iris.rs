@@ -16,6 +16,7 @@ fn main() {
     let dataset = builder.build();
     barchart_impl_view(&parameters, &dataset);
     histogram_impl_view(&parameters, &dataset);
+    scatterplot_impl_view(&parameters, &dataset);
 }
 
 fn barchart_impl_view(parameters: &Parameters, dataset: &Dataset<FlowerSchema>) {
@@ -65,6 +66,27 @@ fn histogram_impl_view(parameters: &Parameters, dataset: &Dataset<FlowerSchema>)
     println!();
 }
 
+fn scatterplot_impl_view(parameters: &Parameters, dataset: &Dataset<FlowerSchema>) {
+    let view = SepalLengthSpeciesView {
+        dataset,
+        field: parameters.field.clone(),
+    };
+    let flat = ScatterPlot::new(&view).render(Render {
+        width_hint: 80,
+        height_hint: 24,
+        widget_config: { ScatterPlotConfig::default() },
+        ..Render::default()
+    });
+    println!(
+        "Shows the '{}' of flowers plotted against their sepal length.",
+        parameters.field
+    );
+    println!("Produced via custom implementation of a `flat::View`.");
+    println!();
+    println!("{flat}");
+    println!();
+}
+
 #[derive(Default, BlargParser)]
 struct Parameters {
     #[blarg(short = 'v')]
@@ -225,6 +247,52 @@ impl<'a> View<FlowerSchema> for SepalLengthView<'a> {
     }
 }
 
+struct SepalLengthSpeciesView<'a> {
+    dataset: &'a Dataset<FlowerSchema>,
+    field: AttributeField,
+}
+
+impl<'a> View<FlowerSchema> for SepalLengthSpeciesView<'a> {
+    type PrimaryDimension = SepalLength;
+    type BreakdownDimension = Species;
+    type DisplayDimensions = (SepalLength,);
+
+    fn dataset(&self) -> &Dataset<FlowerSchema> {
+        &self.dataset
+    }
+
+    fn value(&self, dims: &<FlowerSchema as Schema>::Dimensions) -> f64 {
+        dims.value(&self.field)
+    }
+
+    fn value_label(&self) -> String {
+        self.field.print_string()
+    }
+
+    fn primary_dim(&self, dims: &<FlowerSchema as Schema>::Dimensions) -> Self::PrimaryDimension {
+        dims.sepal_length.clone()
+    }
+
+    fn breakdown_dim(
+        &self,
+        dims: &<FlowerSchema as Schema>::Dimensions,
+    ) -> Self::BreakdownDimension {
+        dims.species.clone()
+    }
+
+    fn breakdown_label(&self) -> Option<String> {
+        Some("Species".to_string())
+    }
+
+    fn display_dims(&self, dims: &<FlowerSchema as Schema>::Dimensions) -> Self::DisplayDimensions {
+        (dims.sepal_length.clone(),)
+    }
+
+    fn display_headers(&self) -> Vec<String> {
+        vec!["Sepal Length".to_string()]
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct FlowerJson {
     sepal_length: f64,
@@ -296,6 +364,12 @@ impl Binnable for SepalLength {
     }
 }
 
+impl Coordinate for SepalLength {
+    fn coordinate(&self) -> f64 {
+        self.0 .0
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct SepalWidth(OrderedFloat<f64>);
 
@@ -117,6 +117,14 @@ pub fn minimal_precision_string(value: f64) -> String {
     }
 }
 
+/// Compute the quantile `p` of the (non-empty, sorted) `values`, using linear interpolation between the closest ranks.
+pub(crate) fn quantile(values: &[f64], p: f64) -> f64 {
+    let rank = p * (values.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    values[lower] + (values[upper] - values[lower]) * (rank - lower as f64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -248,4 +256,15 @@ mod tests {
         assert_eq!(minimal_precision_string(12345678912.0), "1.2e10");
         assert_eq!(minimal_precision_string(123456789123.0), "1.2e11");
     }
+
+    #[test]
+    fn quantiles() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(quantile(&values, 0.0), 1.0);
+        assert_eq!(quantile(&values, 0.25), 2.0);
+        assert_eq!(quantile(&values, 0.5), 3.0);
+        assert_eq!(quantile(&values, 1.0), 5.0);
+        assert_eq!(quantile(&[1.0, 2.0], 0.5), 1.5);
+        assert_eq!(quantile(&[7.0], 0.75), 7.0);
+    }
 }
@@ -0,0 +1,28 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::super::Render;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::BarChart;
+
+/// Render configuration specific to [`BarChart`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::BarChartConfig;
+/// let barchart_config = BarChartConfig {
+///     pyramid: true,
+///     ..BarChartConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct BarChartConfig {
+    /// Whether to draw a two-valued breakdown back-to-back (as a population pyramid) or not.
+    /// The primary dimension values are drawn down the center, with the bars of the first breakdown value growing to the left, and those of the second breakdown value growing to the right.
+    /// Both sides share the same scale.
+    ///
+    /// This option only takes effect when the breakdown has exactly two values, and the [`Render`] doesn't `stack_breakdown`.
+    ///
+    /// Default: `false`.
+    pub pyramid: bool,
+}
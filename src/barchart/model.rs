@@ -0,0 +1,922 @@
+use crate::aggregate::{aggregate_apply, minimal_precision_string};
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{BarChartConfig, Schema, View};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::DagChart;
+
+/// The bar-chart widget.
+///
+/// A bar-chart represents each unique value of the view's primary dimension (1st) as a single bar.
+/// Unlike the [`DagChart`], the other display dimensions are not drawn in the frame.
+/// When the breakdown dimension has exactly two values, use [`BarChartConfig::pyramid`] to draw them back-to-back.
+///
+/// See also: [`DagChart`]
+///
+/// ```
+/// use flat::*;
+///
+/// let schema = Schemas::two("Animal", "Size");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("whale".to_string(), "large".to_string()))
+///     .add(("shark".to_string(), "medium".to_string()))
+///     .add(("shark".to_string(), "small".to_string()))
+///     .add(("tiger".to_string(), "medium".to_string()))
+///     .add(("tiger".to_string(), "medium".to_string()))
+///     .add(("tiger".to_string(), "small".to_string()))
+///     .build();
+/// let view = dataset.count();
+/// let flat = BarChart::new(&view)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Animal  |Sum(Count)
+/// shark   |**
+/// tiger   |***
+/// whale   |*"#);
+/// ```
+pub struct BarChart<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> BarChart<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+    <V as View<S>>::BreakdownDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+{
+    /// Construct a bar-chart widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this bar-chart.
+    pub fn render(self, config: Render<BarChartConfig>) -> Flat {
+        let mut aggregate_values: HashMap<(V::PrimaryDimension, V::BreakdownDimension), Vec<f64>> =
+            HashMap::default();
+        let mut sort_primaries: Vec<V::PrimaryDimension> = Vec::default();
+        let mut sort_breakdowns: Vec<V::BreakdownDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let value = self.view.value(dims);
+            let primary_dim = self.view.primary_dim(dims);
+            let breakdown_dim = self.view.breakdown_dim(dims);
+            let aggregate_dims = (primary_dim.clone(), breakdown_dim.clone());
+            let values = aggregate_values.entry(aggregate_dims).or_default();
+            values.push(value);
+
+            if !sort_primaries.contains(&primary_dim) {
+                sort_primaries.push(primary_dim);
+            }
+
+            if !sort_breakdowns.contains(&breakdown_dim) {
+                sort_breakdowns.push(breakdown_dim);
+            }
+        }
+
+        sort_primaries.sort();
+        sort_breakdowns.sort();
+
+        if config.widget_config.pyramid
+            && !config.stack_breakdown
+            && self.view.breakdown_label().is_some()
+            && sort_breakdowns.len() == 2
+        {
+            return self.render_pyramid(config, aggregate_values, sort_primaries, sort_breakdowns);
+        }
+
+        let mut columns = Columns::default();
+        // primary value
+        columns.push(Column::string(Alignment::Left));
+
+        if config.show_aggregate {
+            // spacer " "
+            columns.push(Column::string(Alignment::Center));
+            // total left [
+            columns.push(Column::string(Alignment::Left));
+            // total value
+            columns.push(Column::string(Alignment::Right));
+            // total right ]
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // rendering delimiter |
+        columns.push(Column::string(Alignment::Center));
+
+        if self.view.breakdown_label().is_some() {
+            for i in 0..sort_breakdowns.len() {
+                // aggregate count
+                columns.push(Column::breakdown(Alignment::Center));
+
+                if i + 1 < sort_breakdowns.len() {
+                    // spacer " "
+                    columns.push(Column::string(Alignment::Left));
+                }
+            }
+
+            // breakdown right |
+            columns.push(Column::string(Alignment::Center));
+        } else {
+            // aggregate count
+            columns.push(Column::count(Alignment::Left));
+        }
+
+        let mut grid = Grid::new(columns);
+
+        if let Some(breakdown_header) = self.view.breakdown_label() {
+            let value_label = self.view.value_label();
+
+            if value_label == breakdown_header {
+                let pre_header = build_preheader(&config, &breakdown_header, true);
+                grid.add(pre_header);
+            } else {
+                let pre_header1 = build_preheader(&config, &breakdown_header, false);
+                grid.add(pre_header1);
+                let pre_header2 = build_preheader(&config, &value_label, true);
+                grid.add(pre_header2);
+            }
+        }
+
+        let mut header = Row::default();
+        header.label(self.view.display_headers()[0].clone());
+        header.push(Value::String(self.view.display_headers()[0].clone()));
+
+        if config.show_aggregate {
+            header.push(Value::Empty);
+            header.push(Value::Overflow(config.aggregate.to_string()));
+            header.push(Value::Skip);
+            header.push(Value::Skip);
+        }
+
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("|".to_string()));
+
+        if self.view.breakdown_label().is_some() {
+            for (k, breakdown_dim) in sort_breakdowns.iter().enumerate() {
+                header.push(Value::String(breakdown_dim.to_string()));
+
+                if k + 1 < sort_breakdowns.len() {
+                    header.push(Value::String(" ".to_string()));
+                }
+            }
+
+            header.push(Value::String("|".to_string()));
+        } else {
+            header.push(Value::Plain(format!(
+                "{}({})",
+                config.aggregate,
+                self.view.value_label()
+            )));
+        }
+
+        grid.add(header);
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+
+        for primary_dim in sort_primaries.iter() {
+            let mut row = Row::default();
+            row.label(primary_dim.to_string());
+            row.push(Value::String(primary_dim.to_string()));
+
+            if self.view.breakdown_label().is_some() {
+                let breakdown_values: Vec<f64> = sort_breakdowns
+                    .iter()
+                    .map(|breakdown_dim| {
+                        let aggregate_dims = (primary_dim.clone(), breakdown_dim.clone());
+                        aggregate_apply(
+                            &config.aggregate,
+                            &aggregate_values,
+                            &aggregate_dims,
+                            &mut minimum_value,
+                            &mut maximum_value,
+                        )
+                    })
+                    .collect();
+
+                if config.show_aggregate {
+                    row.push(Value::String(" ".to_string()));
+                    row.push_aggregate(minimal_precision_string(
+                        config.aggregate.apply(breakdown_values.as_slice()),
+                    ));
+                }
+
+                row.push(Value::String("  ".to_string()));
+                row.push(Value::String("|".to_string()));
+
+                for (k, breakdown_value) in breakdown_values.iter().enumerate() {
+                    row.push(Value::Bar(*breakdown_value));
+
+                    if k + 1 != breakdown_values.len() {
+                        row.push(Value::String(" ".to_string()));
+                    }
+                }
+
+                row.push(Value::String("|".to_string()));
+            } else {
+                let aggregate_dims = (primary_dim.clone(), sort_breakdowns[0].clone());
+                let value = aggregate_apply(
+                    &config.aggregate,
+                    &aggregate_values,
+                    &aggregate_dims,
+                    &mut minimum_value,
+                    &mut maximum_value,
+                );
+
+                if config.show_aggregate {
+                    row.push(Value::String(" ".to_string()));
+                    row.push_aggregate(minimal_precision_string(value));
+                }
+
+                row.push(Value::String("  ".to_string()));
+                row.push(Value::String("|".to_string()));
+                row.push(Value::Bar(value));
+            }
+
+            grid.add(row);
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+
+    /// Generate the back-to-back rendering for a two-valued breakdown.
+    fn render_pyramid(
+        self,
+        config: Render<BarChartConfig>,
+        aggregate_values: HashMap<(V::PrimaryDimension, V::BreakdownDimension), Vec<f64>>,
+        sort_primaries: Vec<V::PrimaryDimension>,
+        sort_breakdowns: Vec<V::BreakdownDimension>,
+    ) -> Flat {
+        let mut columns = Columns::default();
+        // left aggregate count
+        columns.push(Column::breakdown(Alignment::Right));
+        // left delimiter |
+        columns.push(Column::string(Alignment::Center));
+        // spacer " "
+        columns.push(Column::string(Alignment::Center));
+        // primary value
+        columns.push(Column::string(Alignment::Center));
+
+        if config.show_aggregate {
+            // spacer " "
+            columns.push(Column::string(Alignment::Center));
+            // total left [
+            columns.push(Column::string(Alignment::Left));
+            // total value
+            columns.push(Column::string(Alignment::Right));
+            // total right ]
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        // spacer " "
+        columns.push(Column::string(Alignment::Center));
+        // right delimiter |
+        columns.push(Column::string(Alignment::Center));
+        // right aggregate count
+        columns.push(Column::breakdown(Alignment::Left));
+
+        let mut grid = Grid::new(columns);
+        let breakdown_header = self
+            .view
+            .breakdown_label()
+            .expect("pyramid must have a breakdown");
+        let value_label = self.view.value_label();
+
+        if value_label != breakdown_header {
+            let mut pre_header = Row::default();
+            pre_header.push(Value::Plain(breakdown_header.clone()));
+            grid.add(pre_header);
+        }
+
+        let mut pre_header = Row::default();
+        pre_header.push(Value::Plain(format!("{}({value_label})", config.aggregate)));
+        grid.add(pre_header);
+
+        let mut header = Row::default();
+        header.label(self.view.display_headers()[0].clone());
+        header.push(Value::String(sort_breakdowns[0].to_string()));
+        header.push(Value::String("|".to_string()));
+        header.push(Value::String(" ".to_string()));
+        header.push(Value::String(self.view.display_headers()[0].clone()));
+
+        if config.show_aggregate {
+            header.push(Value::Empty);
+            header.push(Value::Overflow(config.aggregate.to_string()));
+            header.push(Value::Skip);
+            header.push(Value::Skip);
+        }
+
+        header.push(Value::String(" ".to_string()));
+        header.push(Value::String("|".to_string()));
+        header.push(Value::String(sort_breakdowns[1].to_string()));
+        grid.add(header);
+
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+
+        for primary_dim in sort_primaries.iter() {
+            let breakdown_values: Vec<f64> = sort_breakdowns
+                .iter()
+                .map(|breakdown_dim| {
+                    let aggregate_dims = (primary_dim.clone(), breakdown_dim.clone());
+                    aggregate_apply(
+                        &config.aggregate,
+                        &aggregate_values,
+                        &aggregate_dims,
+                        &mut minimum_value,
+                        &mut maximum_value,
+                    )
+                })
+                .collect();
+            let mut row = Row::default();
+            row.label(primary_dim.to_string());
+            row.push(Value::Bar(breakdown_values[0]));
+            row.push(Value::String("|".to_string()));
+            row.push(Value::String(" ".to_string()));
+            row.push(Value::String(primary_dim.to_string()));
+
+            if config.show_aggregate {
+                row.push(Value::String(" ".to_string()));
+                row.push_aggregate(minimal_precision_string(
+                    config.aggregate.apply(breakdown_values.as_slice()),
+                ));
+            }
+
+            row.push(Value::String(" ".to_string()));
+            row.push(Value::String("|".to_string()));
+            row.push(Value::Bar(breakdown_values[1]));
+            grid.add(row);
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+fn build_preheader(config: &Render<BarChartConfig>, label: &str, embed: bool) -> Row {
+    let mut row = Row::default();
+    row.push(Value::Empty);
+
+    if config.show_aggregate {
+        row.push(Value::Empty);
+        row.push(Value::Empty);
+        row.push(Value::Empty);
+        row.push(Value::Empty);
+    }
+
+    row.push(Value::Empty);
+    row.push(Value::Empty);
+
+    if embed {
+        row.push(Value::Plain(format!("{}({label})", config.aggregate)));
+    } else {
+        row.push(Value::Plain(label.to_string()));
+    }
+
+    row
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{BarChart, BarChartConfig, Orientation, Render};
+        use crate::{DatasetBuilder, Schema1, Schema2, Schema3, Schemas};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |Sum(abc)"#
+            );
+        }
+
+        #[test]
+        fn zero() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).add((0,)).build();
+            let view = dataset.reflect_1st();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |Sum(abc)
+0    |"#
+            );
+        }
+
+        #[test]
+        fn negatives_and_positives() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1,))
+                .add((0,))
+                .add((-1,))
+                .build();
+            let view = dataset.reflect_1st();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |Sum(abc)
+-1   |⊖
+0    |
+1    |*"#
+            );
+        }
+
+        #[test]
+        fn show_aggregate() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1,))
+                .add((2,))
+                .add((2,))
+                .build();
+            let view = dataset.reflect_1st();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc Sum  |Sum(abc)
+1   [1]  |*
+2   [4]  |****"#
+            );
+        }
+
+        #[test]
+        fn collapsed_display_dimensions() {
+            let schema = Schemas::three("A", "B", "C");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a1", "b1", "c1"))
+                .add(("a1", "b2", "c2"))
+                .add(("a2", "b1", "c1"))
+                .build();
+            let view = dataset.count();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+A   |Sum(Count)
+a1  |**
+a2  |*"#
+            );
+        }
+
+        #[test]
+        fn breakdown() {
+            let schema: Schema2<u8, u8> = Schemas::two("abc", "something long");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 2))
+                .add((2, 3))
+                .add((3, 4))
+                .build();
+            let view = dataset.breakdown_2nd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      Sum(something long)
+abc  | 2    3    4  |
+1    | **           |
+2    |     ***      |
+3    |          ****|"#
+            );
+        }
+
+        #[test]
+        fn count_breakdown() {
+            let schema: Schema3<&str, u8, bool> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 2, true))
+                .add(("a", 3, false))
+                .add(("b", 4, true))
+                .build();
+            let view = dataset.count_breakdown_3rd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      ghi
+      Sum(Count)
+abc  |false true |
+a    |  *     *  |
+b    |        *  |"#
+            );
+        }
+
+        #[test]
+        fn vertical() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1,))
+                .add((-2,))
+                .add((-2,))
+                .add((3,))
+                .add((3,))
+                .add((3,))
+                .build();
+            let view = dataset.reflect_1st();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                orientation: Orientation::Vertical,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(abc)
+       *
+       *
+       *
+       *
+       *
+       *
+       *
+       *
+    *  *
+────────
+⊖
+⊖
+⊖
+⊖
+-2  1  3
+abc"#
+            );
+        }
+
+        #[test]
+        fn vertical_scaled() {
+            let schema: Schema2<u8, u8> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 20))
+                .add((2, 10))
+                .build();
+            let view = dataset.view_2nd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                height_hint: 8,
+                orientation: Orientation::Vertical,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(def)
+*
+*
+*
+*  *
+*  *
+1  2
+abc"#
+            );
+        }
+
+        #[test]
+        fn vertical_breakdown() {
+            let schema: Schema3<&str, u8, bool> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 2, true))
+                .add(("a", 3, false))
+                .add(("b", 4, true))
+                .add(("b", 4, true))
+                .build();
+            let view = dataset.count_breakdown_3rd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                orientation: Orientation::Vertical,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+ghi
+Sum(Count)
+                     *
+  *     *            *
+false true   false true
+     a            b
+abc"#
+            );
+        }
+
+        #[test]
+        fn vertical_breakdown_show_aggregate() {
+            let schema: Schema3<&str, u8, bool> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 2, true))
+                .add(("a", 3, false))
+                .add(("b", 4, true))
+                .add(("b", 4, true))
+                .build();
+            let view = dataset.count_breakdown_3rd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                orientation: Orientation::Vertical,
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+ghi
+Sum(Count)
+                     *
+  *     *            *
+false true   false true
+     a            b
+    [2]          [2]
+abc"#
+            );
+        }
+
+        #[test]
+        fn count_breakdown_stacked() {
+            let schema: Schema3<&str, u8, bool> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 2, true))
+                .add(("a", 3, false))
+                .add(("a", 3, false))
+                .add(("b", 4, true))
+                .build();
+            let view = dataset.count_breakdown_3rd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                stack_breakdown: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      ghi
+      Sum(Count)
+abc  |* false  o true
+a    |**o
+b    |o"#
+            );
+        }
+
+        #[test]
+        fn breakdown_stacked_mixed_signs() {
+            let schema: Schema3<&str, &str, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", "x", 3))
+                .add(("a", "y", -2))
+                .add(("a", "z", 1))
+                .add(("b", "x", -1))
+                .add(("b", "y", 2))
+                .add(("b", "z", -3))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                stack_breakdown: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      def
+      Sum(ghi)
+abc  |* x  o y  + z
+a    |***+⊖⊖
+b    |oo⊖⊖⊖⊖"#
+            );
+        }
+
+        #[test]
+        fn breakdown_stacked_diverging() {
+            let schema: Schema3<&str, &str, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", "x", 3))
+                .add(("a", "y", -2))
+                .add(("a", "z", 1))
+                .add(("b", "x", -1))
+                .add(("b", "y", 2))
+                .add(("b", "z", -3))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                stack_breakdown: true,
+                diverging: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      def
+      Sum(ghi)
+abc  |* x  o y  + z
+a    |  oo│***+
+b    |+++*│oo"#
+            );
+        }
+
+        #[test]
+        fn count_breakdown_stacked_vertical() {
+            let schema: Schema3<&str, u8, bool> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 2, true))
+                .add(("a", 3, false))
+                .add(("b", 4, true))
+                .build();
+            let view = dataset.count_breakdown_3rd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                orientation: Orientation::Vertical,
+                stack_breakdown: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+ghi
+Sum(Count)
+  *     *            *
+false true   false true
+     a            b
+abc"#
+            );
+        }
+
+        #[test]
+        fn pyramid() {
+            let schema: Schema3<&str, u8, bool> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 2, true))
+                .add(("a", 3, false))
+                .add(("a", 3, false))
+                .add(("bb", 4, true))
+                .add(("bb", 4, true))
+                .add(("bb", 4, true))
+                .build();
+            let view = dataset.count_breakdown_3rd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                widget_config: BarChartConfig { pyramid: true },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+ghi
+Sum(Count)
+false| abc |true
+   **|  a  |*
+     | bb  |***"#
+            );
+        }
+
+        #[test]
+        fn pyramid_show_aggregate() {
+            let schema: Schema3<&str, bool, u8> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", true, 2))
+                .add(("a", false, 30))
+                .add(("b", true, 40))
+                .add(("b", false, 10))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                width_hint: 30,
+                show_aggregate: true,
+                widget_config: BarChartConfig { pyramid: true },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+def
+Sum(ghi)
+    false| abc Sum  |true
+   ******|  a  [32] |
+       **|  b  [50] |*********"#
+            );
+        }
+
+        #[test]
+        fn pyramid_not_two_valued() {
+            let schema: Schema2<u8, u8> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 2))
+                .add((2, 3))
+                .add((3, 4))
+                .build();
+            let view = dataset.breakdown_2nd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                widget_config: BarChartConfig { pyramid: true },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      Sum(def)
+abc  | 2    3    4  |
+1    | **           |
+2    |     ***      |
+3    |          ****|"#
+            );
+        }
+
+        #[test]
+        fn pyramid_vertical() {
+            let schema: Schema3<&str, u8, bool> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 2, true))
+                .add(("a", 3, false))
+                .add(("b", 4, true))
+                .add(("b", 4, true))
+                .build();
+            let view = dataset.count_breakdown_3rd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render {
+                orientation: Orientation::Vertical,
+                widget_config: BarChartConfig { pyramid: true },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+ghi
+Sum(Count)
+                     *
+  *     *            *
+false true   false true
+     a            b
+abc"#
+            );
+        }
+    }
+
+    #[cfg(feature = "pointer_impls")]
+    mod pointer_impls {
+        use crate::{BarChart, Render};
+        use crate::{DatasetBuilder, Schema2, Schemas};
+        use ordered_float::OrderedFloat;
+
+        #[test]
+        fn view2() {
+            let schema: Schema2<i64, OrderedFloat<f64>> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, OrderedFloat(0.1)))
+                .add((2, OrderedFloat(0.4)))
+                .add((2, OrderedFloat(0.9)))
+                .build();
+            let view = dataset.view_2nd();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |Sum(def)
+1    |
+2    |*"#
+            );
+
+            let view = dataset.count();
+            let barchart = BarChart::new(&view);
+            let flat = barchart.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |Sum(Count)
+1    |*
+2    |**"#
+            );
+        }
+    }
+}
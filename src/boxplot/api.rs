@@ -0,0 +1,22 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::BoxPlot;
+
+/// Render configuration specific to [`BoxPlot`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::BoxPlotConfig;
+/// let boxplot_config = BoxPlotConfig {
+///     abbreviate: true,
+///     ..BoxPlotConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct BoxPlotConfig {
+    /// Whether to abbreviate the dimensional values in the frame or not.
+    /// This has the same behaviour as [`crate::DagChartConfig::abbreviate`].
+    ///
+    /// Default: `false`.
+    pub abbreviate: bool,
+}
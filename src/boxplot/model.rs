@@ -0,0 +1,454 @@
+use crate::aggregate::{minimal_precision_string, quantile};
+use crate::canvas::{axis, normalize};
+use crate::dagchart::DagFrame;
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{BoxPlotConfig, Schema, View};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::DagChart;
+
+/// The smallest width at which the box and whiskers are drawn.
+const MINIMUM_WIDTH: usize = 8;
+
+/// The box-plot widget.
+///
+/// A box-plot draws the distribution of the view's values for each unique value of the primary dimension (1st).
+/// The minimum, first quartile, median, third quartile, and maximum are drawn as `├──[##|###]───┤`.
+/// All the boxes share the same horizontal axis, which is labelled with its bounds in the header.
+/// The values are pooled across the breakdown, if any.
+///
+/// The frame is drawn in the same manner as the [`DagChart`].
+/// The box-plot is sized to the `width_hint` of the [`Render`] configuration.
+///
+/// See also: [`DagChart`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema2<String, i64> = Schemas::two("Animal", "Length");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("whale".to_string(), 12))
+///     .add(("whale".to_string(), 20))
+///     .add(("shark".to_string(), 2))
+///     .add(("shark".to_string(), 4))
+///     .add(("shark".to_string(), 5))
+///     .add(("shark".to_string(), 8))
+///     .add(("shark".to_string(), 16))
+///     .build();
+/// let view = dataset.view_2nd();
+/// let flat = BoxPlot::new(&view)
+///     .render(Render {
+///         width_hint: 30,
+///         ..Render::default()
+///     });
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Animal  |2      Length      20
+/// shark   |├─[|###]────────┤
+/// whale   |           ├─[##|#]─┤"#);
+/// # }
+/// ```
+pub struct BoxPlot<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> BoxPlot<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone + PartialEq + Eq + Hash,
+    <V as View<S>>::DisplayDimensions: Clone + PartialEq + Eq + Hash + Ord,
+{
+    /// Construct a box-plot widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this box-plot.
+    pub fn render(self, config: Render<BoxPlotConfig>) -> Flat {
+        let mut primary_values: HashMap<V::PrimaryDimension, Vec<f64>> = HashMap::default();
+        let mut lookup: HashMap<V::DisplayDimensions, V::PrimaryDimension> = HashMap::default();
+        let mut frame = DagFrame::new(
+            self.view.display_headers(),
+            config.widget_config.abbreviate,
+            false,
+        );
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+
+        for dims in self.view.dataset().data() {
+            let value = self.view.value(dims);
+            let primary_dim = self.view.primary_dim(dims);
+            let display_dims = self.view.display_dims(dims);
+            frame.observe(&display_dims, value);
+
+            let values = primary_values.entry(primary_dim.clone()).or_default();
+            values.push(value);
+
+            lookup.entry(display_dims).or_insert(primary_dim);
+
+            if value < minimum_value {
+                minimum_value = value;
+            }
+
+            if value > maximum_value {
+                maximum_value = value;
+            }
+        }
+
+        if primary_values.is_empty() {
+            minimum_value = 0.0;
+            maximum_value = 0.0;
+        }
+
+        let summaries: HashMap<V::PrimaryDimension, Summary> = primary_values
+            .into_iter()
+            .map(|(primary_dim, mut values)| {
+                values.sort_by(|a, b| a.total_cmp(b));
+                let aggregate = config.aggregate.apply(values.as_slice());
+                (primary_dim, Summary::new(&values, aggregate))
+            })
+            .collect();
+        let mut frame_width = frame.width(&config.aggregate) + 3;
+
+        if config.show_aggregate {
+            let aggregate_width = summaries
+                .values()
+                .map(|summary| minimal_precision_string(summary.aggregate).len())
+                .max()
+                .unwrap_or(0);
+            frame_width += std::cmp::max(aggregate_width, config.aggregate.to_string().len()) + 3;
+        }
+
+        let width = std::cmp::max(config.width_hint.saturating_sub(frame_width), MINIMUM_WIDTH);
+        let mut columns = Columns::default();
+        frame.columns(&mut columns);
+
+        if config.show_aggregate {
+            // spacer " "
+            columns.push(Column::string(Alignment::Center));
+            // total left [
+            columns.push(Column::string(Alignment::Left));
+            // total value
+            columns.push(Column::string(Alignment::Right));
+            // total right ]
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // rendering delimiter |
+        columns.push(Column::string(Alignment::Center));
+        // box and whiskers
+        columns.push(Column::string(Alignment::Left));
+
+        let mut grid = Grid::new(columns);
+        let mut header = Row::default();
+        frame.header(&mut header, &config.aggregate);
+
+        if config.show_aggregate {
+            header.push(Value::Empty);
+            header.push(Value::Overflow(config.aggregate.to_string()));
+            header.push(Value::Skip);
+            header.push(Value::Skip);
+        }
+
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("|".to_string()));
+        header.push(Value::String(axis(
+            &self.view.value_label(),
+            minimum_value,
+            maximum_value,
+            width,
+        )));
+        grid.add(header);
+
+        for frame_row in frame.rows(&config.aggregate) {
+            let mut row = Row::default();
+
+            for value in frame_row.cells.into_iter() {
+                row.push(value);
+            }
+
+            if frame_row.label.is_some() {
+                let primary_dim = lookup
+                    .get(&frame_row.display_dims)
+                    .expect("display dimensions must be mapped to the primary dimension");
+                let summary = &summaries[primary_dim];
+
+                if config.show_aggregate {
+                    row.push(Value::String(" ".to_string()));
+                    row.push_aggregate(minimal_precision_string(summary.aggregate));
+                }
+
+                row.push(Value::String("  ".to_string()));
+                row.push(Value::String("|".to_string()));
+                row.push(Value::String(summary.draw(
+                    minimum_value,
+                    maximum_value,
+                    width,
+                )));
+            }
+
+            grid.add(row);
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+/// The five number summary of a distribution.
+#[derive(Debug, PartialEq)]
+struct Summary {
+    minimum: f64,
+    lower_quartile: f64,
+    median: f64,
+    upper_quartile: f64,
+    maximum: f64,
+    aggregate: f64,
+}
+
+impl Summary {
+    /// Summarize the (non-empty, sorted) `values`.
+    fn new(values: &[f64], aggregate: f64) -> Self {
+        Self {
+            minimum: values[0],
+            lower_quartile: quantile(values, 0.25),
+            median: quantile(values, 0.5),
+            upper_quartile: quantile(values, 0.75),
+            maximum: values[values.len() - 1],
+            aggregate,
+        }
+    }
+
+    /// Draw the box and whiskers onto an axis of `width` characters spanning `minimum..maximum`.
+    fn draw(&self, minimum: f64, maximum: f64, width: usize) -> String {
+        let position = |value: f64| -> usize {
+            (normalize(value, minimum, maximum) * (width - 1) as f64).round() as usize
+        };
+        let mut line = vec![' '; width];
+        let (start, end) = (position(self.minimum), position(self.maximum));
+        let (lower, upper) = (position(self.lower_quartile), position(self.upper_quartile));
+
+        for c in line.iter_mut().take(end + 1).skip(start) {
+            *c = '─';
+        }
+
+        for c in line.iter_mut().take(upper + 1).skip(lower) {
+            *c = '#';
+        }
+
+        line[start] = '├';
+        line[end] = '┤';
+        line[lower] = '[';
+        line[upper] = ']';
+        line[position(self.median)] = '|';
+        line.into_iter().collect::<String>().trim_end().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_draw() {
+        let summary = Summary::new(&[0.0, 2.0, 5.0, 8.0, 10.0], 0.0);
+        assert_eq!(summary.draw(0.0, 10.0, 11), "├─[##|##]─┤");
+        assert_eq!(summary.draw(0.0, 20.0, 11), "├[#|]┤");
+        assert_eq!(summary.draw(-10.0, 10.0, 11), "     ├[#|]┤");
+    }
+
+    #[test]
+    fn summary_draw_degenerate() {
+        let summary = Summary::new(&[3.0, 3.0], 0.0);
+        assert_eq!(summary.draw(3.0, 3.0, 9), "    |");
+        assert_eq!(summary.draw(0.0, 8.0, 9), "   |");
+    }
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{BoxPlot, BoxPlotConfig, Render};
+        use crate::{DatasetBuilder, Schema1, Schema2, Schema3, Schemas};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let flat = BoxPlot::new(&view).render(Render {
+                width_hint: 20,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |0    abc     0"#
+            );
+        }
+
+        #[test]
+        fn single() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema).add((1, 5)).build();
+            let view = dataset.view_2nd();
+            let flat = BoxPlot::new(&view).render(Render {
+                width_hint: 20,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |5    def     5
+1    |       |"#
+            );
+        }
+
+        #[test]
+        fn view2() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, -2))
+                .add((1, 0))
+                .add((1, 4))
+                .add((1, 6))
+                .add((2, 3))
+                .add((2, 8))
+                .add((2, 8))
+                .add((2, 10))
+                .build();
+            let view = dataset.view_2nd();
+            let flat = BoxPlot::new(&view).render(Render {
+                width_hint: 30,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |-2        def         10
+1    |├──[####|###]──┤
+2    |          ├──────[#|]──┤"#
+            );
+        }
+
+        #[test]
+        fn view2_show_aggregate() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, -2))
+                .add((1, 0))
+                .add((1, 4))
+                .add((1, 6))
+                .add((2, 3))
+                .add((2, 8))
+                .add((2, 8))
+                .add((2, 10))
+                .build();
+            let view = dataset.view_2nd();
+            let flat = BoxPlot::new(&view).render(Render {
+                width_hint: 30,
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc Sum   |-2     def      10
+1   [ 8]  |├─[###|##]─┤
+2   [29]  |       ├────[#|]─┤"#
+            );
+        }
+
+        #[test]
+        fn view3() {
+            let schema: Schema3<i64, String, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a".to_string(), 1))
+                .add((1, "b".to_string(), 3))
+                .add((1, "b".to_string(), 5))
+                .add((2, "a".to_string(), 2))
+                .add((2, "a".to_string(), 9))
+                .build();
+            let view = dataset.view_3rd();
+            let flat = BoxPlot::new(&view).render(Render {
+                width_hint: 30,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+def    abc  |1      ghi      9
+a    - 1    |├─[#|#]─┤
+b    ┘
+a    - 2    |  ├───[##|###]──┤"#
+            );
+        }
+
+        #[test]
+        fn view3_abbreviate() {
+            let schema: Schema3<i64, String, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "alpha".to_string(), 1))
+                .add((1, "beta".to_string(), 3))
+                .add((2, "alpha".to_string(), 9))
+                .build();
+            let view = dataset.view_3rd();
+            let flat = BoxPlot::new(&view).render(Render {
+                width_hint: 30,
+                widget_config: BoxPlotConfig { abbreviate: true },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+def    abc  |1      ghi      9
+a..  - 1    |├[|]┤
+b..  ┘
+a..  - 2    |                |"#
+            );
+        }
+    }
+
+    #[cfg(feature = "pointer_impls")]
+    mod pointer_impls {
+        use crate::{BoxPlot, Render};
+        use crate::{DatasetBuilder, Schema2, Schemas};
+        use ordered_float::OrderedFloat;
+
+        #[test]
+        fn view2() {
+            let schema: Schema2<i64, OrderedFloat<f64>> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, OrderedFloat(0.1)))
+                .add((1, OrderedFloat(0.4)))
+                .add((2, OrderedFloat(0.9)))
+                .build();
+            let view = dataset.view_2nd();
+            let flat = BoxPlot::new(&view).render(Render {
+                width_hint: 20,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |0.1  def   0.9
+1    |├[|#]┤
+2    |             |"#
+            );
+        }
+    }
+}
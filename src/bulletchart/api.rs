@@ -0,0 +1,36 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::BulletChart;
+
+/// Render configuration specific to [`BulletChart`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::BulletChartConfig;
+/// let bulletchart_config = BulletChartConfig {
+///     minimum: Some(95.0),
+///     target: Some(99.9),
+///     bands: vec![99.0, 99.5],
+///     ..BulletChartConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct BulletChartConfig {
+    /// The lower bound of the axis, from which the bars are drawn.
+    /// This is useful to focus on the upper range of the values (ex: attainments near 100%).
+    ///
+    /// Default: `None`, which starts the axis at zero (or at the smallest value, if it is negative).
+    pub minimum: Option<f64>,
+    /// The target value, marked with a `|` on each row.
+    /// Rows whose [`crate::BulletView`] supplies their own target use that instead.
+    ///
+    /// Default: `None`.
+    pub target: Option<f64>,
+    /// The thresholds between the qualitative bands (ex: poor / ok / good), in ascending order.
+    /// The `n` thresholds divide the axis into `n + 1` bands, shaded from darkest (`▓`) to lightest (`░`).
+    /// Any bands beyond the third share the lightest shade.
+    /// Rows whose [`crate::BulletView`] supplies their own thresholds use those instead.
+    ///
+    /// Default: `[]` (no bands).
+    pub bands: Vec<f64>,
+}
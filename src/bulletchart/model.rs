@@ -0,0 +1,505 @@
+use crate::aggregate::{aggregate_apply, minimal_precision_string};
+use crate::canvas::{axis, normalize};
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{BulletChartConfig, BulletView, Schema, View};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::BarChart;
+
+/// The smallest width at which the bullets are drawn.
+const MINIMUM_WIDTH: usize = 8;
+
+/// The shades of the qualitative bands, from darkest to lightest.
+const BAND_SHADES: [char; 3] = ['▓', '▒', '░'];
+
+/// The glyph used to draw the actual value.
+const BAR: char = '█';
+
+/// The glyph used to mark the target value.
+const TARGET: char = '|';
+
+/// The bullet-chart widget.
+///
+/// A bullet-chart represents each unique value of the view's primary dimension (1st) as a row, like the [`BarChart`].
+/// Each row draws the actual (aggregated) value as a bar (`█`) over the shaded qualitative bands (ex: poor `▓`, ok `▒`, good `░`), along with a `|` target marker.
+/// The breakdown dimension, if any, is folded into the actual value of each row.
+///
+/// The target and the band thresholds are set with [`BulletChartConfig::target`] and [`BulletChartConfig::bands`], and are shared by all the rows.
+/// Alternatively, construct the bullet-chart with [`BulletChart::from_bullet_view`] to read the target and the band thresholds of each row from a [`BulletView`] (ex: `bullet_2nd_3rd`).
+/// Rows for which the view doesn't supply them fall back to the configuration.
+/// When several inputs make up a row, the first supplied target (and thresholds) are used.
+/// Non-finite (ex: `NaN`) actuals, targets, and thresholds are left out of the rendering.
+/// All the rows share the same horizontal axis, which is labelled with its bounds in the header.
+/// The axis starts at zero, unless [`BulletChartConfig::minimum`] is set.
+/// The bullet-chart is sized to the `width_hint` of the [`Render`] configuration.
+///
+/// See also: [`BarChart`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema2<&str, f64> = Schemas::two("Service", "Attainment");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("auth", 97.0))
+///     .add(("billing", 99.2))
+///     .add(("search", 93.5))
+///     .build();
+/// let view = dataset.view_2nd();
+/// let flat = BulletChart::new(&view)
+///     .render(Render {
+///         width_hint: 40,
+///         widget_config: BulletChartConfig {
+///             minimum: Some(90.0),
+///             target: Some(99.0),
+///             bands: vec![95.0, 98.0],
+///         },
+///         ..Render::default()
+///     });
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Service  |90       Attainment       99.2
+/// auth     |███████████████████████▒▒▒░░|░
+/// billing  |████████████████████████████|█
+/// search   |████████████▓▓▓▓▒▒▒▒▒▒▒▒▒▒░░|░"#);
+/// # }
+/// ```
+pub struct BulletChart<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    bullets: Option<&'a dyn BulletView<S>>,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> BulletChart<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+{
+    /// Construct a bullet-chart widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            bullets: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Construct a bullet-chart widget from the provided bullet view, which supplies the target and the band thresholds of each row.
+    pub fn from_bullet_view(view: &'a V) -> Self
+    where
+        V: BulletView<S>,
+    {
+        Self {
+            view,
+            bullets: Some(view),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this bullet-chart.
+    pub fn render(self, config: Render<BulletChartConfig>) -> Flat {
+        let mut aggregate_values: HashMap<V::PrimaryDimension, Vec<f64>> = HashMap::default();
+        let mut sort_primaries: Vec<V::PrimaryDimension> = Vec::default();
+        let mut targets: HashMap<V::PrimaryDimension, f64> = HashMap::default();
+        let mut row_bands: HashMap<V::PrimaryDimension, Vec<f64>> = HashMap::default();
+
+        for dims in self.view.dataset().data() {
+            let value = self.view.value(dims);
+            let primary_dim = self.view.primary_dim(dims);
+            let values = aggregate_values.entry(primary_dim.clone()).or_default();
+            values.push(value);
+
+            if let Some(bullets) = self.bullets {
+                if let Some(target) = bullets.target(dims).filter(|target| target.is_finite()) {
+                    targets.entry(primary_dim.clone()).or_insert(target);
+                }
+
+                if let Some(bands) = bullets.bands(dims) {
+                    row_bands.entry(primary_dim.clone()).or_insert(bands);
+                }
+            }
+
+            if !sort_primaries.contains(&primary_dim) {
+                sort_primaries.push(primary_dim);
+            }
+        }
+
+        sort_primaries.sort();
+
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+        let actuals: Vec<f64> = sort_primaries
+            .iter()
+            .map(|primary_dim| {
+                aggregate_apply(
+                    &config.aggregate,
+                    &aggregate_values,
+                    primary_dim,
+                    &mut minimum_value,
+                    &mut maximum_value,
+                )
+            })
+            .collect();
+        // The target and the (sorted) band thresholds of each row, falling back to the configuration.
+        let bullets: Vec<(Option<f64>, Vec<f64>)> = sort_primaries
+            .iter()
+            .map(|primary_dim| {
+                let target = targets
+                    .get(primary_dim)
+                    .copied()
+                    .or(config.widget_config.target)
+                    .filter(|target| target.is_finite());
+                let mut bands: Vec<f64> = row_bands
+                    .get(primary_dim)
+                    .unwrap_or(&config.widget_config.bands)
+                    .iter()
+                    .copied()
+                    .filter(|threshold| threshold.is_finite())
+                    .collect();
+                bands.sort_by(|a, b| a.total_cmp(b));
+                (target, bands)
+            })
+            .collect();
+        // The axis includes the (finite) actual values, the targets, and the band thresholds.
+        // Unless the minimum is configured, the axis also includes zero (where the bars start).
+        let (minimum, maximum) = actuals
+            .iter()
+            .filter(|actual| actual.is_finite())
+            .chain(
+                bullets
+                    .iter()
+                    .flat_map(|(target, bands)| target.iter().chain(bands.iter())),
+            )
+            .fold((0.0, 0.0), |(minimum, maximum): (f64, f64), value| {
+                (minimum.min(*value), maximum.max(*value))
+            });
+        let (minimum, maximum) = match config.widget_config.minimum {
+            Some(configured) if configured.is_finite() => (configured, maximum.max(configured)),
+            _ => (minimum, maximum),
+        };
+
+        let primary_width = sort_primaries
+            .iter()
+            .map(|primary_dim| primary_dim.to_string().chars().count())
+            .chain([self.view.display_headers()[0].chars().count()])
+            .max()
+            .unwrap();
+        let mut frame_width = primary_width + 3;
+
+        if config.show_aggregate {
+            let aggregate_width = actuals
+                .iter()
+                .map(|actual| minimal_precision_string(*actual).len())
+                .max()
+                .unwrap_or(0);
+            frame_width += std::cmp::max(aggregate_width, config.aggregate.to_string().len()) + 3;
+        }
+
+        let width = std::cmp::max(config.width_hint.saturating_sub(frame_width), MINIMUM_WIDTH);
+        let mut columns = Columns::default();
+        // primary value
+        columns.push(Column::string(Alignment::Left));
+
+        if config.show_aggregate {
+            // spacer " "
+            columns.push(Column::string(Alignment::Center));
+            // total left [
+            columns.push(Column::string(Alignment::Left));
+            // total value
+            columns.push(Column::string(Alignment::Right));
+            // total right ]
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // rendering delimiter |
+        columns.push(Column::string(Alignment::Center));
+        // bullet
+        columns.push(Column::string(Alignment::Left));
+
+        let mut grid = Grid::new(columns);
+        let mut header = Row::default();
+        header.label(self.view.display_headers()[0].clone());
+        header.push(Value::String(self.view.display_headers()[0].clone()));
+
+        if config.show_aggregate {
+            header.push(Value::Empty);
+            header.push(Value::Overflow(config.aggregate.to_string()));
+            header.push(Value::Skip);
+            header.push(Value::Skip);
+        }
+
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("|".to_string()));
+        header.push(Value::String(axis(
+            &self.view.value_label(),
+            minimum,
+            maximum,
+            width,
+        )));
+        grid.add(header);
+
+        for ((primary_dim, actual), (target, bands)) in
+            sort_primaries.iter().zip(actuals).zip(bullets)
+        {
+            let mut row = Row::default();
+            row.label(primary_dim.to_string());
+            row.push(Value::String(primary_dim.to_string()));
+
+            if config.show_aggregate {
+                row.push(Value::String(" ".to_string()));
+                row.push_aggregate(minimal_precision_string(actual));
+            }
+
+            row.push(Value::String("  ".to_string()));
+            row.push(Value::String("|".to_string()));
+            row.push(Value::String(draw(
+                actual,
+                target,
+                &bands,
+                (minimum, maximum),
+                width,
+            )));
+            grid.add(row);
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+/// Draw the bullet for the `actual` value onto an axis of `width` characters spanning the `extent`.
+/// The (sorted) `bands` are drawn first, then the bar from zero (or the nearest end of the axis) to the `actual` value, and finally the `target` marker.
+/// The bar is left out when the `actual` value isn't finite.
+fn draw(
+    actual: f64,
+    target: Option<f64>,
+    bands: &[f64],
+    (minimum, maximum): (f64, f64),
+    width: usize,
+) -> String {
+    let position = |value: f64| -> usize {
+        (normalize(value, minimum, maximum).clamp(0.0, 1.0) * (width - 1) as f64).round() as usize
+    };
+    let mut line = vec![' '; width];
+
+    if !bands.is_empty() {
+        for (i, c) in line.iter_mut().enumerate() {
+            // The value at this character, the inverse of its position.
+            let value = minimum + (maximum - minimum) * i as f64 / (width - 1) as f64;
+            let band = bands
+                .iter()
+                .filter(|threshold| **threshold <= value)
+                .count();
+            *c = BAND_SHADES[std::cmp::min(band, BAND_SHADES.len() - 1)];
+        }
+    }
+
+    let origin = 0f64.clamp(minimum, maximum);
+
+    if actual.is_finite() && actual != origin {
+        let (zero, end) = (position(origin), position(actual));
+
+        for c in line
+            .iter_mut()
+            .take(std::cmp::max(zero, end) + 1)
+            .skip(std::cmp::min(zero, end))
+        {
+            *c = BAR;
+        }
+    }
+
+    if let Some(target) = target {
+        line[position(target)] = TARGET;
+    }
+
+    line.into_iter().collect::<String>().trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_bullets() {
+        assert_eq!(draw(0.0, None, &[], (0.0, 10.0), 11), "");
+        assert_eq!(draw(5.0, None, &[], (0.0, 10.0), 11), "██████");
+        assert_eq!(draw(5.0, Some(8.0), &[], (0.0, 10.0), 11), "██████  |");
+        assert_eq!(
+            draw(5.0, Some(8.0), &[3.0, 7.0], (0.0, 10.0), 11),
+            "██████▒░|░░"
+        );
+        assert_eq!(
+            draw(0.0, Some(10.0), &[3.0, 7.0], (0.0, 10.0), 11),
+            "▓▓▓▒▒▒▒░░░|"
+        );
+        assert_eq!(draw(-5.0, Some(0.0), &[], (-10.0, 10.0), 11), "   ██|");
+        assert_eq!(draw(97.0, Some(99.0), &[], (95.0, 100.0), 11), "█████   |");
+        assert_eq!(draw(90.0, None, &[], (95.0, 100.0), 11), "█");
+        assert_eq!(draw(f64::NAN, Some(8.0), &[], (0.0, 10.0), 11), "        |");
+    }
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{BulletChart, BulletChartConfig, Render};
+        use crate::{DatasetBuilder, Schema1, Schema2, Schema3, Schema4, Schemas};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let bulletchart = BulletChart::new(&view);
+            let flat = bulletchart.render(Render {
+                width_hint: 20,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |0    abc     0"#
+            );
+        }
+
+        #[test]
+        fn actual() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 2))
+                .add((1, 3))
+                .add((2, 10))
+                .add((3, 0))
+                .build();
+            let view = dataset.view_2nd();
+            let bulletchart = BulletChart::new(&view);
+            let flat = bulletchart.render(Render {
+                width_hint: 20,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |0    def    10
+1    |████████
+2    |██████████████
+3    |"#
+            );
+        }
+
+        #[test]
+        fn target_bands() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 4))
+                .add((2, 9))
+                .add((2, 3))
+                .build();
+            let view = dataset.view_2nd();
+            let bulletchart = BulletChart::new(&view);
+            let flat = bulletchart.render(Render {
+                width_hint: 30,
+                show_aggregate: true,
+                widget_config: BulletChartConfig {
+                    target: Some(10.0),
+                    bands: vec![8.0, 5.0],
+                    ..BulletChartConfig::default()
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc Sum   |0      def      12
+1   [ 4]  |███████▓▒▒▒▒░░|░░░
+2   [12]  |██████████████|███"#
+            );
+        }
+
+        #[test]
+        fn view_targets() {
+            let schema: Schema3<&str, f64, f64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 4.0, 10.0))
+                .add(("a", 2.0, 12.0))
+                .add(("b", 9.0, 6.0))
+                .build();
+            let view = dataset.bullet_2nd_3rd();
+            let bulletchart = BulletChart::from_bullet_view(&view);
+            let flat = bulletchart.render(Render {
+                width_hint: 30,
+                widget_config: BulletChartConfig {
+                    target: Some(3.0),
+                    bands: vec![5.0],
+                    ..BulletChartConfig::default()
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |0         def         10
+a    |███████████████▒▒▒▒▒▒▒▒|
+b    |██████████████|███████▒▒"#
+            );
+        }
+
+        #[test]
+        fn view_targets_bands() {
+            let schema: Schema4<&str, f64, f64, Vec<f64>> =
+                Schemas::four("abc", "def", "ghi", "jkl");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 4.0, 10.0, vec![3.0, 6.0]))
+                .add(("b", 9.0, 6.0, vec![5.0, 8.0]))
+                .build();
+            let view = dataset.bullet_2nd_3rd_4th();
+            let bulletchart = BulletChart::from_bullet_view(&view);
+            let flat = bulletchart.render(Render {
+                width_hint: 30,
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc Sum  |0      def      10
+a   [4]  |████████▒▒▒░░░░░░|
+b   [9]  |██████████|█████░░"#
+            );
+        }
+
+        #[test]
+        fn non_finite() {
+            let schema: Schema4<&str, f64, f64, Vec<f64>> =
+                Schemas::four("abc", "def", "ghi", "jkl");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", f64::NAN, 8.0, vec![3.0, f64::NAN]))
+                .add(("b", 4.0, f64::NAN, vec![f64::INFINITY, 6.0]))
+                .build();
+            let view = dataset.bullet_2nd_3rd_4th();
+            let bulletchart = BulletChart::from_bullet_view(&view);
+            let flat = bulletchart.render(Render {
+                width_hint: 30,
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc Sum    |0      def       8
+a   [NaN]  |▓▓▓▓▓▓▓▒▒▒▒▒▒▒▒▒▒|
+b   [  4]  |██████████▓▓▓▒▒▒▒▒"#
+            );
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt::{Display, Formatter};
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::CalendarHeatmap;
+
+/// Render configuration specific to [`CalendarHeatmap`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::CalendarHeatmapConfig;
+/// let calendarheatmap_config = CalendarHeatmapConfig {
+///     ..CalendarHeatmapConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct CalendarHeatmapConfig {}
+
+/// Allows a type `T` to be used as a calendar date, such as the *primary* dimension of a [`CalendarHeatmap`].
+/// Consumers may choose to implement this to draw non-standard date types.
+///
+/// The integer types are interpreted as the number of days since the epoch (`1970-01-01`).
+/// The `(year, month, day)` tuples and [`YearMonthDay`] are interpreted as dates in the (proleptic) Gregorian calendar.
+/// Invalid dates (ex: month `13`, or day `0`) are rejected, rather than rolled over into a neighbouring month.
+pub trait Date {
+    /// Get the number of days from the epoch (`1970-01-01`) to this date (`self: T`).
+    /// Returns `None` when this is not a valid date.
+    fn days_since_epoch(&self) -> Option<i64>;
+}
+
+impl<T: Date> Date for &T {
+    fn days_since_epoch(&self) -> Option<i64> {
+        (*self).days_since_epoch()
+    }
+}
+
+macro_rules! impl_date {
+    ($T:ty) => {
+        impl Date for $T {
+            fn days_since_epoch(&self) -> Option<i64> {
+                Some(*self as i64)
+            }
+        }
+    };
+}
+
+impl_date!(isize);
+impl_date!(i64);
+impl_date!(i32);
+impl_date!(i16);
+impl_date!(usize);
+impl_date!(u64);
+impl_date!(u32);
+impl_date!(u16);
+
+impl Date for (i32, u32, u32) {
+    fn days_since_epoch(&self) -> Option<i64> {
+        checked_days_from_civil(self.0, self.1, self.2)
+    }
+}
+
+/// A `(year, month, day)` date, which may be used as a dimension of a [`crate::Dataset`].
+///
+/// ### Example
+/// ```
+/// # use flat::YearMonthDay;
+/// let date = YearMonthDay::new(2024, 2, 29);
+/// assert_eq!(date.to_string(), "2024-02-29");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonthDay {
+    /// The year (ex: `2024`).
+    pub year: i32,
+    /// The month, from `1` (January) to `12` (December).
+    pub month: u32,
+    /// The day of the month, from `1`.
+    pub day: u32,
+}
+
+impl YearMonthDay {
+    /// Construct a date from its `year`, `month` (1-12), and `day` (1-31).
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Self { year, month, day }
+    }
+}
+
+impl Display for YearMonthDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl Date for YearMonthDay {
+    fn days_since_epoch(&self) -> Option<i64> {
+        checked_days_from_civil(self.year, self.month, self.day)
+    }
+}
+
+/// Convert the Gregorian `(year, month, day)` into the number of days since the epoch, or `None` if it is not a valid date.
+pub(crate) fn checked_days_from_civil(year: i32, month: u32, day: u32) -> Option<i64> {
+    let days = days_from_civil(year, month, day);
+
+    // An invalid date rolls over into another date (ex: `2024-02-30` is `2024-03-01`), so it won't convert back.
+    if civil_from_days(days) == (year, month, day) {
+        Some(days)
+    } else {
+        None
+    }
+}
+
+/// Convert the Gregorian `(year, month, day)` into the number of days since the epoch.
+/// See: <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+pub(crate) fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = year as i64 - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Convert the number of days since the epoch into the Gregorian `(year, month, day)`.
+/// See: <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+pub(crate) fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i32, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+
+        for days in -800_000..800_000 {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(<&u32 as Date>::days_since_epoch(&&4), Some(4));
+        assert_eq!((1970, 1, 2).days_since_epoch(), Some(1));
+        assert_eq!(YearMonthDay::new(1970, 2, 1).days_since_epoch(), Some(31));
+    }
+
+    #[test]
+    fn invalid_dates() {
+        assert_eq!(checked_days_from_civil(2024, 2, 29), Some(19_782));
+        assert_eq!(checked_days_from_civil(2023, 2, 29), None);
+        assert_eq!(checked_days_from_civil(2024, 13, 1), None);
+        assert_eq!(checked_days_from_civil(2024, 0, 1), None);
+        assert_eq!(checked_days_from_civil(2024, 1, 0), None);
+        assert_eq!(checked_days_from_civil(2024, 4, 31), None);
+        assert_eq!((2024, 13, 1).days_since_epoch(), None);
+        assert_eq!(YearMonthDay::new(2024, 1, 32).days_since_epoch(), None);
+    }
+}
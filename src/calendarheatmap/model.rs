@@ -0,0 +1,430 @@
+use crate::calendarheatmap::api::civil_from_days;
+use crate::render::{shade, Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{CalendarHeatmapConfig, Date, Schema, View};
+use std::collections::HashMap;
+use std::iter;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::Heatmap;
+
+/// The number of characters used to draw each date.
+const CELL_WIDTH: usize = 2;
+
+/// The abbreviated names of the weekdays, starting on Monday.
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// The abbreviated names of the months, starting on January.
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The calendar-heatmap widget.
+///
+/// A calendar-heatmap represents each date of the view's primary dimension (1st) as a cell in a calendar.
+/// The calendar has a row for each weekday (starting on Monday), and a column for each week spanned by the dates.
+/// The months are labelled across the top, above the week in which they begin.
+///
+/// The primary dimension must be a [`Date`] type (ex: a [`crate::YearMonthDay`], or the number of days since the epoch).
+/// The values of each date are aggregated, and the cell is filled with a shade glyph (` ░▒▓█`) in the manner of the [`Heatmap`].
+/// The breakdown dimension, if any, is folded into the value of each date.
+/// Invalid dates (ex: the 30th of February) are left out of the calendar.
+///
+/// The weeks wrap around into blocks (each with its own month labels), so that the calendar fits within the `width_hint` of the [`Render`] configuration.
+///
+/// See also: [`Heatmap`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema2<YearMonthDay, u32> = Schemas::two("Date", "Commits");
+/// let mut builder = DatasetBuilder::new(schema);
+///
+/// for day in 1..=28 {
+///     builder.update((YearMonthDay::new(2024, 1, day), day % 5));
+///     builder.update((YearMonthDay::new(2024, 2, day), day % 3));
+/// }
+///
+/// let dataset = builder.build();
+/// let view = dataset.view_2nd();
+/// let flat = CalendarHeatmap::new(&view)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Sum(Commits)
+///     Jan     Feb
+/// Mon ░░▓▓  ▒▒  ▒▒  ░░▒▒
+/// Tue ▒▒██░░▓▓    ░░▒▒
+/// Wed ▓▓  ▒▒██  ░░▒▒  ░░
+/// Thu ██░░▓▓  ░░▒▒  ░░
+/// Fri   ▒▒██░░▒▒  ░░▒▒
+/// Sat ░░▓▓  ▒▒  ░░▒▒
+/// Sun ▒▒██░░▓▓░░▒▒  ░░"#);
+/// # }
+/// ```
+pub struct CalendarHeatmap<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> CalendarHeatmap<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Date,
+{
+    /// Construct a calendar-heatmap widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this calendar-heatmap.
+    pub fn render(self, config: Render<CalendarHeatmapConfig>) -> Flat {
+        let mut day_values: HashMap<i64, Vec<f64>> = HashMap::default();
+
+        for dims in self.view.dataset().data() {
+            // Invalid dates are left out of the calendar.
+            if let Some(day) = self.view.primary_dim(dims).days_since_epoch() {
+                let value = self.view.value(dims);
+                day_values.entry(day).or_default().push(value);
+            }
+        }
+
+        let aggregates: HashMap<i64, f64> = day_values
+            .iter()
+            .map(|(day, values)| (*day, config.aggregate.apply(values.as_slice())))
+            .collect();
+        let extent = aggregates
+            .values()
+            .fold(0.0, |extent: f64, value| extent.max(value.abs()));
+        let minimum_value = aggregates.values().copied().fold(0.0, f64::min);
+        let maximum_value = aggregates.values().copied().fold(0.0, f64::max);
+
+        let mut columns = Columns::default();
+        // weekday
+        columns.push(Column::string(Alignment::Left));
+        // spacer " "
+        columns.push(Column::string(Alignment::Center));
+        // weeks
+        columns.push(Column::string(Alignment::Left));
+
+        let mut grid = Grid::new(columns);
+        let mut title = Row::default();
+        title.push(Value::Plain(format!(
+            "{}({})",
+            config.aggregate,
+            self.view.value_label()
+        )));
+        grid.add(title);
+
+        let first = aggregates.keys().min().copied();
+        let last = aggregates.keys().max().copied();
+
+        if let Some((first, last)) = first.zip(last) {
+            // The calendar starts on the Monday of the first week.
+            let start = first - weekday(first);
+            let weeks = ((last - start) / 7 + 1) as usize;
+            // The weeks wrap around into blocks, so that each block fits the width hint.
+            let frame_width = WEEKDAYS[0].len() + 1;
+            let block_weeks = std::cmp::max(
+                config.width_hint.saturating_sub(frame_width) / CELL_WIDTH,
+                1,
+            );
+
+            for block in (0..weeks).step_by(block_weeks) {
+                let block_start = start + (block * 7) as i64;
+                let block_weeks = std::cmp::min(block_weeks, weeks - block);
+                add_block(
+                    &mut grid,
+                    &aggregates,
+                    extent,
+                    block_start,
+                    (first.max(block_start), last),
+                    block_weeks,
+                );
+            }
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+/// Add the rows of a block of the calendar to the `grid`, for the `weeks` from the Monday `start`.
+/// The block is labelled with the months that begin within the days `first..=last`.
+fn add_block(
+    grid: &mut Grid,
+    aggregates: &HashMap<i64, f64>,
+    extent: f64,
+    start: i64,
+    (first, last): (i64, i64),
+    weeks: usize,
+) {
+    let mut header = Row::default();
+    header.push(Value::Empty);
+    header.push(Value::String(" ".to_string()));
+    header.push(Value::String(month_labels(start, first, last, weeks)));
+    grid.add(header);
+
+    for (w, name) in WEEKDAYS.iter().enumerate() {
+        let line: String = (0..weeks)
+            .flat_map(|k| {
+                let day = start + (k * 7 + w) as i64;
+                let glyph = match aggregates.get(&day) {
+                    Some(value) => shade(*value, extent),
+                    None => ' ',
+                };
+                iter::repeat_n(glyph, CELL_WIDTH)
+            })
+            .collect();
+        let line = line.trim_end();
+        let mut row = Row::default();
+        row.push(Value::String(name.to_string()));
+
+        if !line.is_empty() {
+            row.push(Value::String(" ".to_string()));
+            row.push(Value::String(line.to_string()));
+        }
+
+        grid.add(row);
+    }
+}
+
+/// The weekday of the `day` (since the epoch), from `0` (Monday) to `6` (Sunday).
+fn weekday(day: i64) -> i64 {
+    // The epoch (1970-01-01) is a Thursday.
+    (day + 3).rem_euclid(7)
+}
+
+/// Label each month above the week in which it begins, for the `weeks` from the Monday `start`.
+/// Only the days in `first..=last` are considered, so the first week is labelled even if its month began earlier.
+/// Labels which would overlap the following label are dropped.
+fn month_labels(start: i64, first: i64, last: i64, weeks: usize) -> String {
+    let mut labels: Vec<(usize, &str)> = Vec::default();
+
+    for k in 0..weeks {
+        let week = start + (k * 7) as i64;
+        let days: Vec<(i32, u32, u32)> = (week..week + 7)
+            .filter(|day| (first..=last).contains(day))
+            .map(civil_from_days)
+            .collect();
+        let begins =
+            days.iter()
+                .find(|(_, _, day)| *day == 1)
+                .or(if k == 0 { days.first() } else { None });
+
+        if let Some((_, month, _)) = begins {
+            labels.push((k * CELL_WIDTH, MONTHS[*month as usize - 1]));
+        }
+    }
+
+    // Walk backwards, so that a partial first month gives way to the next month.
+    let mut line: Vec<char> = Vec::default();
+    let mut limit = usize::MAX;
+
+    for (position, label) in labels.into_iter().rev() {
+        if position + label.len() < limit {
+            if line.len() < position + label.len() {
+                line.resize(position + label.len(), ' ');
+            }
+
+            for (c, character) in label.chars().enumerate() {
+                line[position + c] = character;
+            }
+
+            limit = position;
+        }
+    }
+
+    line.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendarheatmap::api::days_from_civil;
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(weekday(0), 3);
+        assert_eq!(weekday(-3), 0);
+        assert_eq!(weekday(days_from_civil(2024, 1, 1)), 0);
+        assert_eq!(weekday(days_from_civil(2024, 3, 31)), 6);
+    }
+
+    #[test]
+    fn month_label_positions() {
+        let first = days_from_civil(2024, 1, 1);
+        let last = days_from_civil(2024, 3, 31);
+        assert_eq!(month_labels(first, first, last, 13), "Jan     Feb     Mar");
+
+        let first = days_from_civil(2024, 1, 30);
+        let last = days_from_civil(2024, 2, 29);
+        let start = first - weekday(first);
+        assert_eq!(month_labels(start, first, last, 5), "Feb");
+
+        let first = days_from_civil(2023, 12, 25);
+        let last = days_from_civil(2024, 1, 31);
+        assert_eq!(month_labels(first, first, last, 6), "  Jan");
+    }
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{Aggregate, CalendarHeatmap, Render, YearMonthDay};
+        use crate::{DatasetBuilder, Schema1, Schema2, Schemas};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.count();
+            let calendarheatmap = CalendarHeatmap::new(&view);
+            let flat = calendarheatmap.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(Count)"#
+            );
+        }
+
+        #[test]
+        fn days_since_epoch() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((0,))
+                .add((0,))
+                .add((0,))
+                .add((0,))
+                .add((1,))
+                .add((2,))
+                .add((2,))
+                .add((10,))
+                .add((30,))
+                .add((30,))
+                .add((30,))
+                .build();
+            let view = dataset.count();
+            let calendarheatmap = CalendarHeatmap::new(&view);
+            let flat = calendarheatmap.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(Count)
+    Jan
+Mon
+Tue
+Wed
+Thu ██
+Fri ░░
+Sat ▒▒      ▓▓
+Sun   ░░"#
+            );
+        }
+
+        #[test]
+        fn year_month_day() {
+            let schema: Schema2<YearMonthDay, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((YearMonthDay::new(2023, 12, 25), 4))
+                .add((YearMonthDay::new(2023, 12, 25), 2))
+                .add((YearMonthDay::new(2024, 1, 1), 1))
+                .add((YearMonthDay::new(2024, 1, 14), -3))
+                .add((YearMonthDay::new(2024, 2, 2), 2))
+                .build();
+            let view = dataset.view_2nd();
+            let calendarheatmap = CalendarHeatmap::new(&view);
+            let flat = calendarheatmap.render(Render {
+                aggregate: Aggregate::Max,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Max(def)
+      Jan     Feb
+Mon ██░░
+Tue
+Wed
+Thu
+Fri           ▒▒
+Sat
+Sun     ▓▓"#
+            );
+        }
+
+        #[test]
+        fn invalid_dates() {
+            let schema: Schema2<YearMonthDay, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((YearMonthDay::new(2024, 2, 28), 1))
+                .add((YearMonthDay::new(2024, 2, 30), 5))
+                .add((YearMonthDay::new(2024, 13, 1), 5))
+                .add((YearMonthDay::new(2024, 3, 0), 5))
+                .add((YearMonthDay::new(2024, 3, 1), 2))
+                .build();
+            let view = dataset.view_2nd();
+            let calendarheatmap = CalendarHeatmap::new(&view);
+            let flat = calendarheatmap.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(def)
+    Mar
+Mon
+Tue
+Wed ▒▒
+Thu
+Fri ██
+Sat
+Sun"#
+            );
+        }
+
+        #[test]
+        fn wrapped() {
+            let schema: Schema2<YearMonthDay, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((YearMonthDay::new(2024, 1, 1), 1))
+                .add((YearMonthDay::new(2024, 1, 17), 2))
+                .add((YearMonthDay::new(2024, 2, 7), 3))
+                .add((YearMonthDay::new(2024, 3, 3), 4))
+                .build();
+            let view = dataset.view_2nd();
+            let calendarheatmap = CalendarHeatmap::new(&view);
+            let flat = calendarheatmap.render(Render {
+                width_hint: 16,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(def)
+    Jan     Feb
+Mon ░░
+Tue
+Wed     ▒▒    ▓▓
+Thu
+Fri
+Sat
+Sun
+    Feb Mar
+Mon
+Tue
+Wed
+Thu
+Fri
+Sat
+Sun     ██"#
+            );
+        }
+    }
+}
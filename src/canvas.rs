@@ -0,0 +1,379 @@
+use crate::aggregate::minimal_precision_string;
+use crate::render::{Alignment, Column, Columns, Grid, Row, Value};
+
+/// The braille dot bits, indexed by `[row][column]` within a single character cell.
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+const BRAILLE_BASE: u32 = 0x2800;
+
+/// The markers used to distinguish the categories drawn on a [`Canvas`].
+pub(crate) const MARKERS: [char; 10] = ['*', 'o', '+', 'x', '#', '@', '%', '&', '=', '~'];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Empty,
+    Braille(u32),
+    Marker(char),
+}
+
+/// A fixed size character canvas onto which points are plotted.
+///
+/// Points are specified in the unit square, where `(0, 0)` is the bottom left and `(1, 1)` is the top right.
+/// Braille points use the 2x4 dot resolution of each character, while marker points occupy the entire character.
+#[derive(Debug)]
+pub(crate) struct Canvas {
+    width: usize,
+    height: usize,
+    cells: Vec<Vec<Cell>>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![vec![Cell::Empty; width]; height],
+        }
+    }
+
+    /// Plot a braille dot at the unit coordinates `(x, y)`.
+    pub fn dot(&mut self, x: f64, y: f64) {
+        let column = scale(x, self.width * 2);
+        let row = scale(1.0 - y, self.height * 4);
+        let cell = &mut self.cells[row / 4][column / 2];
+        let bit = BRAILLE_DOTS[row % 4][column % 2];
+
+        *cell = match cell {
+            Cell::Braille(bits) => Cell::Braille(*bits | bit),
+            Cell::Empty | Cell::Marker(_) => Cell::Braille(bit),
+        };
+    }
+
+    /// Plot the `marker` at the unit coordinates `(x, y)`.
+    pub fn marker(&mut self, x: f64, y: f64, marker: char) {
+        let column = scale(x, self.width);
+        let row = scale(1.0 - y, self.height);
+        self.cells[row][column] = Cell::Marker(marker);
+    }
+
+    /// Plot a braille line between the unit coordinates `(x1, y1)` and `(x2, y2)`.
+    pub fn line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
+        let steps = std::cmp::max(
+            scale(x1, self.width * 2).abs_diff(scale(x2, self.width * 2)),
+            scale(y1, self.height * 4).abs_diff(scale(y2, self.height * 4)),
+        );
+
+        for step in 0..=steps {
+            let ratio = if steps == 0 {
+                0.0
+            } else {
+                step as f64 / steps as f64
+            };
+            self.dot(x1 + (x2 - x1) * ratio, y1 + (y2 - y1) * ratio);
+        }
+    }
+
+    /// Plot the `marker` along a line between the unit coordinates `(x1, y1)` and `(x2, y2)`.
+    pub fn marker_line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, marker: char) {
+        let steps = std::cmp::max(
+            scale(x1, self.width).abs_diff(scale(x2, self.width)),
+            scale(y1, self.height).abs_diff(scale(y2, self.height)),
+        );
+
+        for step in 0..=steps {
+            let ratio = if steps == 0 {
+                0.0
+            } else {
+                step as f64 / steps as f64
+            };
+            self.marker(x1 + (x2 - x1) * ratio, y1 + (y2 - y1) * ratio, marker);
+        }
+    }
+
+    /// Materialize the canvas into its lines, from top to bottom.
+    /// Trailing whitespace is trimmed.
+    pub fn lines(&self) -> Vec<String> {
+        self.cells
+            .iter()
+            .map(|row| {
+                let line: String = row
+                    .iter()
+                    .map(|cell| match cell {
+                        Cell::Empty => ' ',
+                        Cell::Braille(bits) => char::from_u32(BRAILLE_BASE + bits)
+                            .expect("braille bits must form a valid character"),
+                        Cell::Marker(marker) => *marker,
+                    })
+                    .collect();
+                line.trim_end().to_string()
+            })
+            .collect()
+    }
+}
+
+/// Map the unit `value` onto the discrete range `[0, size)`.
+fn scale(value: f64, size: usize) -> usize {
+    let maximum = size.saturating_sub(1);
+    let scaled = (value.clamp(0.0, 1.0) * maximum as f64).round() as usize;
+    std::cmp::min(scaled, maximum)
+}
+
+/// Normalize the `value` into the unit range defined by `minimum` and `maximum`.
+/// A degenerate range normalizes to the center.
+pub(crate) fn normalize(value: f64, minimum: f64, maximum: f64) -> f64 {
+    if maximum == minimum {
+        0.5
+    } else {
+        (value - minimum) / (maximum - minimum)
+    }
+}
+
+/// Label the ends of an axis spanning the `(minimum, maximum)` extent.
+pub(crate) fn extent_labels((minimum, maximum): (f64, f64)) -> [String; 2] {
+    [
+        minimal_precision_string(minimum),
+        minimal_precision_string(maximum),
+    ]
+}
+
+/// Get the marker for the `k`-th category.
+pub(crate) fn marker(k: usize) -> char {
+    MARKERS[k % MARKERS.len()]
+}
+
+/// Find the minimum and maximum of the values, or `(0, 0)` when there are none.
+pub(crate) fn extent(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values
+        .fold(None, |acc: Option<(f64, f64)>, value| match acc {
+            Some((minimum, maximum)) => Some((minimum.min(value), maximum.max(value))),
+            None => Some((value, value)),
+        })
+        .unwrap_or((0.0, 0.0))
+}
+
+/// Build the legend line, labelling each of the `names` with its marker.
+pub(crate) fn legend(header: &str, names: &[String]) -> String {
+    let entries: Vec<String> = names
+        .iter()
+        .enumerate()
+        .map(|(k, name)| format!("{} {name}", marker(k)))
+        .collect();
+    format!("{header}: {}", entries.join("  "))
+}
+
+/// The axes (and their labels) drawn around a [`Canvas`].
+///
+/// ```text
+/// r#"
+/// y_title
+/// y_maximum │..
+/// y_minimum │..
+///           └──
+///            x_minimum  x_maximum
+///            x_title
+///            legend"#
+/// ```
+#[derive(Debug)]
+pub(crate) struct Axes {
+    pub x_title: String,
+    /// The labels at the left (minimum) and right (maximum) ends of the x-axis.
+    pub x_labels: [String; 2],
+    pub y_title: String,
+    pub y_extent: (f64, f64),
+    pub legend: Option<String>,
+}
+
+impl Axes {
+    fn y_labels(&self) -> [String; 2] {
+        [
+            minimal_precision_string(self.y_extent.1),
+            minimal_precision_string(self.y_extent.0),
+        ]
+    }
+
+    /// Find the `(width, height)` of the canvas which fits these axes into the hints.
+    pub fn canvas_size(&self, width_hint: usize, height_hint: usize) -> (usize, usize) {
+        let frame_width = self
+            .y_labels()
+            .iter()
+            .map(|l| l.chars().count())
+            .max()
+            .unwrap()
+            + 2;
+        // The title, x-axis, x-labels, and x-title rows (plus the legend).
+        let reserved = 4 + usize::from(self.legend.is_some());
+        (
+            std::cmp::max(width_hint.saturating_sub(frame_width), 2),
+            std::cmp::max(height_hint.saturating_sub(reserved), 2),
+        )
+    }
+
+    /// Lay out the `canvas` within these axes.
+    pub fn grid(self, canvas: &Canvas) -> Grid {
+        let y_labels = self.y_labels();
+        let x_labels = &self.x_labels;
+        let mut columns = Columns::default();
+        // y-label
+        columns.push(Column::string(Alignment::Right));
+        // spacer " "
+        columns.push(Column::string(Alignment::Left));
+        // y-axis
+        columns.push(Column::string(Alignment::Left));
+        // canvas
+        columns.push(Column::string(Alignment::Left));
+        let mut grid = Grid::new(columns);
+
+        let mut title = Row::default();
+        title.push(Value::Plain(self.y_title));
+        grid.add(title);
+
+        let lines = canvas.lines();
+
+        for (i, line) in lines.iter().enumerate() {
+            let mut row = Row::default();
+
+            if i == 0 {
+                row.push(Value::String(y_labels[0].clone()));
+            } else if i + 1 == lines.len() {
+                row.push(Value::String(y_labels[1].clone()));
+            } else {
+                row.push(Value::Empty);
+            }
+
+            row.push(Value::String(" ".to_string()));
+            row.push(Value::String("│".to_string()));
+            row.push(Value::String(line.clone()));
+            grid.add(row);
+        }
+
+        let mut axis = Row::default();
+        axis.push(Value::Empty);
+        axis.push(Value::String(" ".to_string()));
+        axis.push(Value::String("└".to_string()));
+        axis.push(Value::String("─".repeat(canvas.width)));
+        grid.add(axis);
+
+        let mut labels = Row::default();
+        labels.push(Value::Empty);
+        labels.push(Value::String(" ".to_string()));
+        labels.push(Value::String(" ".to_string()));
+        labels.push(Value::String(spread(
+            &x_labels[0],
+            &x_labels[1],
+            canvas.width,
+        )));
+        grid.add(labels);
+
+        let mut x_title = Row::default();
+        x_title.push(Value::Empty);
+        x_title.push(Value::String(" ".to_string()));
+        x_title.push(Value::String(" ".to_string()));
+        x_title.push(Value::Plain(self.x_title));
+        grid.add(x_title);
+
+        if let Some(legend) = self.legend {
+            let mut row = Row::default();
+            row.push(Value::Empty);
+            row.push(Value::String(" ".to_string()));
+            row.push(Value::String(" ".to_string()));
+            row.push(Value::Plain(legend));
+            grid.add(row);
+        }
+
+        grid
+    }
+}
+
+/// Lay out the `left` and `right` labels at either end of the `width`.
+fn spread(left: &str, right: &str, width: usize) -> String {
+    let padding = width.saturating_sub(left.chars().count() + right.chars().count());
+    format!(
+        "{left}{:padding$}{right}",
+        "",
+        padding = std::cmp::max(padding, 1)
+    )
+}
+
+/// Draw the axis labels: the `minimum` on the left, the `maximum` on the right, and the `label` in the middle (if it fits).
+pub(crate) fn axis(label: &str, minimum: f64, maximum: f64, width: usize) -> String {
+    let minimum = minimal_precision_string(minimum);
+    let maximum = minimal_precision_string(maximum);
+    let label_width = label.chars().count();
+    let inner = width.saturating_sub(minimum.len() + maximum.len());
+
+    if inner >= label_width + 2 {
+        let left = (inner - label_width) / 2;
+        let right = inner - label_width - left;
+        format!(
+            "{minimum}{}{label}{}{maximum}",
+            " ".repeat(left),
+            " ".repeat(right)
+        )
+    } else {
+        format!("{minimum}{}{maximum}", " ".repeat(std::cmp::max(inner, 1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let canvas = Canvas::new(3, 2);
+        assert_eq!(canvas.lines(), vec!["", ""]);
+    }
+
+    #[test]
+    fn dot_corners() {
+        let mut canvas = Canvas::new(2, 2);
+        canvas.dot(0.0, 0.0);
+        canvas.dot(1.0, 1.0);
+        assert_eq!(canvas.lines(), vec![" ⠈", "⡀"]);
+    }
+
+    #[test]
+    fn dot_combines() {
+        let mut canvas = Canvas::new(1, 1);
+        canvas.dot(0.0, 1.0);
+        canvas.dot(1.0, 1.0);
+        canvas.dot(0.0, 0.0);
+        canvas.dot(1.0, 0.0);
+        assert_eq!(canvas.lines(), vec!["⣉"]);
+    }
+
+    #[test]
+    fn marker_overrides() {
+        let mut canvas = Canvas::new(3, 2);
+        canvas.dot(0.0, 1.0);
+        canvas.marker(0.0, 1.0, 'x');
+        canvas.marker(0.5, 0.0, 'o');
+        assert_eq!(canvas.lines(), vec!["x", " o"]);
+    }
+
+    #[test]
+    fn line_diagonal() {
+        let mut canvas = Canvas::new(2, 1);
+        canvas.line(0.0, 0.0, 1.0, 1.0);
+        assert_eq!(canvas.lines(), vec!["⡠⠊"]);
+    }
+
+    #[test]
+    fn marker_line_horizontal() {
+        let mut canvas = Canvas::new(4, 2);
+        canvas.marker_line(0.0, 0.0, 1.0, 0.0, '-');
+        assert_eq!(canvas.lines(), vec!["", "----"]);
+    }
+
+    #[test]
+    fn normalize_degenerate() {
+        assert_eq!(normalize(1.0, 1.0, 1.0), 0.5);
+        assert_eq!(normalize(1.0, 0.0, 2.0), 0.5);
+        assert_eq!(normalize(2.0, 0.0, 2.0), 1.0);
+    }
+
+    #[test]
+    fn axis_labels() {
+        assert_eq!(axis("abc", 0.0, 10.0, 11), "0  abc   10");
+        assert_eq!(axis("abcdef", 0.0, 10.0, 8), "0     10");
+    }
+}
@@ -0,0 +1,5 @@
+mod api;
+mod model;
+
+pub use api::*;
+pub use model::*;
@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter};
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::CorrelationMatrix;
+
+/// Render configuration specific to [`CorrelationMatrix`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::{Correlation, CorrelationMatrixConfig};
+/// let correlationmatrix_config = CorrelationMatrixConfig {
+///     correlation: Correlation::Spearman,
+///     ..CorrelationMatrixConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct CorrelationMatrixConfig {
+    /// The correlation coefficient to compute between each pair of variables.
+    ///
+    /// Default: [`Correlation::Pearson`].
+    pub correlation: Correlation,
+}
+
+/// The correlation coefficients supported by the [`CorrelationMatrix`].
+#[derive(Debug, Default)]
+pub enum Correlation {
+    /// The Pearson (linear) correlation coefficient.
+    #[default]
+    Pearson,
+    /// The Spearman (rank) correlation coefficient.
+    /// This is the Pearson correlation coefficient of the ranks of the values, where tied values share their average rank.
+    Spearman,
+}
+
+impl Display for Correlation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            Correlation::Pearson => write!(f, "Pearson"),
+            Correlation::Spearman => write!(f, "Spearman"),
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use crate::render::{shade, Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{Correlation, CorrelationMatrixConfig, MatrixView, Schema};
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::Heatmap;
+
+/// The correlation-matrix widget.
+///
+/// A correlation-matrix represents the correlation coefficient between each pair of the view's variables.
+/// Only the lower triangle of the matrix is drawn, since the matrix is symmetric.
+/// Each cell shows the coefficient along with a shade glyph (` ░▒▓█`), chosen by the magnitude of the coefficient in the manner of the [`Heatmap`].
+/// Negatively correlated cells are distinguished by their sign.
+///
+/// The variables are picked by the view: `matrix` takes all the dimensions, while `matrix_2nd_3rd` and `matrix_2nd_3rd_4th` leave out the first dimension (ex: a categorical label).
+/// The correlation coefficient is picked with [`CorrelationMatrixConfig::correlation`].
+/// When a coefficient is undefined (ex: a variable which doesn't vary), its cell is left blank.
+///
+/// See also: [`Heatmap`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema3<f64, f64, u32> = Schemas::three("Length", "Width", "Petals");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add((5.1, 3.5, 5))
+///     .add((4.9, 3.0, 4))
+///     .add((6.4, 3.2, 6))
+///     .add((6.9, 3.1, 5))
+///     .add((5.8, 2.7, 4))
+///     .add((7.1, 3.0, 6))
+///     .build();
+/// let view = dataset.matrix();
+/// let flat = CorrelationMatrix::new(&view)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Pearson  Length     Width     Petals
+/// Length     1.00 ██
+/// Width     -0.19 ░░   1.00 ██
+/// Petals     0.68 ▓▓   0.42 ▒▒    1.00 ██"#);
+/// # }
+/// ```
+pub struct CorrelationMatrix<'a, S, V>
+where
+    S: Schema,
+    V: MatrixView<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> CorrelationMatrix<'a, S, V>
+where
+    S: Schema,
+    V: MatrixView<S>,
+{
+    /// Construct a correlation-matrix widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this correlation-matrix.
+    pub fn render(self, config: Render<CorrelationMatrixConfig>) -> Flat {
+        let headers = self.view.variable_headers();
+        let mut variables: Vec<Vec<f64>> = vec![Vec::default(); headers.len()];
+
+        for dims in self.view.dataset().data() {
+            for (i, value) in self.view.variables(dims).into_iter().enumerate() {
+                variables[i].push(value);
+            }
+        }
+
+        if matches!(config.widget_config.correlation, Correlation::Spearman) {
+            variables = variables.iter().map(|xs| ranks(xs)).collect();
+        }
+
+        let mut columns = Columns::default();
+        // variable
+        columns.push(Column::string(Alignment::Left));
+
+        for _ in headers.iter() {
+            // spacer "  "
+            columns.push(Column::string(Alignment::Center));
+            // coefficient
+            columns.push(Column::string(Alignment::Right));
+            // spacer " "
+            columns.push(Column::string(Alignment::Center));
+            // coefficient shade
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        let mut grid = Grid::new(columns);
+        let mut header = Row::default();
+        header.push(Value::String(config.widget_config.correlation.to_string()));
+
+        for name in headers.iter() {
+            header.push(Value::String("  ".to_string()));
+            header.push(Value::String(name.clone()));
+            header.push(Value::Empty);
+            header.push(Value::Empty);
+        }
+
+        grid.add(header);
+
+        for (i, name) in headers.iter().enumerate() {
+            let mut row = Row::default();
+            row.push(Value::String(name.clone()));
+
+            for j in 0..=i {
+                match pearson(&variables[i], &variables[j]) {
+                    Some(coefficient) => {
+                        row.push(Value::String("  ".to_string()));
+                        row.push(Value::String(format!("{coefficient:.2}")));
+                        row.push(Value::String(" ".to_string()));
+                        let glyph = shade(coefficient, 1.0);
+
+                        if glyph == ' ' {
+                            row.push(Value::Empty);
+                        } else {
+                            row.push(Value::String([glyph, glyph].iter().collect()));
+                        }
+                    }
+                    None => {
+                        row.push(Value::Empty);
+                        row.push(Value::Empty);
+                        row.push(Value::Empty);
+                        row.push(Value::Empty);
+                    }
+                }
+            }
+
+            grid.add(row);
+        }
+
+        Flat::new(config, -1.0..1.0, grid)
+    }
+}
+
+/// Compute the Pearson correlation coefficient between the paired values `xs` and `ys`.
+/// Returns `None` when the coefficient is undefined (fewer than two pairs, or no variance in either).
+fn pearson(xs: &[f64], ys: &[f64]) -> Option<f64> {
+    let n = xs.len() as f64;
+
+    if xs.len() < 2 {
+        return None;
+    }
+
+    let x_mean = xs.iter().sum::<f64>() / n;
+    let y_mean = ys.iter().sum::<f64>() / n;
+    let mut covariance = 0.0;
+    let mut x_variance = 0.0;
+    let mut y_variance = 0.0;
+
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        covariance += (x - x_mean) * (y - y_mean);
+        x_variance += (x - x_mean).powi(2);
+        y_variance += (y - y_mean).powi(2);
+    }
+
+    if x_variance == 0.0 || y_variance == 0.0 {
+        return None;
+    }
+
+    // Guard against the rounding error pushing the coefficient out of [-1, 1].
+    Some((covariance / (x_variance * y_variance).sqrt()).clamp(-1.0, 1.0))
+}
+
+/// Rank the values `xs` (starting from 1), where tied values share their average rank.
+fn ranks(xs: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..xs.len()).collect();
+    order.sort_by(|a, b| xs[*a].total_cmp(&xs[*b]));
+    let mut out = vec![0.0; xs.len()];
+    let mut start = 0;
+
+    while start < order.len() {
+        let mut end = start + 1;
+
+        while end < order.len() && xs[order[end]] == xs[order[start]] {
+            end += 1;
+        }
+
+        // The average of the ranks (start + 1)..=end.
+        let rank = (start + 1 + end) as f64 / 2.0;
+
+        for k in order.iter().take(end).skip(start) {
+            out[*k] = rank;
+        }
+
+        start = end;
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pearson_coefficients() {
+        assert_eq!(pearson(&[], &[]), None);
+        assert_eq!(pearson(&[1.0], &[2.0]), None);
+        assert_eq!(pearson(&[1.0, 1.0], &[2.0, 3.0]), None);
+        assert_eq!(pearson(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]), Some(1.0));
+        assert_eq!(pearson(&[1.0, 2.0, 3.0], &[6.0, 4.0, 2.0]), Some(-1.0));
+        assert_eq!(
+            pearson(&[1.0, 2.0, 3.0, 4.0], &[1.0, 3.0, 2.0, 4.0]),
+            Some(0.8)
+        );
+    }
+
+    #[test]
+    fn average_ranks() {
+        assert_eq!(ranks(&[]), Vec::<f64>::default());
+        assert_eq!(ranks(&[30.0, 10.0, 20.0]), vec![3.0, 1.0, 2.0]);
+        assert_eq!(ranks(&[5.0, 1.0, 5.0, 2.0]), vec![3.5, 1.0, 3.5, 2.0]);
+    }
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{Correlation, CorrelationMatrix, CorrelationMatrixConfig, Render};
+        use crate::{DatasetBuilder, Schema3, Schema4, Schemas};
+
+        #[test]
+        fn empty() {
+            let schema: Schema3<i64, i64, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.matrix();
+            let correlationmatrix = CorrelationMatrix::new(&view);
+            let flat = correlationmatrix.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Pearson  abc  def  ghi
+abc
+def
+ghi"#
+            );
+        }
+
+        #[test]
+        fn pearson() {
+            let schema: Schema3<i64, i64, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 10, 3))
+                .add((2, 8, 1))
+                .add((3, 6, 4))
+                .add((4, 4, 1))
+                .add((5, 1, 5))
+                .build();
+            let view = dataset.matrix();
+            let correlationmatrix = CorrelationMatrix::new(&view);
+            let flat = correlationmatrix.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Pearson    abc       def      ghi
+abc       1.00 ██
+def      -1.00 ██   1.00 ██
+ghi       0.35 ▒▒  -0.41 ▒▒  1.00 ██"#
+            );
+        }
+
+        #[test]
+        fn mixed_schema() {
+            let schema: Schema4<&str, i64, i64, i64> = Schemas::four("abc", "def", "ghi", "jkl");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 1, 10, 3))
+                .add(("b", 2, 8, 1))
+                .add(("c", 3, 6, 4))
+                .add(("d", 4, 4, 1))
+                .add(("e", 5, 1, 5))
+                .build();
+            let view = dataset.matrix_2nd_3rd_4th();
+            let correlationmatrix = CorrelationMatrix::new(&view);
+            let flat = correlationmatrix.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Pearson    def       ghi      jkl
+def       1.00 ██
+ghi      -1.00 ██   1.00 ██
+jkl       0.35 ▒▒  -0.41 ▒▒  1.00 ██"#
+            );
+        }
+
+        #[test]
+        fn spearman() {
+            let schema: Schema4<i64, i64, i64, i64> = Schemas::four("abc", "def", "ghi", "jkl");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 1, 3, 7))
+                .add((2, 4, 1, 7))
+                .add((3, 9, 4, 7))
+                .add((4, 16, 2, 7))
+                .add((5, 100, 5, 7))
+                .build();
+            let view = dataset.matrix();
+            let correlationmatrix = CorrelationMatrix::new(&view);
+            let flat = correlationmatrix.render(Render {
+                widget_config: CorrelationMatrixConfig {
+                    correlation: Correlation::Spearman,
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Spearman   abc      def      ghi     jkl
+abc       1.00 ██
+def       1.00 ██  1.00 ██
+ghi       0.50 ▒▒  0.50 ▒▒  1.00 ██
+jkl"#
+            );
+        }
+    }
+}
@@ -1,5 +1,7 @@
 mod api;
+mod frame;
 mod model;
 
 pub use api::*;
+pub(crate) use frame::*;
 pub use model::*;
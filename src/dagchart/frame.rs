@@ -0,0 +1,412 @@
+use crate::abbreviate::find_abbreviations;
+use crate::aggregate::minimal_precision_string;
+use crate::render::{Alignment, Column, Columns, Row, Value};
+use crate::{Aggregate, Dimensions};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// The frame of a dag-chart.
+///
+/// Each unique tuple of display dimensions forms a path in a directed acyclic graph.
+/// Paths with common sub-paths, starting from the primary dimension (1st), are collapsed.
+/// The frame is shared by the widgets that draw their rendering alongside a dag (ex: [`crate::DagChart`], [`crate::BoxPlot`]).
+pub(crate) struct DagFrame<D> {
+    headers: Vec<String>,
+    abbreviate: bool,
+    show_aggregate: bool,
+    display_dimensions: Vec<D>,
+    seen_dimensions: HashSet<D>,
+    dimension_values: Vec<HashSet<String>>,
+    full_paths: HashSet<String>,
+    path_occurrences: HashMap<String, usize>,
+    partial_aggregate_values: HashMap<String, Vec<f64>>,
+}
+
+/// A single line of the [`DagFrame`].
+pub(crate) struct FrameRow<D> {
+    /// The display dimensions this line was drawn from.
+    pub display_dims: D,
+    /// The frame cells, in rendering order.
+    pub cells: Vec<Value>,
+    /// The primary dimension, when this line is where the primary dimension is drawn.
+    /// Widgets draw their rendering on these lines only.
+    pub label: Option<String>,
+}
+
+impl<D> DagFrame<D>
+where
+    D: Dimensions + Clone + PartialEq + Eq + Hash + Ord,
+{
+    /// Construct an empty frame for the display dimension `headers`.
+    /// The `show_aggregate` option corresponds to the [`crate::DagChartConfig`] option for the *non-primary* dimensions.
+    pub fn new(headers: Vec<String>, abbreviate: bool, show_aggregate: bool) -> Self {
+        let dimension_values = headers.iter().map(|_| HashSet::default()).collect();
+
+        Self {
+            headers,
+            abbreviate,
+            show_aggregate,
+            display_dimensions: Vec::default(),
+            seen_dimensions: HashSet::default(),
+            dimension_values,
+            full_paths: HashSet::default(),
+            path_occurrences: HashMap::default(),
+            partial_aggregate_values: HashMap::default(),
+        }
+    }
+
+    /// Observe the display dimensions (and associated value) of a data point.
+    pub fn observe(&mut self, display_dims: &D, value: f64) {
+        let full_path = display_dims
+            .as_strings()
+            .iter()
+            .fold(String::default(), |acc, part| acc + part + ";");
+
+        for (j, value) in display_dims.as_strings().into_iter().enumerate() {
+            self.dimension_values[j].insert(value);
+        }
+
+        // Only count the occurrences once per 'full path'.
+        // This is because we might have multiple entries, for example:
+        // ```
+        // DatasetBuilder::new(schema)
+        //     .add(("whale".to_string(), 4u32), 2)
+        //     .add(("whale".to_string(), 4u32), 3)
+        // ```
+        if !self.full_paths.contains(&full_path) {
+            self.full_paths.insert(full_path);
+
+            for dag_index in 0..display_dims.len() {
+                let partial_path = display_dims.as_strings()[0..dag_index + 1]
+                    .iter()
+                    .fold(String::default(), |acc, part| acc + part + ";");
+                self.path_occurrences
+                    .entry(partial_path)
+                    .and_modify(|c| *c += 1)
+                    .or_insert(1);
+            }
+        }
+
+        if self.show_aggregate {
+            for dag_index in 1..display_dims.len() {
+                let partial_path = display_dims.as_strings()[0..dag_index + 1]
+                    .iter()
+                    .fold(String::default(), |acc, part| acc + part + ";");
+                let values = self
+                    .partial_aggregate_values
+                    .entry(partial_path)
+                    .or_default();
+                values.push(value);
+            }
+        }
+
+        if !self.seen_dimensions.contains(display_dims) {
+            self.seen_dimensions.insert(display_dims.clone());
+            self.display_dimensions.push(display_dims.clone());
+        }
+    }
+
+    /// Push the frame columns.
+    pub fn columns(&self, columns: &mut Columns) {
+        for j in 0..self.headers.len() {
+            // dimension value
+            columns.push(Column::string(Alignment::Left));
+
+            if j + 1 < self.headers.len() {
+                // spacer " "
+                columns.push(Column::string(Alignment::Center));
+
+                if self.show_aggregate {
+                    // total left [
+                    columns.push(Column::string(Alignment::Left));
+                    // total value
+                    columns.push(Column::string(Alignment::Right));
+                    // total right ]
+                    columns.push(Column::string(Alignment::Left));
+                }
+
+                // dag marker " . "
+                columns.push(Column::string(Alignment::Center));
+            }
+        }
+    }
+
+    /// Push the frame cells for a pre-header row.
+    pub fn preheader(&self, row: &mut Row) {
+        for j in 0..self.headers.len() {
+            row.push(Value::Empty);
+
+            if j + 1 < self.headers.len() {
+                row.push(Value::Empty);
+
+                if self.show_aggregate {
+                    row.push(Value::Empty);
+                    row.push(Value::Empty);
+                    row.push(Value::Empty);
+                }
+
+                row.push(Value::Empty);
+            }
+        }
+    }
+
+    /// Push the frame cells for the header row.
+    pub fn header(&self, header: &mut Row, aggregate: &Aggregate) {
+        for (j, name) in self.headers.iter().rev().enumerate() {
+            header.push(Value::String(name.clone()));
+
+            if j + 1 < self.headers.len() {
+                header.push(Value::String(" ".to_string()));
+
+                if self.show_aggregate {
+                    header.push(Value::Overflow(aggregate.to_string()));
+                    header.push(Value::Skip);
+                    header.push(Value::Skip);
+                }
+
+                // For the dag marker " . "
+                header.push(Value::String("   ".to_string()));
+            }
+        }
+    }
+
+    /// Estimate the width of the frame, in characters.
+    /// Widgets which size their rendering themselves use this to fill the remainder of the `width_hint`.
+    pub fn width(&self, aggregate: &Aggregate) -> usize {
+        let dimension_abbreviations = self.abbreviations();
+        let mut width = 0;
+
+        for (dag_index, values) in self.dimension_values.iter().enumerate() {
+            let value_width = values
+                .iter()
+                .map(|value| {
+                    if self.abbreviate {
+                        dimension_abbreviations[dag_index][value].chars().count()
+                    } else {
+                        value.chars().count()
+                    }
+                })
+                .max()
+                .unwrap_or(0);
+            width += std::cmp::max(value_width, self.headers[dag_index].chars().count());
+
+            if dag_index != 0 {
+                // spacer " " and dag marker " . "
+                width += 4;
+
+                if self.show_aggregate {
+                    let aggregate_width = self
+                        .partial_aggregate_values
+                        .values()
+                        .map(|values| minimal_precision_string(aggregate.apply(values)).len())
+                        .max()
+                        .unwrap_or(0);
+                    // total [ and ]
+                    width += aggregate_width + 2;
+                }
+            }
+        }
+
+        width
+    }
+
+    /// Build the frame lines, in sorted order of the display dimensions.
+    pub fn rows(mut self, aggregate: &Aggregate) -> Vec<FrameRow<D>> {
+        self.display_dimensions.sort();
+        let dimension_abbreviations = self.abbreviations();
+        let mut column_groups: HashMap<usize, Group> = HashMap::default();
+        let mut rows = Vec::default();
+
+        for display_dims in self.display_dimensions.into_iter() {
+            let path = display_dims.as_strings();
+            let mut column_chunks_reversed: Vec<Vec<Value>> = Vec::default();
+            let mut descendant_position = None;
+            let mut label = None;
+
+            #[allow(unused_doc_comments)]
+            /// Run through the path in dag index ascending order, which
+            /// is the "rendering" reverse order.
+            ///
+            /// For this example dag, we'll iterate as follows:
+            /// a - b ┐
+            /// c ┐   - d
+            /// e - f ┘
+            /// h ┘
+            ///
+            /// path: ["d", "b", "a"]
+            /// dag_index | j | part | partial_path
+            /// ------------------------------
+            /// 0        | 2 | "d"   | "d"
+            /// 1        | 1 | "b"   | "d;b"
+            /// 2        | 0 | "a"   | "d;b;a"
+            ///
+            /// path: ["d", "f", "c"]
+            /// dag_index | j | part | partial_path
+            /// ------------------------------
+            /// 0        | 2 | "d"   | "d"
+            /// 1        | 1 | "f"   | "d;f"
+            /// 2        | 0 | "c"   | "d;f;c"
+            ///
+            /// path: ["d", "f", "e"]
+            /// dag_index | j | part | partial_path
+            /// ------------------------------
+            /// 0        | 2 | "d"   | "d"
+            /// 1        | 1 | "f"   | "d;f"
+            /// 2        | 0 | "e"   | "d;f;e"
+            ///
+            /// etc..
+            ///
+            for (dag_index, part) in path.clone().iter().enumerate() {
+                let j = path.len() - dag_index - 1;
+                let partial_path = path[0..dag_index + 1]
+                    .iter()
+                    .fold(String::default(), |acc, part| acc + part + ";");
+                let group = column_groups.entry(j).or_default();
+
+                if group.matches(&partial_path) {
+                    group.increment();
+                } else {
+                    group.swap(partial_path.clone());
+                }
+
+                let occurrences = self.path_occurrences[&partial_path];
+                let position = (occurrences as f64 / 2.0).ceil() as usize - 1;
+                let mut column_chunks = Vec::default();
+
+                let position = match position {
+                    position if position > group.index => Position::Above,
+                    position if position == group.index => Position::At,
+                    _ => Position::Below,
+                };
+
+                if position == Position::At {
+                    if self.abbreviate {
+                        column_chunks.push(Value::String(
+                            dimension_abbreviations[dag_index][part].clone(),
+                        ));
+                    } else {
+                        column_chunks.push(Value::String(part.clone()));
+                    }
+
+                    if dag_index == 0 {
+                        label.replace(part.clone());
+                    } else {
+                        assert!(descendant_position.is_some());
+                        column_chunks.push(Value::String(" ".to_string()));
+
+                        if self.show_aggregate {
+                            let value = aggregate
+                                .apply(self.partial_aggregate_values[&partial_path].as_slice());
+                            column_chunks.push(Value::String("[".to_string()));
+                            column_chunks.push(Value::String(minimal_precision_string(value)));
+                            column_chunks.push(Value::String("]".to_string()));
+                        }
+
+                        if let Some(desc_pos) = &descendant_position {
+                            match desc_pos {
+                                Position::Above => {
+                                    column_chunks.push(Value::String("┐".to_string()));
+                                }
+                                Position::At => {
+                                    column_chunks.push(Value::String("-".to_string()));
+                                }
+                                Position::Below => {
+                                    column_chunks.push(Value::String("┘".to_string()));
+                                }
+                            }
+                        }
+                    }
+                } else if dag_index != 0 {
+                    assert!(descendant_position.is_some());
+
+                    if let Some(desc_pos) = &descendant_position {
+                        match desc_pos {
+                            Position::At => {
+                                column_chunks.push(Value::Empty);
+                                column_chunks.push(Value::String(" ".to_string()));
+
+                                if self.show_aggregate {
+                                    column_chunks.push(Value::Empty);
+                                    column_chunks.push(Value::Empty);
+                                    column_chunks.push(Value::Empty);
+                                }
+                                column_chunks.push(Value::String("-".to_string()));
+                            }
+                            Position::Above | Position::Below => {
+                                // TODO: handle this case
+                            }
+                        }
+                    }
+                }
+
+                descendant_position.replace(position);
+                column_chunks_reversed.push(column_chunks);
+            }
+
+            let cells = column_chunks_reversed.into_iter().rev().flatten().collect();
+            rows.push(FrameRow {
+                display_dims,
+                cells,
+                label,
+            });
+        }
+
+        rows
+    }
+}
+
+impl<D> DagFrame<D> {
+    fn abbreviations(&self) -> Vec<HashMap<String, String>> {
+        let mut dimension_abbreviations: Vec<HashMap<String, String>> =
+            self.headers.iter().map(|_| HashMap::default()).collect();
+
+        if self.abbreviate {
+            let max_header_length = self
+                .headers
+                .iter()
+                .map(|h| h.chars().count())
+                .max()
+                .unwrap();
+
+            for (dag_index, values) in self.dimension_values.iter().enumerate() {
+                let min_header_length = self.headers[dag_index].to_string().chars().count();
+                let (_, abbreviations) =
+                    find_abbreviations(min_header_length, max_header_length, values);
+                dimension_abbreviations[dag_index] = abbreviations;
+            }
+        }
+
+        dimension_abbreviations
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Position {
+    Above,
+    At,
+    Below,
+}
+
+#[derive(Debug, Default)]
+struct Group {
+    locus: Option<String>,
+    index: usize,
+}
+
+impl Group {
+    fn matches(&self, path: &String) -> bool {
+        match &self.locus {
+            Some(l) => l == path,
+            None => false,
+        }
+    }
+
+    fn swap(&mut self, locus: String) {
+        self.locus.replace(locus);
+        self.index = 0;
+    }
+
+    fn increment(&mut self) {
+        self.index += 1;
+    }
+}
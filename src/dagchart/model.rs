@@ -1,10 +1,10 @@
-use crate::abbreviate::find_abbreviations;
+use super::DagFrame;
 use crate::aggregate::{aggregate_apply, minimal_precision_string};
 use crate::render::{Alignment, Column, Columns, Grid, Row, Value};
 use crate::{DagChartConfig, Dimensions, Schema, View};
 use crate::{Flat, Render};
-use std::collections::{HashMap, HashSet};
-use std::fmt::{Debug, Display};
+use std::collections::HashMap;
+use std::fmt::Display;
 use std::hash::Hash;
 use std::marker::PhantomData;
 // We use this in the doc strings.
@@ -72,18 +72,16 @@ where
     pub fn render(self, config: Render<DagChartConfig>) -> Flat {
         let mut aggregate_values: HashMap<(V::PrimaryDimension, V::BreakdownDimension), Vec<f64>> =
             HashMap::default();
-        let mut partial_aggregate_values: HashMap<String, Vec<f64>> = HashMap::default();
-        let mut full_paths: HashSet<String> = HashSet::default();
-        let mut display_dimensions: Vec<V::DisplayDimensions> = Vec::default();
         let mut sort_breakdowns: Vec<V::BreakdownDimension> = Vec::default();
         let mut lookup: HashMap<
             V::DisplayDimensions,
             (V::PrimaryDimension, V::BreakdownDimension),
         > = HashMap::default();
-        let mut dimension_values: Vec<HashSet<String>> = (0..self.view.display_headers().len())
-            .map(|_| HashSet::default())
-            .collect();
-        let mut path_occurrences: HashMap<String, usize> = HashMap::default();
+        let mut frame = DagFrame::new(
+            self.view.display_headers(),
+            config.widget_config.abbreviate,
+            config.widget_config.show_aggregate,
+        );
 
         for dims in self.view.dataset().data() {
             let value = self.view.value(dims);
@@ -91,108 +89,26 @@ where
             let breakdown_dims = self.view.breakdown_dim(dims);
             let aggregate_dims = (primary_dim.clone(), breakdown_dims.clone());
             let display_dims = self.view.display_dims(dims);
-            let full_path = display_dims
-                .as_strings()
-                .iter()
-                .fold(String::default(), |acc, part| acc + part + ";");
-
-            for (j, value) in display_dims.as_strings().into_iter().enumerate() {
-                dimension_values[j].insert(value);
-            }
-
-            // Only count the occurrences once per 'full path'.
-            // This is because we might have multiple entries, for example:
-            // ```
-            // DatasetBuilder::new(schema)
-            //     .add(("whale".to_string(), 4u32), 2)
-            //     .add(("whale".to_string(), 4u32), 3)
-            // ```
-            if !full_paths.contains(&full_path) {
-                full_paths.insert(full_path);
-
-                for dag_index in 0..display_dims.len() {
-                    let partial_path = display_dims.as_strings()[0..dag_index + 1]
-                        .iter()
-                        .fold(String::default(), |acc, part| acc + part + ";");
-                    path_occurrences
-                        .entry(partial_path)
-                        .and_modify(|c| *c += 1)
-                        .or_insert(1);
-                }
-            }
-
-            if config.widget_config.show_aggregate {
-                for dag_index in 1..display_dims.len() {
-                    let partial_path = display_dims.as_strings()[0..dag_index + 1]
-                        .iter()
-                        .fold(String::default(), |acc, part| acc + part + ";");
-                    let values = partial_aggregate_values.entry(partial_path).or_default();
-                    values.push(value);
-                }
-            }
+            frame.observe(&display_dims, value);
 
             let values = aggregate_values.entry(aggregate_dims.clone()).or_default();
             values.push(value);
 
-            if !lookup.contains_key(&display_dims) {
-                // Notice, the breakdown_dim will be different in the case of an `is_breakdown` schema.
-                // But in that case, we don't actually use the breakdown from `lookup`.
-                // We really only need this so we can get the `Nothing` breakdown for non-`is_breakdown` schemas.
-                lookup.insert(
-                    display_dims.clone(),
-                    (primary_dim.clone(), breakdown_dims.clone()),
-                );
-                display_dimensions.push(display_dims);
-            }
+            // Notice, the breakdown_dim will be different in the case of an `is_breakdown` schema.
+            // But in that case, we don't actually use the breakdown from `lookup`.
+            // We really only need this so we can get the `Nothing` breakdown for non-`is_breakdown` schemas.
+            lookup
+                .entry(display_dims)
+                .or_insert_with(|| (primary_dim.clone(), breakdown_dims.clone()));
 
             if !sort_breakdowns.contains(&breakdown_dims) {
                 sort_breakdowns.push(breakdown_dims);
             }
         }
 
-        display_dimensions.sort();
         sort_breakdowns.sort();
-
-        let mut dimension_abbreviations: Vec<HashMap<String, String>> =
-            (0..self.view.display_headers().len())
-                .map(|_| HashMap::default())
-                .collect();
-
-        if config.widget_config.abbreviate {
-            let headers = self.view.display_headers();
-            let max_header_length = headers.iter().map(|h| h.chars().count()).max().unwrap();
-
-            for (dag_index, values) in dimension_values.iter().enumerate() {
-                let min_header_length = headers[dag_index].to_string().chars().count();
-                let (_, abbreviations) =
-                    find_abbreviations(min_header_length, max_header_length, values);
-                dimension_abbreviations[dag_index] = abbreviations;
-            }
-        }
-
         let mut columns = Columns::default();
-
-        for j in 0..self.view.display_headers().len() {
-            // dimension value
-            columns.push(Column::string(Alignment::Left));
-
-            if j + 1 < self.view.display_headers().len() {
-                // spacer " "
-                columns.push(Column::string(Alignment::Center));
-
-                if config.widget_config.show_aggregate {
-                    // total left [
-                    columns.push(Column::string(Alignment::Left));
-                    // total value
-                    columns.push(Column::string(Alignment::Right));
-                    // total right ]
-                    columns.push(Column::string(Alignment::Left));
-                }
-
-                // dag marker " . "
-                columns.push(Column::string(Alignment::Center));
-            }
-        }
+        frame.columns(&mut columns);
 
         if config.show_aggregate {
             // spacer " "
@@ -234,49 +150,19 @@ where
             let value_label = self.view.value_label();
 
             if value_label == breakdown_header {
-                let pre_header = build_preheader(
-                    &config,
-                    self.view.display_headers().len(),
-                    &breakdown_header,
-                    true,
-                );
+                let pre_header = build_preheader(&config, &frame, &breakdown_header, true);
                 grid.add(pre_header);
             } else {
-                let pre_header1 = build_preheader(
-                    &config,
-                    self.view.display_headers().len(),
-                    &breakdown_header,
-                    false,
-                );
+                let pre_header1 = build_preheader(&config, &frame, &breakdown_header, false);
                 grid.add(pre_header1);
-                let pre_header2 = build_preheader(
-                    &config,
-                    self.view.display_headers().len(),
-                    &value_label,
-                    true,
-                );
+                let pre_header2 = build_preheader(&config, &frame, &value_label, true);
                 grid.add(pre_header2);
             }
         }
 
         let mut header = Row::default();
-
-        for (j, name) in self.view.display_headers().iter().rev().enumerate() {
-            header.push(Value::String(name.clone()));
-
-            if j + 1 < self.view.display_headers().len() {
-                header.push(Value::String(" ".to_string()));
-
-                if config.widget_config.show_aggregate {
-                    header.push(Value::Overflow(config.aggregate.to_string()));
-                    header.push(Value::Skip);
-                    header.push(Value::Skip);
-                }
-
-                // For the dag marker " . "
-                header.push(Value::String("   ".to_string()));
-            }
-        }
+        header.label(self.view.display_headers()[0].clone());
+        frame.header(&mut header, &config.aggregate);
 
         if config.show_aggregate {
             header.push(Value::Empty);
@@ -304,214 +190,85 @@ where
                 config.aggregate.to_string(),
                 self.view.value_label()
             )));
-            // header.push(Value::Plain(config.aggregate.to_string()));
         }
 
         grid.add(header);
-        let mut column_groups: HashMap<usize, Group> = HashMap::default();
         let mut minimum_value = f64::MAX;
         let mut maximum_value = f64::MIN;
 
-        for display_dims in display_dimensions.iter() {
-            let path = display_dims.as_strings();
-            let mut column_chunks_reversed: Vec<Vec<Value>> = Vec::default();
-            let mut descendant_position = None;
-
-            #[allow(unused_doc_comments)]
-            /// Run through the path in dag index ascending order, which
-            /// is the "rendering" reverse order.
-            ///
-            /// For this example dag, we'll iterate as follows:
-            /// a - b ┐
-            /// c ┐   - d
-            /// e - f ┘
-            /// h ┘
-            ///
-            /// path: ["d", "b", "a"]
-            /// dag_index | j | part | partial_path
-            /// ------------------------------
-            /// 0        | 2 | "d"   | "d"
-            /// 1        | 1 | "b"   | "d;b"
-            /// 2        | 0 | "a"   | "d;b;a"
-            ///
-            /// path: ["d", "f", "c"]
-            /// dag_index | j | part | partial_path
-            /// ------------------------------
-            /// 0        | 2 | "d"   | "d"
-            /// 1        | 1 | "f"   | "d;f"
-            /// 2        | 0 | "c"   | "d;f;c"
-            ///
-            /// path: ["d", "f", "e"]
-            /// dag_index | j | part | partial_path
-            /// ------------------------------
-            /// 0        | 2 | "d"   | "d"
-            /// 1        | 1 | "f"   | "d;f"
-            /// 2        | 0 | "e"   | "d;f;e"
-            ///
-            /// etc..
-            ///
-            for (dag_index, part) in path.clone().iter().enumerate() {
-                let j = path.len() - dag_index - 1;
-                let partial_path = path[0..dag_index + 1]
-                    .iter()
-                    .fold(String::default(), |acc, part| acc + part + ";");
-                let group = column_groups.entry(j).or_default();
-
-                if group.matches(&partial_path) {
-                    group.increment();
-                } else {
-                    group.swap(partial_path.clone());
-                }
-
-                let occurrences = path_occurrences[&partial_path];
-                let position = (occurrences as f64 / 2.0).ceil() as usize - 1;
-                let mut column_chunks = Vec::default();
+        for frame_row in frame.rows(&config.aggregate) {
+            let mut row = Row::default();
 
-                let position = match position {
-                    position if position > group.index => Position::Above,
-                    position if position == group.index => Position::At,
-                    _ => Position::Below,
-                };
+            for value in frame_row.cells.into_iter() {
+                row.push(value);
+            }
 
-                if position == Position::At {
-                    if config.widget_config.abbreviate {
-                        column_chunks.push(Value::String(
-                            dimension_abbreviations[dag_index][part].clone(),
-                        ));
-                    } else {
-                        column_chunks.push(Value::String(part.clone()));
-                    }
+            if let Some(label) = frame_row.label {
+                row.label(label);
+                let (primary_dim, breakdown_dim) = lookup
+                    .get(&frame_row.display_dims)
+                    .expect("sort dimensions must be mapped to dimensions");
 
-                    if dag_index == 0 {
-                        let (primary_dim, breakdown_dim) = lookup
-                            .get(display_dims)
-                            .expect("sort dimensions must be mapped to dimensions");
-
-                        if self.view.breakdown_label().is_some() {
-                            let breakdown_values: Vec<f64> = sort_breakdowns
-                                .iter()
-                                .map(|breakdown_dim| {
-                                    let aggregate_dims =
-                                        (primary_dim.clone(), breakdown_dim.clone());
-                                    aggregate_apply(
-                                        &config.aggregate,
-                                        &aggregate_values,
-                                        &aggregate_dims,
-                                        &mut minimum_value,
-                                        &mut maximum_value,
-                                    )
-                                })
-                                .collect();
-
-                            if config.show_aggregate {
-                                column_chunks.push(Value::String(" ".to_string()));
-                                column_chunks.push(Value::String("[".to_string()));
-                                column_chunks.push(Value::String(minimal_precision_string(
-                                    config.aggregate.apply(breakdown_values.as_slice()),
-                                )));
-                                column_chunks.push(Value::String("]".to_string()));
-                            }
-
-                            column_chunks.push(Value::String("  ".to_string()));
-                            column_chunks.push(Value::String("|".to_string()));
-
-                            for (k, breakdown_value) in breakdown_values.iter().enumerate() {
-                                column_chunks.push(Value::Value(*breakdown_value));
-
-                                if k + 1 != breakdown_values.len() {
-                                    column_chunks.push(Value::String(" ".to_string()));
-                                }
-                            }
-
-                            column_chunks.push(Value::String("|".to_string()));
-                        } else {
+                if self.view.breakdown_label().is_some() {
+                    let breakdown_values: Vec<f64> = sort_breakdowns
+                        .iter()
+                        .map(|breakdown_dim| {
                             let aggregate_dims = (primary_dim.clone(), breakdown_dim.clone());
-                            let value = aggregate_apply(
+                            aggregate_apply(
                                 &config.aggregate,
                                 &aggregate_values,
                                 &aggregate_dims,
                                 &mut minimum_value,
                                 &mut maximum_value,
-                            );
-
-                            if config.show_aggregate {
-                                column_chunks.push(Value::String(" ".to_string()));
-                                column_chunks.push(Value::String("[".to_string()));
-                                column_chunks.push(Value::String(minimal_precision_string(value)));
-                                column_chunks.push(Value::String("]".to_string()));
-                            }
-
-                            column_chunks.push(Value::String("  ".to_string()));
-                            column_chunks.push(Value::String("|".to_string()));
-                            column_chunks.push(Value::Value(value));
-
-                            if value < minimum_value {
-                                minimum_value = value;
-                            }
-
-                            if value > maximum_value {
-                                maximum_value = value;
-                            }
-                        }
-                    } else {
-                        assert!(descendant_position.is_some());
-                        column_chunks.push(Value::String(" ".to_string()));
-
-                        if config.widget_config.show_aggregate {
-                            let value = config
-                                .aggregate
-                                .apply(partial_aggregate_values[&partial_path].as_slice());
-                            column_chunks.push(Value::String("[".to_string()));
-                            column_chunks.push(Value::String(minimal_precision_string(value)));
-                            column_chunks.push(Value::String("]".to_string()));
-                        }
+                            )
+                        })
+                        .collect();
+
+                    if config.show_aggregate {
+                        row.push(Value::String(" ".to_string()));
+                        row.push_aggregate(minimal_precision_string(
+                            config.aggregate.apply(breakdown_values.as_slice()),
+                        ));
+                    }
+
+                    row.push(Value::String("  ".to_string()));
+                    row.push(Value::String("|".to_string()));
 
-                        if let Some(desc_pos) = &descendant_position {
-                            match desc_pos {
-                                Position::Above => {
-                                    column_chunks.push(Value::String(format!("┐")));
-                                }
-                                Position::At => {
-                                    column_chunks.push(Value::String(format!("-")));
-                                }
-                                Position::Below => {
-                                    column_chunks.push(Value::String(format!("┘")));
-                                }
-                            }
+                    for (k, breakdown_value) in breakdown_values.iter().enumerate() {
+                        row.push(Value::Bar(*breakdown_value));
+
+                        if k + 1 != breakdown_values.len() {
+                            row.push(Value::String(" ".to_string()));
                         }
                     }
-                } else if dag_index != 0 {
-                    assert!(descendant_position.is_some());
-
-                    if let Some(desc_pos) = &descendant_position {
-                        match desc_pos {
-                            Position::At => {
-                                column_chunks.push(Value::Empty);
-                                column_chunks.push(Value::String(" ".to_string()));
-
-                                if config.widget_config.show_aggregate {
-                                    column_chunks.push(Value::Empty);
-                                    column_chunks.push(Value::Empty);
-                                    column_chunks.push(Value::Empty);
-                                }
-                                column_chunks.push(Value::String(format!("-")));
-                            }
-                            Position::Above | Position::Below => {
-                                // TODO: handle this case
-                            }
-                        }
+
+                    row.push(Value::String("|".to_string()));
+                } else {
+                    let aggregate_dims = (primary_dim.clone(), breakdown_dim.clone());
+                    let value = aggregate_apply(
+                        &config.aggregate,
+                        &aggregate_values,
+                        &aggregate_dims,
+                        &mut minimum_value,
+                        &mut maximum_value,
+                    );
+
+                    if config.show_aggregate {
+                        row.push(Value::String(" ".to_string()));
+                        row.push_aggregate(minimal_precision_string(value));
                     }
-                }
 
-                descendant_position.replace(position);
-                column_chunks_reversed.push(column_chunks);
-            }
+                    row.push(Value::String("  ".to_string()));
+                    row.push(Value::String("|".to_string()));
+                    row.push(Value::Bar(value));
 
-            let mut row = Row::default();
+                    if value < minimum_value {
+                        minimum_value = value;
+                    }
 
-            for column_chunks in column_chunks_reversed.into_iter().rev() {
-                for value in column_chunks.into_iter() {
-                    row.push(value);
+                    if value > maximum_value {
+                        maximum_value = value;
+                    }
                 }
             }
 
@@ -522,29 +279,17 @@ where
     }
 }
 
-fn build_preheader(
+fn build_preheader<D>(
     config: &Render<DagChartConfig>,
-    columns: usize,
+    frame: &DagFrame<D>,
     label: &str,
     embed: bool,
-) -> Row {
+) -> Row
+where
+    D: Dimensions + Clone + PartialEq + Eq + Hash + Ord,
+{
     let mut row = Row::default();
-
-    for j in 0..columns {
-        row.push(Value::Empty);
-
-        if j + 1 < columns {
-            row.push(Value::Empty);
-
-            if config.widget_config.show_aggregate {
-                row.push(Value::Empty);
-                row.push(Value::Empty);
-                row.push(Value::Empty);
-            }
-
-            row.push(Value::Empty);
-        }
-    }
+    frame.preheader(&mut row);
 
     if config.show_aggregate {
         row.push(Value::Empty);
@@ -568,53 +313,13 @@ fn build_preheader(
     row
 }
 
-#[derive(Debug, PartialEq, Eq)]
-enum Position {
-    Above,
-    At,
-    Below,
-}
-
-#[derive(Debug)]
-struct Group {
-    locus: Option<String>,
-    index: usize,
-}
-
-impl Default for Group {
-    fn default() -> Self {
-        Self {
-            locus: None,
-            index: 0,
-        }
-    }
-}
-
-impl Group {
-    fn matches(&self, path: &String) -> bool {
-        match &self.locus {
-            Some(l) => l == path,
-            None => false,
-        }
-    }
-
-    fn swap(&mut self, locus: String) {
-        self.locus.replace(locus);
-        self.index = 0;
-    }
-
-    fn increment(&mut self) {
-        self.index += 1;
-    }
-}
-
 #[cfg(test)]
 mod tests {
 
     #[cfg(feature = "primitive_impls")]
     mod primitive_impls {
-        use crate::{DagChart, DagChartConfig, Render};
-        use crate::{DatasetBuilder, Schema1, Schema2, Schemas};
+        use crate::{DagChart, DagChartConfig, Orientation, Render};
+        use crate::{DatasetBuilder, Schema1, Schema2, Schema3, Schemas};
 
         #[test]
         fn empty() {
@@ -753,6 +458,98 @@ abc  |2 3 4|
             );
         }
 
+        #[test]
+        fn vertical() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((-2,))
+                .add((0,))
+                .add((3,))
+                .build();
+            let view = dataset.reflect_1st();
+            let dagchart = DagChart::new(&view);
+            let flat = dagchart.render(Render {
+                orientation: Orientation::Vertical,
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(abc)
+            *
+            *
+            *
+──────────────
+ ⊖
+ ⊖
+ -2    0    3
+[-2]  [0]  [3]
+abc"#
+            );
+        }
+
+        #[test]
+        fn vertical_show_aggregates() {
+            let schema: Schema3<&str, i64, i64> = Schemas::three("animal", "length", "value");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("shark", 4, -3))
+                .add(("shark", 1, -2))
+                .add(("tiger", 5, 7))
+                .add(("tiger", 1, -9))
+                .build();
+            let view = dataset.view_3rd();
+            let dagchart = DagChart::new(&view);
+            let flat = dagchart.render(Render {
+                orientation: Orientation::Vertical,
+                show_aggregate: true,
+                widget_config: DagChartConfig {
+                    show_aggregate: true,
+                    ..DagChartConfig::default()
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(value)
+────────────
+  ⊖      ⊖
+  ⊖      ⊖
+  ⊖
+  ⊖
+  ⊖
+shark  tiger
+[-5]   [-2]
+animal"#
+            );
+        }
+
+        #[test]
+        fn vertical_depth_2() {
+            let schema = Schemas::two("A", "B");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a1", "b1"))
+                .add(("a1", "b2"))
+                .add(("a2", "b1"))
+                .build();
+            let view = dataset.count();
+            let dagchart = DagChart::new(&view);
+            let flat = dagchart.render(Render {
+                orientation: Orientation::Vertical,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(Count)
+*
+*   *
+a1  a2
+A"#
+            );
+        }
+
         #[test]
         fn depth_3_combo111() {
             let schema = Schemas::three("A", "B", "C");
@@ -1,6 +1,8 @@
 use crate::{
-    Schema, Schema1, Schema2, Schema3, Schema4, View1Full, View2Breakdown2ndCount, View2Full,
-    View3Breakdown3rdCount, View3Full, View4Breakdown4thCount, View4Full,
+    Coordinate, Schema, Schema1, Schema2, Schema3, Schema4, View1Full, View2Breakdown2ndCount,
+    View2Full, View3Breakdown3rdCount, View3Bullet, View3Full, View3Interval, View3Matrix,
+    View3Matrix2nd3rd, View4Breakdown4thCount, View4Bullet, View4Full, View4Interval, View4Matrix,
+    View4Matrix2nd3rd4th,
 };
 #[cfg(any(feature = "primitive_impls", feature = "pointer_impls"))]
 use crate::{
@@ -520,6 +522,77 @@ impl<T, U, V> Dataset<Schema3<T, U, V>> {
     }
 }
 
+impl<T, U: Coordinate, V: Coordinate> Dataset<Schema3<T, U, V>> {
+    /// Take an interval view of this 3-dimensional dataset.
+    /// Interval views are rendered by the interval widgets (ex: [`crate::Timeline`]), and
+    /// always have a frame on the left and a rendering on the right.
+    ///
+    /// This view will render the span between the 2nd (start) and 3rd (end) dimensions, and use the first dimension in the frame of the widget.
+    /// ```text
+    /// r#"
+    /// Frame..   | Rendering..
+    /// (dim1, )  | interval(dim2, dim3)"#
+    /// ```
+    ///
+    /// Implemented for `Schema3<_, U, V>` where `U: Coordinate, V: Coordinate`.
+    pub fn interval_2nd_3rd(&self) -> View3Interval<'_, Schema3<T, U, V>> {
+        View3Interval { dataset: self }
+    }
+
+    /// Take a matrix view of this 3-dimensional dataset, leaving out the first dimension (ex: a categorical label).
+    /// Matrix views are rendered by the matrix widgets (ex: [`crate::CorrelationMatrix`]), and
+    /// relate each pair of dimensions to one another.
+    ///
+    /// This view will render the 2nd and 3rd dimensions as variables of the matrix.
+    /// ```text
+    /// r#"
+    ///       | dim2  dim3
+    /// dim2  |
+    /// dim3  |"#
+    /// ```
+    ///
+    /// Implemented for `Schema3<_, U, V>` where `U: Coordinate, V: Coordinate`.
+    pub fn matrix_2nd_3rd(&self) -> View3Matrix2nd3rd<'_, Schema3<T, U, V>> {
+        View3Matrix2nd3rd { dataset: self }
+    }
+
+    /// Take a bullet view of this 3-dimensional dataset.
+    /// Bullet views are rendered by the [`crate::BulletChart`], and
+    /// always have a frame on the left and a rendering on the right.
+    ///
+    /// This view will render the 2nd dimension against the target of the 3rd dimension, and use the first dimension in the frame of the widget.
+    /// ```text
+    /// r#"
+    /// Frame..   | Rendering..
+    /// (dim1, )  | bullet(aggregate(dim2), target=dim3)"#
+    /// ```
+    ///
+    /// Implemented for `Schema3<_, U, V>` where `U: Coordinate, V: Coordinate`.
+    pub fn bullet_2nd_3rd(&self) -> View3Bullet<'_, Schema3<T, U, V>> {
+        View3Bullet { dataset: self }
+    }
+}
+
+impl<T: Coordinate, U: Coordinate, V: Coordinate> Dataset<Schema3<T, U, V>> {
+    /// Take a matrix view of this 3-dimensional dataset.
+    /// Matrix views are rendered by the matrix widgets (ex: [`crate::CorrelationMatrix`]), and
+    /// relate each pair of dimensions to one another.
+    ///
+    /// This view will render all the dimensions (1st, 2nd, 3rd) as variables of the matrix.
+    /// ```text
+    /// r#"
+    ///       | dim1  dim2  dim3
+    /// dim1  |
+    /// dim2  |
+    /// dim3  |"#
+    /// ```
+    ///
+    /// Implemented for `Schema3<T, U, V>` where `T: Coordinate, U: Coordinate, V: Coordinate`.
+    pub fn matrix(&self) -> View3Matrix<'_, Schema3<T, U, V>> {
+        View3Matrix { dataset: self }
+    }
+}
+
 #[cfg(feature = "primitive_impls")]
 mod primitive_impls4 {
     use super::*;
@@ -741,6 +814,84 @@ impl<T, U, V, W> Dataset<Schema4<T, U, V, W>> {
     }
 }
 
+impl<T, U: Coordinate, V: Coordinate, W> Dataset<Schema4<T, U, V, W>> {
+    /// Take an interval view of this 4-dimensional dataset.
+    /// Interval views are rendered by the interval widgets (ex: [`crate::Timeline`]), and
+    /// always have a frame on the left and a rendering on the right.
+    ///
+    /// This view will render the spans between the 2nd (start) and 3rd (end) dimensions, grouped by the final dimension (4th).
+    /// The other dimension (1st) is used in the frame of the widget.
+    /// ```text
+    /// r#"
+    /// Frame..       | Rendering..
+    /// (dim4, dim1)  | interval(dim2, dim3).."#
+    /// ```
+    ///
+    /// Implemented for `Schema4<_, U, V, _>` where `U: Coordinate, V: Coordinate`.
+    pub fn interval_2nd_3rd(&self) -> View4Interval<'_, Schema4<T, U, V, W>> {
+        View4Interval { dataset: self }
+    }
+}
+
+impl<T, U: Coordinate, V: Coordinate, W: Coordinate> Dataset<Schema4<T, U, V, W>> {
+    /// Take a matrix view of this 4-dimensional dataset, leaving out the first dimension (ex: a categorical label).
+    /// Matrix views are rendered by the matrix widgets (ex: [`crate::CorrelationMatrix`]), and
+    /// relate each pair of dimensions to one another.
+    ///
+    /// This view will render the 2nd, 3rd, and 4th dimensions as variables of the matrix.
+    /// ```text
+    /// r#"
+    ///       | dim2  dim3  dim4
+    /// dim2  |
+    /// dim3  |
+    /// dim4  |"#
+    /// ```
+    ///
+    /// Implemented for `Schema4<_, U, V, W>` where `U: Coordinate, V: Coordinate, W: Coordinate`.
+    pub fn matrix_2nd_3rd_4th(&self) -> View4Matrix2nd3rd4th<'_, Schema4<T, U, V, W>> {
+        View4Matrix2nd3rd4th { dataset: self }
+    }
+}
+
+impl<T, U: Coordinate, V: Coordinate, W: AsRef<[f64]>> Dataset<Schema4<T, U, V, W>> {
+    /// Take a bullet view of this 4-dimensional dataset.
+    /// Bullet views are rendered by the [`crate::BulletChart`], and
+    /// always have a frame on the left and a rendering on the right.
+    ///
+    /// This view will render the 2nd dimension against the target of the 3rd dimension and the band thresholds of the 4th dimension (ex: a `Vec<f64>` or `[f64; 2]`), and use the first dimension in the frame of the widget.
+    /// ```text
+    /// r#"
+    /// Frame..   | Rendering..
+    /// (dim1, )  | bullet(aggregate(dim2), target=dim3, bands=dim4)"#
+    /// ```
+    ///
+    /// Implemented for `Schema4<_, U, V, W>` where `U: Coordinate, V: Coordinate, W: AsRef<[f64]>`.
+    pub fn bullet_2nd_3rd_4th(&self) -> View4Bullet<'_, Schema4<T, U, V, W>> {
+        View4Bullet { dataset: self }
+    }
+}
+
+impl<T: Coordinate, U: Coordinate, V: Coordinate, W: Coordinate> Dataset<Schema4<T, U, V, W>> {
+    /// Take a matrix view of this 4-dimensional dataset.
+    /// Matrix views are rendered by the matrix widgets (ex: [`crate::CorrelationMatrix`]), and
+    /// relate each pair of dimensions to one another.
+    ///
+    /// This view will render all the dimensions (1st, 2nd, 3rd, 4th) as variables of the matrix.
+    /// ```text
+    /// r#"
+    ///       | dim1  dim2  dim3  dim4
+    /// dim1  |
+    /// dim2  |
+    /// dim3  |
+    /// dim4  |"#
+    /// ```
+    ///
+    /// Implemented for `Schema4<T, U, V, W>` where `T: Coordinate, U: Coordinate, V: Coordinate, W: Coordinate`.
+    pub fn matrix(&self) -> View4Matrix<'_, Schema4<T, U, V, W>> {
+        View4Matrix { dataset: self }
+    }
+}
+
 impl<S: Schema> DatasetBuilder<S> {
     /// Build a dataset based for the provided schema.
     pub fn new(schema: S) -> DatasetBuilder<S> {
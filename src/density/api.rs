@@ -0,0 +1,22 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::Density;
+
+/// Render configuration specific to [`Density`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::DensityConfig;
+/// let density_config = DensityConfig {
+///     bandwidth: Some(0.5),
+///     ..DensityConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct DensityConfig {
+    /// The bandwidth (standard deviation) of the Gaussian kernel, in the units of the primary dimension.
+    /// Smaller bandwidths follow the samples more closely, while larger bandwidths smooth the curve.
+    ///
+    /// Default: `None`, which estimates the bandwidth of each curve using Silverman's rule of thumb.
+    pub bandwidth: Option<f64>,
+}
@@ -0,0 +1,385 @@
+use crate::aggregate::quantile;
+use crate::canvas::{extent_labels, legend, marker, normalize, Axes, Canvas};
+use crate::render::{Flat, Render};
+use crate::{Coordinate, DensityConfig, Schema, View};
+use std::f64::consts::PI;
+use std::fmt::Display;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::Histogram;
+
+/// The number of bandwidths by which the x-axis extends beyond the samples, so that the tails of the curves taper off.
+const TAIL_BANDWIDTHS: f64 = 3.0;
+
+/// The kernel density estimate (kde) widget.
+///
+/// A density draws the smooth distribution of the view's primary dimension (1st, x-axis), estimated with a Gaussian kernel.
+/// The primary dimension must be a [`Coordinate`] type (ex: the views from `reflect_1st` or `count`).
+/// Unlike the [`Histogram`], the shape of the density does not depend on a bin count.
+///
+/// The bandwidth of the kernel is estimated from the samples using Silverman's rule of thumb.
+/// Alternatively, set the [`DensityConfig::bandwidth`] to override the estimate.
+///
+/// The density is drawn as a curve, sized to the `width_hint` and `height_hint` of the [`Render`] configuration.
+/// Without a breakdown, the curve is drawn at the braille (U+2800) sub-character resolution.
+/// With a breakdown, each breakdown value is drawn as an overlapping curve with a distinct marker, labelled in the legend.
+///
+/// See also: [`Histogram`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema1<f64> = Schemas::one("Length");
+/// let mut builder = DatasetBuilder::new(schema);
+///
+/// for length in [4.3, 4.9, 5.0, 5.1, 5.4, 5.7, 6.0, 6.4, 6.9, 7.0] {
+///     builder.update((length,));
+/// }
+///
+/// let dataset = builder.build();
+/// let view = dataset.reflect_1st();
+/// let flat = Density::new(&view)
+///     .render(Render {
+///         width_hint: 30,
+///         height_hint: 10,
+///         widget_config: DensityConfig {
+///             bandwidth: Some(0.5),
+///         },
+///         ..Render::default()
+///     });
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Density(Length)
+/// 0.4 │         ⢀⠔⠉⠑⢢
+///     │        ⢠⠃    ⠑⠢⢄⣀
+///     │       ⢠⠃         ⠑⢄
+///     │      ⡠⠃            ⠑⡄
+///     │     ⡔⠁              ⠘⢄
+///   0 │⣀⣀⡠⠔⠊                  ⠑⠤⣀⣀
+///     └───────────────────────────
+///      2.8                     8.5
+///      Length"#);
+/// # }
+/// ```
+pub struct Density<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> Density<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Coordinate,
+    <V as View<S>>::BreakdownDimension: Clone + Display + PartialEq + Ord,
+{
+    /// Construct a density widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this density.
+    pub fn render(self, config: Render<DensityConfig>) -> Flat {
+        let mut samples: Vec<(f64, V::BreakdownDimension)> = Vec::default();
+        let mut sort_breakdowns: Vec<V::BreakdownDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let x = self.view.primary_dim(dims).coordinate();
+            let breakdown_dim = self.view.breakdown_dim(dims);
+
+            if !sort_breakdowns.contains(&breakdown_dim) {
+                sort_breakdowns.push(breakdown_dim.clone());
+            }
+
+            samples.push((x, breakdown_dim));
+        }
+
+        sort_breakdowns.sort();
+
+        // The sorted samples of each series, along with their bandwidth.
+        let series: Vec<(Vec<f64>, f64)> = sort_breakdowns
+            .iter()
+            .map(|breakdown_dim| {
+                let mut xs: Vec<f64> = samples
+                    .iter()
+                    .filter(|(_, b)| b == breakdown_dim)
+                    .map(|(x, _)| *x)
+                    .collect();
+                xs.sort_by(|a, b| a.total_cmp(b));
+                let bandwidth = match config.widget_config.bandwidth {
+                    Some(bandwidth) if bandwidth > 0.0 => bandwidth,
+                    _ => silverman(&xs),
+                };
+                (xs, bandwidth)
+            })
+            .collect();
+
+        let x_extent = series
+            .iter()
+            .filter_map(|(xs, bandwidth)| {
+                let tail = bandwidth * TAIL_BANDWIDTHS;
+                Some((xs.first()? - tail, xs.last()? + tail))
+            })
+            .reduce(|(a_minimum, a_maximum), (b_minimum, b_maximum)| {
+                (a_minimum.min(b_minimum), a_maximum.max(b_maximum))
+            })
+            .unwrap_or((0.0, 0.0));
+        let header = self.view.display_headers()[0].clone();
+        let mut axes = Axes {
+            x_title: header.clone(),
+            x_labels: extent_labels(x_extent),
+            y_title: format!("Density({header})"),
+            y_extent: (0.0, 0.0),
+            legend: self.view.breakdown_label().map(|breakdown_header| {
+                let names: Vec<String> = sort_breakdowns.iter().map(|b| b.to_string()).collect();
+                legend(&breakdown_header, &names)
+            }),
+        };
+        let (x_minimum, x_maximum) = x_extent;
+        let (canvas_width, canvas_height) = axes.canvas_size(config.width_hint, config.height_hint);
+        // Evaluate the curves at the braille resolution of the canvas.
+        let steps = canvas_width * 2;
+        let curves: Vec<Vec<(f64, f64)>> = series
+            .iter()
+            .map(|(xs, bandwidth)| {
+                (0..=steps)
+                    .map(|i| {
+                        let x = x_minimum + (x_maximum - x_minimum) * i as f64 / steps as f64;
+                        (x, gaussian_kde(xs, *bandwidth, x))
+                    })
+                    .collect()
+            })
+            .collect();
+        let y_maximum = curves
+            .iter()
+            .flatten()
+            .fold(0.0, |maximum: f64, (_, y)| maximum.max(*y));
+        axes.y_extent = (0.0, y_maximum);
+        let mut canvas = Canvas::new(canvas_width, canvas_height);
+
+        for (k, curve) in curves.iter().enumerate() {
+            let unit_points: Vec<(f64, f64)> = curve
+                .iter()
+                .map(|(x, y)| {
+                    (
+                        normalize(*x, x_minimum, x_maximum),
+                        normalize(*y, 0.0, y_maximum),
+                    )
+                })
+                .collect();
+
+            for segment in unit_points.windows(2) {
+                let [(x1, y1), (x2, y2)] = [segment[0], segment[1]];
+
+                if self.view.breakdown_label().is_some() {
+                    canvas.marker_line(x1, y1, x2, y2, marker(k));
+                } else {
+                    canvas.line(x1, y1, x2, y2);
+                }
+            }
+        }
+
+        let grid = axes.grid(&canvas);
+        Flat::new(config, 0.0..y_maximum, grid)
+    }
+}
+
+/// Estimate the kernel bandwidth for the (sorted) samples `xs`, using Silverman's rule of thumb: `0.9 * min(σ, IQR / 1.34) * n^(-1/5)`.
+/// When the samples don't spread (ex: a single sample), the bandwidth falls back to `1`.
+fn silverman(xs: &[f64]) -> f64 {
+    if xs.len() < 2 {
+        return 1.0;
+    }
+
+    let n = xs.len() as f64;
+    let mean = xs.iter().sum::<f64>() / n;
+    let deviation = (xs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+    let spread = (quantile(xs, 0.75) - quantile(xs, 0.25)) / 1.34;
+    // The interquartile range collapses when most of the samples are equal, in which case only the deviation is used.
+    let scale = if spread > 0.0 {
+        deviation.min(spread)
+    } else {
+        deviation
+    };
+
+    if scale > 0.0 {
+        0.9 * scale * n.powf(-0.2)
+    } else {
+        1.0
+    }
+}
+
+/// Evaluate the Gaussian kernel density estimate of the samples `xs` at the position `x`.
+fn gaussian_kde(xs: &[f64], bandwidth: f64, x: f64) -> f64 {
+    if xs.is_empty() {
+        return 0.0;
+    }
+
+    let sum: f64 = xs
+        .iter()
+        .map(|sample| (-0.5 * ((x - sample) / bandwidth).powi(2)).exp())
+        .sum();
+    sum / (xs.len() as f64 * bandwidth * (2.0 * PI).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn silverman_bandwidth() {
+        assert_eq!(silverman(&[]), 1.0);
+        assert_eq!(silverman(&[3.0]), 1.0);
+        assert_eq!(silverman(&[3.0, 3.0, 3.0]), 1.0);
+        // σ = 1.58, IQR / 1.34 = 1.49
+        let bandwidth = silverman(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert!((bandwidth - 0.9 * (2.0 / 1.34) * 5f64.powf(-0.2)).abs() < 1e-9);
+        // IQR = 0, so only σ is used.
+        let bandwidth = silverman(&[1.0, 1.0, 1.0, 1.0, 5.0]);
+        assert!((bandwidth - 0.9 * 1.7888543819998317 * 5f64.powf(-0.2)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn gaussian_kde_values() {
+        assert_eq!(gaussian_kde(&[], 1.0, 0.0), 0.0);
+        let peak = 1.0 / (2.0 * PI).sqrt();
+        assert!((gaussian_kde(&[0.0], 1.0, 0.0) - peak).abs() < 1e-12);
+        assert!((gaussian_kde(&[0.0], 2.0, 0.0) - peak / 2.0).abs() < 1e-12);
+        assert!(
+            (gaussian_kde(&[-1.0, 1.0], 1.0, -1.0) - gaussian_kde(&[-1.0, 1.0], 1.0, 1.0)).abs()
+                < 1e-12
+        );
+        assert!(gaussian_kde(&[0.0], 1.0, 5.0) < 1e-5);
+    }
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{DatasetBuilder, Schema1, Schema2, Schemas};
+        use crate::{Density, DensityConfig, Render};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let density = Density::new(&view);
+            let flat = density.render(Render {
+                width_hint: 12,
+                height_hint: 6,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Density(abc)
+0 │
+0 │
+  └─────────
+   0       0
+   abc"#
+            );
+        }
+
+        #[test]
+        fn single() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).add((4,)).build();
+            let view = dataset.reflect_1st();
+            let density = Density::new(&view);
+            let flat = density.render(Render {
+                width_hint: 14,
+                height_hint: 8,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Density(abc)
+0.4 │    ⡠⠚⢄
+    │   ⢰⠁ ⠈⡆
+    │  ⢠⠃   ⠘⡄
+  0 │⣀⠤⠃     ⠘⠤⣀
+    └───────────
+     1         7
+     abc"#
+            );
+        }
+
+        #[test]
+        fn bandwidth() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1,))
+                .add((2,))
+                .add((2,))
+                .add((8,))
+                .build();
+            let view = dataset.reflect_1st();
+            let density = Density::new(&view);
+            let flat = density.render(Render {
+                width_hint: 20,
+                height_hint: 8,
+                widget_config: DensityConfig {
+                    bandwidth: Some(1.0),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Density(abc)
+0.3 │   ⢀⠎⢱
+    │   ⡜  ⢇
+    │  ⢠⠃  ⠘⡄   ⢀⠔⢄
+  0 │⣀⠤⠃    ⠘⢤⣀⠔⠁  ⠑⢄⣀
+    └─────────────────
+     -2             11
+     abc"#
+            );
+        }
+
+        #[test]
+        fn breakdown() {
+            let schema: Schema2<i64, &str> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a"))
+                .add((2, "a"))
+                .add((3, "a"))
+                .add((6, "b"))
+                .add((7, "b"))
+                .build();
+            let view = dataset.count_breakdown_2nd();
+            let density = Density::new(&view);
+            let flat = density.render(Render {
+                width_hint: 24,
+                height_hint: 10,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Density(abc)
+0.7 │               oo o
+    │               ooo o
+    │    ******     o o o
+    │  **     **   o    o
+  0 │ooooooooooooooo*****o
+    └─────────────────────
+     -0.6              7.9
+     abc
+     def: * a  o b"#
+            );
+        }
+    }
+}
@@ -0,0 +1,23 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::Ecdf;
+
+/// Render configuration specific to [`Ecdf`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::EcdfConfig;
+/// let ecdf_config = EcdfConfig {
+///     quantiles: Some(vec![0.1, 0.25, 0.5, 0.9, 0.99]),
+///     ..EcdfConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct EcdfConfig {
+    /// The quantiles (in the range `[0, 1]`) to list as rows, instead of drawing the step curve.
+    /// For example, `0.5` lists the median as the row `p50`.
+    /// Quantiles outside of the range `[0, 1]` are left out.
+    ///
+    /// Default: `None`.
+    pub quantiles: Option<Vec<f64>>,
+}
@@ -0,0 +1,569 @@
+use crate::aggregate::minimal_precision_string;
+use crate::canvas::{legend, marker, Axes, Canvas};
+use crate::histogram::{bin_ranges, Bounds};
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{Binnable, EcdfConfig, Schema, View};
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::Histogram;
+
+/// The empirical cumulative distribution (ecdf) widget.
+///
+/// An ecdf draws the fraction of the samples that fall at or below each position of the view's primary dimension (1st, x-axis).
+/// The primary dimension must be a [`Binnable`] type (ex: the views from `reflect_1st` or `count`).
+/// Unlike the [`Histogram`], the ecdf does not depend on a bin count.
+///
+/// By default, the ecdf is drawn as a step curve, sized to the `width_hint` and `height_hint` of the [`Render`] configuration.
+/// Without a breakdown, the curve is drawn at the braille (U+2800) sub-character resolution.
+/// With a breakdown, each breakdown value is drawn as a separate curve with a distinct marker, labelled in the legend.
+///
+/// Alternatively, set the [`EcdfConfig::quantiles`] to list the quantiles (ex: `p50`, `p99`) as rows, with a column per breakdown value.
+/// The quantiles are read off the step curve: each is the smallest sample at which the curve reaches the quantile's fraction.
+///
+/// See also: [`Histogram`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema1<u32> = Schemas::one("Latency");
+/// let mut builder = DatasetBuilder::new(schema);
+///
+/// for latency in [10, 12, 12, 13, 15, 15, 16, 20, 35, 80] {
+///     builder.update((latency,));
+/// }
+///
+/// let dataset = builder.build();
+/// let view = dataset.reflect_1st();
+/// let flat = Ecdf::new(&view)
+///     .render(Render {
+///         widget_config: EcdfConfig {
+///             quantiles: Some(vec![0.5, 0.9, 0.95]),
+///         },
+///         ..Render::default()
+///     });
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Quantile  Latency
+/// p50            15
+/// p90            35
+/// p95            80"#);
+/// # }
+/// ```
+pub struct Ecdf<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> Ecdf<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone
+        + Display
+        + PartialEq
+        + PartialOrd
+        + Add<<V as View<S>>::PrimaryDimension, Output = <V as View<S>>::PrimaryDimension>
+        + Sub<<V as View<S>>::PrimaryDimension, Output = <V as View<S>>::PrimaryDimension>
+        + Binnable,
+    <V as View<S>>::BreakdownDimension: Clone + Display + PartialEq + Ord,
+{
+    /// Construct an ecdf widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this ecdf.
+    pub fn render(self, config: Render<EcdfConfig>) -> Flat {
+        let mut samples: Vec<(V::PrimaryDimension, V::BreakdownDimension)> = Vec::default();
+        let mut sort_breakdowns: Vec<V::BreakdownDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let x = self.view.primary_dim(dims);
+            let breakdown_dim = self.view.breakdown_dim(dims);
+
+            if !sort_breakdowns.contains(&breakdown_dim) {
+                sort_breakdowns.push(breakdown_dim.clone());
+            }
+
+            samples.push((x, breakdown_dim));
+        }
+
+        sort_breakdowns.sort();
+
+        // The sorted samples of each series.
+        let series: Vec<Vec<V::PrimaryDimension>> = sort_breakdowns
+            .iter()
+            .map(|breakdown_dim| {
+                let mut xs: Vec<V::PrimaryDimension> = samples
+                    .iter()
+                    .filter(|(_, b)| b == breakdown_dim)
+                    .map(|(x, _)| x.clone())
+                    .collect();
+                xs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+                xs
+            })
+            .collect();
+
+        match &config.widget_config.quantiles {
+            Some(quantiles) => {
+                let grid = self.quantile_grid(quantiles, &sort_breakdowns, &series);
+                Flat::new(config, 0.0..0.0, grid)
+            }
+            None => {
+                let grid = self.curve_grid(&config, &sort_breakdowns, &series);
+                Flat::new(config, 0.0..1.0, grid)
+            }
+        }
+    }
+
+    fn quantile_grid(
+        &self,
+        quantiles: &[f64],
+        sort_breakdowns: &[V::BreakdownDimension],
+        series: &[Vec<V::PrimaryDimension>],
+    ) -> Grid {
+        // There is always a value column for the header, even when there are no samples.
+        let value_columns = std::cmp::max(series.len(), 1);
+        let mut columns = Columns::default();
+        // quantile label
+        columns.push(Column::string(Alignment::Left));
+
+        for _ in 0..value_columns {
+            // spacer "  "
+            columns.push(Column::string(Alignment::Center));
+            // quantile value
+            columns.push(Column::string(Alignment::Right));
+        }
+
+        let mut grid = Grid::new(columns);
+        let mut header = Row::default();
+        header.push(Value::String("Quantile".to_string()));
+
+        match self.view.breakdown_label() {
+            Some(breakdown_header) => {
+                let mut preheader = Row::default();
+                preheader.push(Value::Empty);
+                preheader.push(Value::Empty);
+                preheader.push(Value::Plain(breakdown_header));
+                grid.add(preheader);
+
+                for breakdown_dim in sort_breakdowns.iter() {
+                    header.push(Value::String("  ".to_string()));
+                    header.push(Value::String(breakdown_dim.to_string()));
+                }
+            }
+            None => {
+                header.push(Value::String("  ".to_string()));
+                header.push(Value::String(self.view.display_headers()[0].clone()));
+            }
+        }
+
+        grid.add(header);
+
+        // Quantiles outside of the range [0, 1] are left out.
+        for p in quantiles.iter().filter(|p| (0.0..=1.0).contains(*p)) {
+            let mut row = Row::default();
+            row.push(Value::String(format!(
+                "p{}",
+                minimal_precision_string((p * 1000.0).round() / 10.0)
+            )));
+
+            for xs in series.iter() {
+                row.push(Value::String("  ".to_string()));
+
+                match step_quantile(xs, *p) {
+                    Some(x) => row.push(Value::String(x.to_string())),
+                    None => row.push(Value::Empty),
+                }
+            }
+
+            grid.add(row);
+        }
+
+        grid
+    }
+
+    fn curve_grid(
+        &self,
+        config: &Render<EcdfConfig>,
+        sort_breakdowns: &[V::BreakdownDimension],
+        series: &[Vec<V::PrimaryDimension>],
+    ) -> Grid {
+        let header = self.view.display_headers()[0].clone();
+        let samples = || series.iter().flatten();
+        let x_minimum = samples().min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let x_maximum = samples().max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let axes = Axes {
+            x_title: header.clone(),
+            x_labels: match (x_minimum, x_maximum) {
+                (Some(minimum), Some(maximum)) => [minimum.to_string(), maximum.to_string()],
+                _ => ["0".to_string(), "0".to_string()],
+            },
+            y_title: format!("Fraction(≤ {header})"),
+            y_extent: (0.0, 1.0),
+            legend: self.view.breakdown_label().map(|breakdown_header| {
+                let names: Vec<String> = sort_breakdowns.iter().map(|b| b.to_string()).collect();
+                legend(&breakdown_header, &names)
+            }),
+        };
+        let (canvas_width, canvas_height) = axes.canvas_size(config.width_hint, config.height_hint);
+        let mut canvas = Canvas::new(canvas_width, canvas_height);
+        // Position the samples along the x-axis by binning them at the resolution of the canvas.
+        let resolution = if self.view.breakdown_label().is_some() {
+            canvas_width
+        } else {
+            canvas_width * 2
+        };
+        let x_ranges = positions(samples().cloned(), resolution);
+
+        for (k, xs) in series.iter().enumerate() {
+            let unit_points: Vec<(f64, f64)> = steps(xs)
+                .into_iter()
+                .map(|(x, y)| (position(&x_ranges, &x), y))
+                .collect();
+
+            for segment in unit_points.windows(2) {
+                let [(x1, y1), (x2, y2)] = [segment[0], segment[1]];
+
+                if self.view.breakdown_label().is_some() {
+                    canvas.marker_line(x1, y1, x2, y2, marker(k));
+                } else {
+                    canvas.line(x1, y1, x2, y2);
+                }
+            }
+        }
+
+        axes.grid(&canvas)
+    }
+}
+
+/// Divide the range spanned by the `values` into (at most) `resolution` bins, leaving out any bins beyond the maximum value.
+/// Integer bins are rounded up in size, so they may run out before all the `resolution` bins are used.
+fn positions<T>(values: impl Iterator<Item = T> + Clone, resolution: usize) -> Vec<Bounds<T>>
+where
+    T: Clone + PartialOrd + Add<T, Output = T> + Sub<T, Output = T> + Binnable,
+{
+    let mut ranges = bin_ranges(values.clone(), resolution);
+
+    if let Some(maximum) = values.max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal)) {
+        if let Some(last) = ranges.iter().position(|bounds| bounds.contains(&maximum)) {
+            ranges.truncate(last + 1);
+        }
+    }
+
+    ranges
+}
+
+/// Find the unit position of the `x` along the `ranges`, where the first range is at `0` and the last range is at `1`.
+/// A single range positions the `x` in the center.
+fn position<T: PartialOrd>(ranges: &[Bounds<T>], x: &T) -> f64 {
+    let index = ranges
+        .iter()
+        .position(|bounds| bounds.contains(x))
+        .expect("x must map to one of the position ranges");
+
+    if ranges.len() == 1 {
+        0.5
+    } else {
+        index as f64 / (ranges.len() - 1) as f64
+    }
+}
+
+/// Find the `p` quantile of the (sorted) samples `xs`, as read off the step curve.
+/// This is the smallest sample at which the curve reaches the fraction `p` (or the first sample, for `p = 0`).
+fn step_quantile<T: Clone>(xs: &[T], p: f64) -> Option<T> {
+    let rank = (p * xs.len() as f64).ceil() as usize;
+    xs.get(rank.saturating_sub(1)).cloned()
+}
+
+/// Trace the step curve of the (sorted) samples `xs`, as the corners `(x, fraction)` of the steps.
+/// The curve rises from `0` at the first sample to `1` at the last sample.
+fn steps<T: Clone + PartialEq>(xs: &[T]) -> Vec<(T, f64)> {
+    let mut corners: Vec<(T, f64)> = Vec::default();
+    let mut fraction = 0.0;
+
+    for (i, x) in xs.iter().enumerate() {
+        // Only step once per distinct sample.
+        if xs.get(i + 1) == Some(x) {
+            continue;
+        }
+
+        corners.push((x.clone(), fraction));
+        fraction = (i + 1) as f64 / xs.len() as f64;
+        corners.push((x.clone(), fraction));
+    }
+
+    corners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_quantiles() {
+        assert_eq!(step_quantile::<f64>(&[], 0.5), None);
+        assert_eq!(step_quantile(&[1.0, 2.0, 3.0, 4.0], 0.0), Some(1.0));
+        assert_eq!(step_quantile(&[1.0, 2.0, 3.0, 4.0], 0.25), Some(1.0));
+        assert_eq!(step_quantile(&[1.0, 2.0, 3.0, 4.0], 0.26), Some(2.0));
+        assert_eq!(step_quantile(&[1.0, 2.0, 3.0, 4.0], 0.5), Some(2.0));
+        assert_eq!(step_quantile(&[1.0, 2.0, 3.0, 4.0], 1.0), Some(4.0));
+    }
+
+    #[test]
+    fn unit_positions() {
+        let ranges = positions([1, 4].into_iter(), 20);
+        assert_eq!(ranges.len(), 4);
+        assert_eq!(position(&ranges, &1), 0.0);
+        assert_eq!(position(&ranges, &2), 1.0 / 3.0);
+        assert_eq!(position(&ranges, &4), 1.0);
+        let ranges = positions([2.0, 2.0].into_iter(), 20);
+        assert_eq!(position(&ranges, &2.0), 0.5);
+    }
+
+    #[test]
+    fn steps_corners() {
+        assert_eq!(steps::<f64>(&[]), vec![]);
+        assert_eq!(steps(&[1.0]), vec![(1.0, 0.0), (1.0, 1.0)]);
+        assert_eq!(
+            steps(&[1.0, 2.0, 2.0, 4.0]),
+            vec![
+                (1.0, 0.0),
+                (1.0, 0.25),
+                (2.0, 0.25),
+                (2.0, 0.75),
+                (4.0, 0.75),
+                (4.0, 1.0)
+            ]
+        );
+    }
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{DatasetBuilder, Schema1, Schema2, Schemas};
+        use crate::{Ecdf, EcdfConfig, Render};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let ecdf = Ecdf::new(&view);
+            let flat = ecdf.render(Render {
+                width_hint: 12,
+                height_hint: 6,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Fraction(≤ abc)
+1 │
+0 │
+  └─────────
+   0       0
+   abc"#
+            );
+        }
+
+        #[test]
+        fn curve() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1,))
+                .add((2,))
+                .add((2,))
+                .add((8,))
+                .build();
+            let view = dataset.reflect_1st();
+            let ecdf = Ecdf::new(&view);
+            let flat = ecdf.render(Render {
+                width_hint: 14,
+                height_hint: 8,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Fraction(≤ abc)
+1 │          ⢸
+  │ ⢸⠉⠉⠉⠉⠉⠉⠉⠉⠉
+  │⣀⣸
+0 │⡇
+  └───────────
+   1         8
+   abc"#
+            );
+        }
+
+        #[test]
+        fn curve_breakdown() {
+            let schema: Schema2<i64, &str> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a"))
+                .add((2, "a"))
+                .add((3, "b"))
+                .add((6, "b"))
+                .build();
+            let view = dataset.count_breakdown_2nd();
+            let ecdf = Ecdf::new(&view);
+            let flat = ecdf.render(Render {
+                width_hint: 14,
+                height_hint: 9,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Fraction(≤ abc)
+1 │  *       o
+  │
+  │*** ooooooo
+0 │*   o
+  └───────────
+   1         6
+   abc
+   def: * a  o b"#
+            );
+        }
+
+        #[test]
+        fn quantiles() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let mut builder = DatasetBuilder::new(schema);
+
+            for i in 1..=100 {
+                builder.update((i,));
+            }
+
+            let dataset = builder.build();
+            let view = dataset.reflect_1st();
+            let ecdf = Ecdf::new(&view);
+            let flat = ecdf.render(Render {
+                widget_config: EcdfConfig {
+                    quantiles: Some(vec![0.1, 0.25, 0.5, 0.9, 0.99, 0.999]),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Quantile  abc
+p10        10
+p25        25
+p50        50
+p90        90
+p99        99
+p99.9     100"#
+            );
+        }
+
+        #[test]
+        fn quantiles_empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let ecdf = Ecdf::new(&view);
+            let flat = ecdf.render(Render {
+                widget_config: EcdfConfig {
+                    quantiles: Some(vec![0.5]),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Quantile  abc
+p50"#
+            );
+        }
+
+        #[test]
+        fn quantiles_breakdown_empty() {
+            let schema: Schema2<i64, &str> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.count_breakdown_2nd();
+            let ecdf = Ecdf::new(&view);
+            let flat = ecdf.render(Render {
+                widget_config: EcdfConfig {
+                    quantiles: Some(vec![0.5]),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+        def
+Quantile
+p50"#
+            );
+        }
+
+        #[test]
+        fn quantiles_out_of_range() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1,))
+                .add((2,))
+                .add((3,))
+                .build();
+            let view = dataset.reflect_1st();
+            let ecdf = Ecdf::new(&view);
+            let flat = ecdf.render(Render {
+                widget_config: EcdfConfig {
+                    quantiles: Some(vec![-0.1, 0.5, 1.5, f64::NAN]),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Quantile  abc
+p50         2"#
+            );
+        }
+
+        #[test]
+        fn quantiles_breakdown() {
+            let schema: Schema2<i64, &str> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a"))
+                .add((2, "a"))
+                .add((3, "b"))
+                .add((6, "b"))
+                .add((9, "b"))
+                .build();
+            let view = dataset.count_breakdown_2nd();
+            let ecdf = Ecdf::new(&view);
+            let flat = ecdf.render(Render {
+                widget_config: EcdfConfig {
+                    quantiles: Some(vec![0.0, 0.5, 1.0]),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+          def
+Quantile  a  b
+p0        1  3
+p50       1  6
+p100      2  9"#
+            );
+        }
+    }
+}
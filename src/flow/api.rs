@@ -0,0 +1,15 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::Flow;
+
+/// Render configuration specific to [`Flow`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::FlowConfig;
+/// let flow_config = FlowConfig {
+///     ..FlowConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct FlowConfig {}
@@ -0,0 +1,361 @@
+use crate::aggregate::{aggregate_apply, minimal_precision_string};
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{FlowConfig, Schema, View};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::DagChart;
+
+/// The flow (sankey-like) widget.
+///
+/// A flow represents how the value moves from each unique value of the view's primary dimension (1st, left-hand side) to each unique value of the breakdown dimension (right-hand side).
+/// Each left-hand value is listed with its total, followed by its flows towards the right-hand values, which are also listed with their totals.
+/// The flows are drawn as bars proportional to their volume.
+/// The totals are always shown, since they are part of the flow, so the `show_aggregate` option of the [`Render`] configuration is ignored.
+///
+/// The flow requires a breakdown view (ex: `count_breakdown_2nd` or `view_3rd_breakdown_2nd`).
+/// Unlike the [`DagChart`], which shows the hierarchy from the primary dimension, the flow shows the volume exchanged between two categorical dimensions.
+///
+/// See also: [`DagChart`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema3<&str, &str, u32> = Schemas::three("Source", "Destination", "Volume");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("coal", "industry", 6))
+///     .add(("coal", "power", 10))
+///     .add(("gas", "homes", 8))
+///     .add(("gas", "power", 4))
+///     .add(("solar", "homes", 3))
+///     .build();
+/// let view = dataset.view_3rd_breakdown_2nd();
+/// let flow = Flow::new(&view)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flow),
+///     r#"
+/// Source Sum      Destination Sum   Volume  |Sum(Volume)
+/// coal   [16] ─┬→ industry    [ 6]       6  |******
+///              └→ power       [14]      10  |**********
+/// gas    [12] ─┬→ homes       [11]       8  |********
+///              └→ power       [14]       4  |****
+/// solar  [ 3] ──→ homes       [11]       3  |***"#);
+/// # }
+/// ```
+pub struct Flow<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> Flow<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+    <V as View<S>>::BreakdownDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+{
+    /// Construct a flow widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this flow.
+    pub fn render(self, config: Render<FlowConfig>) -> Flat {
+        let mut aggregate_values: HashMap<(V::PrimaryDimension, V::BreakdownDimension), Vec<f64>> =
+            HashMap::default();
+        let mut left_values: HashMap<V::PrimaryDimension, Vec<f64>> = HashMap::default();
+        let mut right_values: HashMap<V::BreakdownDimension, Vec<f64>> = HashMap::default();
+        let mut sort_primaries: Vec<V::PrimaryDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let value = self.view.value(dims);
+            let primary_dim = self.view.primary_dim(dims);
+            let breakdown_dim = self.view.breakdown_dim(dims);
+            let aggregate_dims = (primary_dim.clone(), breakdown_dim.clone());
+            aggregate_values
+                .entry(aggregate_dims)
+                .or_default()
+                .push(value);
+            left_values
+                .entry(primary_dim.clone())
+                .or_default()
+                .push(value);
+            right_values.entry(breakdown_dim).or_default().push(value);
+
+            if !sort_primaries.contains(&primary_dim) {
+                sort_primaries.push(primary_dim);
+            }
+        }
+
+        sort_primaries.sort();
+
+        let mut columns = Columns::default();
+        // left value
+        columns.push(Column::string(Alignment::Left));
+        // spacer " "
+        columns.push(Column::string(Alignment::Center));
+        // left total [
+        columns.push(Column::string(Alignment::Left));
+        // left total value
+        columns.push(Column::string(Alignment::Right));
+        // left total ]
+        columns.push(Column::string(Alignment::Left));
+        // spacer " "
+        columns.push(Column::string(Alignment::Center));
+        // connector
+        columns.push(Column::string(Alignment::Left));
+        // spacer " "
+        columns.push(Column::string(Alignment::Center));
+        // right value
+        columns.push(Column::string(Alignment::Left));
+        // spacer " "
+        columns.push(Column::string(Alignment::Center));
+        // right total [
+        columns.push(Column::string(Alignment::Left));
+        // right total value
+        columns.push(Column::string(Alignment::Right));
+        // right total ]
+        columns.push(Column::string(Alignment::Left));
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // flow volume
+        columns.push(Column::string(Alignment::Right));
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // rendering delimiter |
+        columns.push(Column::string(Alignment::Center));
+        // flow count
+        columns.push(Column::count(Alignment::Left));
+
+        let mut grid = Grid::new(columns);
+        let mut header = Row::default();
+        header.label(self.view.display_headers()[0].clone());
+        header.push(Value::String(self.view.display_headers()[0].clone()));
+        header.push(Value::String(" ".to_string()));
+        header.push(Value::Overflow(config.aggregate.to_string()));
+        header.push(Value::Skip);
+        header.push(Value::Skip);
+        header.push(Value::String(" ".to_string()));
+        header.push(Value::Empty);
+        header.push(Value::String(" ".to_string()));
+        header.push(Value::String(
+            self.view.breakdown_label().unwrap_or_default(),
+        ));
+        header.push(Value::String(" ".to_string()));
+        header.push(Value::Overflow(config.aggregate.to_string()));
+        header.push(Value::Skip);
+        header.push(Value::Skip);
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String(self.view.value_label()));
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("|".to_string()));
+        header.push(Value::Plain(format!(
+            "{}({})",
+            config.aggregate,
+            self.view.value_label()
+        )));
+        grid.add(header);
+
+        let mut sort_breakdowns: Vec<V::BreakdownDimension> =
+            right_values.keys().cloned().collect();
+        sort_breakdowns.sort();
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+
+        for primary_dim in sort_primaries.iter() {
+            let flows: Vec<(&V::BreakdownDimension, f64)> = sort_breakdowns
+                .iter()
+                .filter_map(|breakdown_dim| {
+                    let aggregate_dims = (primary_dim.clone(), breakdown_dim.clone());
+
+                    if aggregate_values.contains_key(&aggregate_dims) {
+                        let value = aggregate_apply(
+                            &config.aggregate,
+                            &aggregate_values,
+                            &aggregate_dims,
+                            &mut minimum_value,
+                            &mut maximum_value,
+                        );
+                        Some((breakdown_dim, value))
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            for (k, (breakdown_dim, value)) in flows.iter().enumerate() {
+                let mut row = Row::default();
+                row.label(format!("{primary_dim} → {breakdown_dim}"));
+
+                if k == 0 {
+                    row.push(Value::String(primary_dim.to_string()));
+                    row.push(Value::String(" ".to_string()));
+                    row.push_aggregate(minimal_precision_string(
+                        config.aggregate.apply(left_values[primary_dim].as_slice()),
+                    ));
+                } else {
+                    row.push(Value::Empty);
+                    row.push(Value::Empty);
+                    row.push(Value::Empty);
+                    row.push(Value::Empty);
+                    row.push(Value::Empty);
+                }
+
+                row.push(Value::String(" ".to_string()));
+                row.push(Value::String(connector(k, flows.len()).to_string()));
+                row.push(Value::String(" ".to_string()));
+                row.push(Value::String(breakdown_dim.to_string()));
+                row.push(Value::String(" ".to_string()));
+                row.push_aggregate(minimal_precision_string(
+                    config
+                        .aggregate
+                        .apply(right_values[*breakdown_dim].as_slice()),
+                ));
+                row.push(Value::String("  ".to_string()));
+                row.push(Value::String(minimal_precision_string(*value)));
+                row.push(Value::String("  ".to_string()));
+                row.push(Value::String("|".to_string()));
+                row.push(Value::Bar(*value));
+                grid.add(row);
+            }
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+/// The connector which leads from a left-hand value into its `k`th of `n` flows.
+fn connector(k: usize, n: usize) -> &'static str {
+    match (k, n) {
+        (_, 1) => "──→",
+        (0, _) => "─┬→",
+        (k, n) if k + 1 == n => " └→",
+        _ => " ├→",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connectors() {
+        assert_eq!(connector(0, 1), "──→");
+        assert_eq!(connector(0, 2), "─┬→");
+        assert_eq!(connector(1, 2), " └→");
+        assert_eq!(connector(1, 3), " ├→");
+        assert_eq!(connector(2, 3), " └→");
+    }
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{Aggregate, Flow, Render};
+        use crate::{DatasetBuilder, Schema2, Schema3, Schemas};
+
+        #[test]
+        fn empty() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.count_breakdown_2nd();
+            let flow = Flow::new(&view);
+            let flat = flow.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc Sum  def Sum  Count  |Sum(Count)"#
+            );
+        }
+
+        #[test]
+        fn count() {
+            let schema: Schema2<&str, &str> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", "x"))
+                .add(("a", "x"))
+                .add(("a", "y"))
+                .add(("b", "y"))
+                .add(("c", "x"))
+                .add(("c", "y"))
+                .add(("c", "z"))
+                .build();
+            let view = dataset.count_breakdown_2nd();
+            let flow = Flow::new(&view);
+            let flat = flow.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc Sum     def Sum  Count  |Sum(Count)
+a   [3] ─┬→ x   [3]      2  |**
+         └→ y   [3]      1  |*
+b   [1] ──→ y   [3]      1  |*
+c   [3] ─┬→ x   [3]      1  |*
+         ├→ y   [3]      1  |*
+         └→ z   [1]      1  |*"#
+            );
+        }
+
+        #[test]
+        fn show_aggregate_ignored() {
+            let schema: Schema2<&str, &str> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", "x"))
+                .add(("a", "y"))
+                .add(("b", "y"))
+                .build();
+            let view = dataset.count_breakdown_2nd();
+            let flat = Flow::new(&view).render(Render::default());
+            let flat_show_aggregate = Flow::new(&view).render(Render {
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(flat.to_string(), flat_show_aggregate.to_string());
+            assert_eq!(
+                format!("\n{}", flat_show_aggregate),
+                r#"
+abc Sum     def Sum  Count  |Sum(Count)
+a   [2] ─┬→ x   [1]      1  |*
+         └→ y   [2]      1  |*
+b   [1] ──→ y   [2]      1  |*"#
+            );
+        }
+
+        #[test]
+        fn volume() {
+            let schema: Schema3<&str, &str, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", "x", 100))
+                .add(("a", "y", 40))
+                .add(("b", "x", 20))
+                .add(("b", "x", 30))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let flow = Flow::new(&view);
+            let flat = flow.render(Render {
+                aggregate: Aggregate::Max,
+                width_hint: 50,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc Max       def Max    ghi  |Max(ghi)
+a   [100] ─┬→ x   [100]  100  |*******************
+           └→ y   [ 40]   40  |*******
+b   [ 30] ──→ x   [100]   30  |*****"#
+            );
+        }
+    }
+}
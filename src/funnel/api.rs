@@ -0,0 +1,15 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::Funnel;
+
+/// Render configuration specific to [`Funnel`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::FunnelConfig;
+/// let funnel_config = FunnelConfig {
+///     ..FunnelConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct FunnelConfig {}
@@ -0,0 +1,279 @@
+use crate::aggregate::{aggregate_apply, minimal_precision_string};
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{FunnelConfig, Schema, View};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::BarChart;
+
+/// The funnel widget.
+///
+/// A funnel represents each unique value of the view's primary dimension (1st) as a stage of a pipeline.
+/// The stages are ordered by the primary dimension, and each is drawn as a bar centered on the rendering.
+/// The breakdown dimension, if any, is folded into the value of each stage.
+///
+/// Each stage is annotated with the percentage kept from the previous stage (`Previous`), and from the first stage (`First`).
+/// Use `show_aggregate` to also display the value of each stage.
+///
+/// See also: [`BarChart`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema2<String, u32> = Schemas::two("Stage", "Users");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("1. signup".to_string(), 20))
+///     .add(("2. activation".to_string(), 12))
+///     .add(("3. purchase".to_string(), 3))
+///     .build();
+/// let view = dataset.view_2nd();
+/// let flat = Funnel::new(&view)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Stage          Previous   First  |Sum(Users)
+/// 1. signup        100.0%  100.0%  |********************
+/// 2. activation     60.0%   60.0%  |    ************
+/// 3. purchase       25.0%   15.0%  |        ***"#);
+/// # }
+/// ```
+pub struct Funnel<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> Funnel<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+{
+    /// Construct a funnel widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this funnel.
+    pub fn render(self, config: Render<FunnelConfig>) -> Flat {
+        let mut aggregate_values: HashMap<V::PrimaryDimension, Vec<f64>> = HashMap::default();
+        let mut sort_primaries: Vec<V::PrimaryDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let value = self.view.value(dims);
+            let primary_dim = self.view.primary_dim(dims);
+            let values = aggregate_values.entry(primary_dim.clone()).or_default();
+            values.push(value);
+
+            if !sort_primaries.contains(&primary_dim) {
+                sort_primaries.push(primary_dim);
+            }
+        }
+
+        sort_primaries.sort();
+
+        let mut columns = Columns::default();
+        // primary value
+        columns.push(Column::string(Alignment::Left));
+
+        if config.show_aggregate {
+            // spacer " "
+            columns.push(Column::string(Alignment::Center));
+            // total left [
+            columns.push(Column::string(Alignment::Left));
+            // total value
+            columns.push(Column::string(Alignment::Right));
+            // total right ]
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // previous percentage
+        columns.push(Column::string(Alignment::Right));
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // first percentage
+        columns.push(Column::string(Alignment::Right));
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // rendering delimiter |
+        columns.push(Column::string(Alignment::Center));
+        // aggregate count
+        columns.push(Column::count(Alignment::Center));
+
+        let mut grid = Grid::new(columns);
+        let mut header = Row::default();
+        header.label(self.view.display_headers()[0].clone());
+        header.push(Value::String(self.view.display_headers()[0].clone()));
+
+        if config.show_aggregate {
+            header.push(Value::Empty);
+            header.push(Value::Overflow(config.aggregate.to_string()));
+            header.push(Value::Skip);
+            header.push(Value::Skip);
+        }
+
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("Previous".to_string()));
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("First".to_string()));
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("|".to_string()));
+        header.push(Value::Plain(format!(
+            "{}({})",
+            config.aggregate,
+            self.view.value_label()
+        )));
+        grid.add(header);
+
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+        let mut first: Option<f64> = None;
+        let mut previous: Option<f64> = None;
+
+        for primary_dim in sort_primaries.iter() {
+            let value = aggregate_apply(
+                &config.aggregate,
+                &aggregate_values,
+                primary_dim,
+                &mut minimum_value,
+                &mut maximum_value,
+            );
+            let mut row = Row::default();
+            row.label(primary_dim.to_string());
+            row.push(Value::String(primary_dim.to_string()));
+
+            if config.show_aggregate {
+                row.push(Value::String(" ".to_string()));
+                row.push_aggregate(minimal_precision_string(value));
+            }
+
+            row.push(Value::String("  ".to_string()));
+            row.push(percentage(value, previous.unwrap_or(value)));
+            row.push(Value::String("  ".to_string()));
+            row.push(percentage(value, *first.get_or_insert(value)));
+            row.push(Value::String("  ".to_string()));
+            row.push(Value::String("|".to_string()));
+            row.push(Value::Bar(value));
+            grid.add(row);
+            previous.replace(value);
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+/// The percentage of the `denominator` kept in the `numerator`, or blank when undefined.
+fn percentage(numerator: f64, denominator: f64) -> Value {
+    if denominator == 0.0 {
+        Value::Empty
+    } else {
+        Value::String(format!("{:.1}%", numerator / denominator * 100.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{Aggregate, Funnel, Render};
+        use crate::{DatasetBuilder, Schema1, Schema2, Schemas};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let funnel = Funnel::new(&view);
+            let flat = funnel.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  Previous  First  |Sum(abc)"#
+            );
+        }
+
+        #[test]
+        fn stages() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((3, 2))
+                .add((1, 10))
+                .add((2, 5))
+                .add((2, 1))
+                .build();
+            let view = dataset.view_2nd();
+            let funnel = Funnel::new(&view);
+            let flat = funnel.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  Previous   First  |Sum(def)
+1      100.0%  100.0%  |**********
+2       60.0%   60.0%  |  ******
+3       33.3%   20.0%  |    **"#
+            );
+        }
+
+        #[test]
+        fn zero_stage() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 0))
+                .add((2, 0))
+                .add((3, 0))
+                .build();
+            let view = dataset.view_2nd();
+            let funnel = Funnel::new(&view);
+            let flat = funnel.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  Previous  First  |Sum(def)
+1                     |
+2                     |
+3                     |"#
+            );
+        }
+
+        #[test]
+        fn show_aggregate() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 8))
+                .add((1, 4))
+                .add((2, 3))
+                .add((3, 1))
+                .build();
+            let view = dataset.view_2nd();
+            let funnel = Funnel::new(&view);
+            let flat = funnel.render(Render {
+                aggregate: Aggregate::Max,
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc Max  Previous   First  |Max(def)
+1   [8]    100.0%  100.0%  |********
+2   [3]     37.5%   37.5%  |  ***
+3   [1]     33.3%   12.5%  |   *"#
+            );
+        }
+    }
+}
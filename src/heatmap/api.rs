@@ -0,0 +1,15 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::Heatmap;
+
+/// Render configuration specific to [`Heatmap`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::HeatmapConfig;
+/// let heatmap_config = HeatmapConfig {
+///     ..HeatmapConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct HeatmapConfig {}
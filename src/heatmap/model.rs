@@ -0,0 +1,493 @@
+use crate::abbreviate::find_abbreviations;
+use crate::aggregate::{aggregate_apply, minimal_precision_string};
+use crate::render::{shade, Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{HeatmapConfig, Schema, View};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::BarChart;
+
+/// The heatmap widget.
+///
+/// A heatmap represents each unique value of the view's primary dimension (1st) as a row, like the [`BarChart`].
+/// Instead of drawing bars, each primary x breakdown cell is filled with a single shade glyph (` ░▒▓█`).
+/// The shade is chosen by the magnitude of the cell's value, relative to the largest magnitude of all the cells.
+/// This keeps the rendering of wide breakdowns compact.
+///
+/// See also: [`BarChart`]
+///
+/// ```
+/// use flat::*;
+///
+/// let schema = Schemas::two("Animal", "Size");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("whale".to_string(), "large".to_string()))
+///     .add(("shark".to_string(), "medium".to_string()))
+///     .add(("shark".to_string(), "small".to_string()))
+///     .add(("tiger".to_string(), "medium".to_string()))
+///     .add(("tiger".to_string(), "medium".to_string()))
+///     .add(("tiger".to_string(), "medium".to_string()))
+///     .add(("tiger".to_string(), "medium".to_string()))
+///     .add(("tiger".to_string(), "small".to_string()))
+///     .build();
+/// let view = dataset.count_breakdown_2nd();
+/// let flat = Heatmap::new(&view)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+///          Size
+///          Sum(Count)
+/// Animal  |large  medium small |
+/// shark   |       ░░░░░░ ░░░░░░|
+/// tiger   |       ██████ ░░░░░░|
+/// whale   |░░░░░░              |"#);
+/// ```
+pub struct Heatmap<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> Heatmap<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+    <V as View<S>>::BreakdownDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+{
+    /// Construct a heatmap widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this heatmap.
+    pub fn render(self, config: Render<HeatmapConfig>) -> Flat {
+        let mut aggregate_values: HashMap<(V::PrimaryDimension, V::BreakdownDimension), Vec<f64>> =
+            HashMap::default();
+        let mut sort_primaries: Vec<V::PrimaryDimension> = Vec::default();
+        let mut sort_breakdowns: Vec<V::BreakdownDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let value = self.view.value(dims);
+            let primary_dim = self.view.primary_dim(dims);
+            let breakdown_dim = self.view.breakdown_dim(dims);
+            let aggregate_dims = (primary_dim.clone(), breakdown_dim.clone());
+            let values = aggregate_values.entry(aggregate_dims).or_default();
+            values.push(value);
+
+            if !sort_primaries.contains(&primary_dim) {
+                sort_primaries.push(primary_dim);
+            }
+
+            if !sort_breakdowns.contains(&breakdown_dim) {
+                sort_breakdowns.push(breakdown_dim);
+            }
+        }
+
+        sort_primaries.sort();
+        sort_breakdowns.sort();
+
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+        let cells: Vec<Vec<f64>> = sort_primaries
+            .iter()
+            .map(|primary_dim| {
+                sort_breakdowns
+                    .iter()
+                    .map(|breakdown_dim| {
+                        let aggregate_dims = (primary_dim.clone(), breakdown_dim.clone());
+                        aggregate_apply(
+                            &config.aggregate,
+                            &aggregate_values,
+                            &aggregate_dims,
+                            &mut minimum_value,
+                            &mut maximum_value,
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+        let totals: Vec<String> = cells
+            .iter()
+            .map(|values| minimal_precision_string(config.aggregate.apply(values.as_slice())))
+            .collect();
+        let extent = f64::max(minimum_value.abs(), maximum_value.abs());
+
+        let breakdown_names: Vec<String> = if self.view.breakdown_label().is_some() {
+            sort_breakdowns.iter().map(|b| b.to_string()).collect()
+        } else {
+            Vec::default()
+        };
+        let mut breakdown_abbreviations: HashMap<String, String> = HashMap::default();
+
+        if config.abbreviate_breakdown && !breakdown_names.is_empty() {
+            let primary_width = sort_primaries
+                .iter()
+                .map(|p| p.to_string().chars().count())
+                .chain([self.view.display_headers()[0].chars().count()])
+                .max()
+                .unwrap();
+            let aggregate_width = if config.show_aggregate {
+                totals
+                    .iter()
+                    .map(|t| t.chars().count() + 3)
+                    .chain([config.aggregate.to_string().chars().count() + 1])
+                    .max()
+                    .unwrap()
+            } else {
+                0
+            };
+            let frame_width = primary_width + aggregate_width + 4 + (breakdown_names.len() - 1);
+            let cell_width = std::cmp::max(
+                config.width_hint.saturating_sub(frame_width) / breakdown_names.len(),
+                1,
+            );
+            let maximum_width = breakdown_names
+                .iter()
+                .map(|name| name.chars().count())
+                .max()
+                .unwrap();
+
+            if maximum_width > cell_width {
+                let values: HashSet<String> = breakdown_names.iter().cloned().collect();
+                let (_, abbreviations) = find_abbreviations(cell_width, maximum_width, &values);
+                breakdown_abbreviations = abbreviations;
+            }
+        }
+
+        let breakdown_names: Vec<String> = breakdown_names
+            .into_iter()
+            .map(|name| breakdown_abbreviations.get(&name).cloned().unwrap_or(name))
+            .collect();
+        let cell_width = breakdown_names
+            .iter()
+            .map(|name| name.chars().count())
+            .max()
+            .unwrap_or(1);
+
+        let mut columns = Columns::default();
+        // primary value
+        columns.push(Column::string(Alignment::Left));
+
+        if config.show_aggregate {
+            // spacer " "
+            columns.push(Column::string(Alignment::Center));
+            // total left [
+            columns.push(Column::string(Alignment::Left));
+            // total value
+            columns.push(Column::string(Alignment::Right));
+            // total right ]
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // rendering delimiter |
+        columns.push(Column::string(Alignment::Center));
+
+        if self.view.breakdown_label().is_some() {
+            for i in 0..sort_breakdowns.len() {
+                // aggregate shade
+                columns.push(Column::string(Alignment::Center));
+
+                if i + 1 < sort_breakdowns.len() {
+                    // spacer " "
+                    columns.push(Column::string(Alignment::Left));
+                }
+            }
+
+            // breakdown right |
+            columns.push(Column::string(Alignment::Center));
+        } else {
+            // aggregate shade
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        let mut grid = Grid::new(columns);
+
+        if let Some(breakdown_header) = self.view.breakdown_label() {
+            let value_label = self.view.value_label();
+
+            if value_label == breakdown_header {
+                let pre_header = build_preheader(&config, &breakdown_header, true);
+                grid.add(pre_header);
+            } else {
+                let pre_header1 = build_preheader(&config, &breakdown_header, false);
+                grid.add(pre_header1);
+                let pre_header2 = build_preheader(&config, &value_label, true);
+                grid.add(pre_header2);
+            }
+        }
+
+        let mut header = Row::default();
+        header.push(Value::String(self.view.display_headers()[0].clone()));
+
+        if config.show_aggregate {
+            header.push(Value::Empty);
+            header.push(Value::Overflow(config.aggregate.to_string()));
+            header.push(Value::Skip);
+            header.push(Value::Skip);
+        }
+
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("|".to_string()));
+
+        if self.view.breakdown_label().is_some() {
+            for (k, breakdown_name) in breakdown_names.iter().enumerate() {
+                header.push(Value::String(breakdown_name.clone()));
+
+                if k + 1 < breakdown_names.len() {
+                    header.push(Value::String(" ".to_string()));
+                }
+            }
+
+            header.push(Value::String("|".to_string()));
+        } else {
+            header.push(Value::Plain(format!(
+                "{}({})",
+                config.aggregate,
+                self.view.value_label()
+            )));
+        }
+
+        grid.add(header);
+
+        for ((primary_dim, values), total) in sort_primaries.iter().zip(cells).zip(totals) {
+            let mut row = Row::default();
+            row.push(Value::String(primary_dim.to_string()));
+
+            if config.show_aggregate {
+                row.push(Value::String(" ".to_string()));
+                row.push_aggregate(total);
+            }
+
+            row.push(Value::String("  ".to_string()));
+            row.push(Value::String("|".to_string()));
+
+            for (k, value) in values.iter().enumerate() {
+                let glyph = shade(*value, extent);
+                row.push(Value::String(
+                    std::iter::repeat_n(glyph, cell_width).collect(),
+                ));
+
+                if self.view.breakdown_label().is_some() && k + 1 != values.len() {
+                    row.push(Value::String(" ".to_string()));
+                }
+            }
+
+            if self.view.breakdown_label().is_some() {
+                row.push(Value::String("|".to_string()));
+            }
+
+            grid.add(row);
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+fn build_preheader(config: &Render<HeatmapConfig>, label: &str, embed: bool) -> Row {
+    let mut row = Row::default();
+    row.push(Value::Empty);
+
+    if config.show_aggregate {
+        row.push(Value::Empty);
+        row.push(Value::Empty);
+        row.push(Value::Empty);
+        row.push(Value::Empty);
+    }
+
+    row.push(Value::Empty);
+    row.push(Value::Empty);
+
+    if embed {
+        row.push(Value::Plain(format!("{}({label})", config.aggregate)));
+    } else {
+        row.push(Value::Plain(label.to_string()));
+    }
+
+    row
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{DatasetBuilder, Schema1, Schema2, Schema3, Schemas};
+        use crate::{Heatmap, Render};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let heatmap = Heatmap::new(&view);
+            let flat = heatmap.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |Sum(abc)"#
+            );
+        }
+
+        #[test]
+        fn zero() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).add((0,)).build();
+            let view = dataset.reflect_1st();
+            let heatmap = Heatmap::new(&view);
+            let flat = heatmap.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |Sum(abc)
+0    | "#
+            );
+        }
+
+        #[test]
+        fn negatives_and_positives() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((-4,))
+                .add((-1,))
+                .add((0,))
+                .add((2,))
+                .add((4,))
+                .build();
+            let view = dataset.reflect_1st();
+            let heatmap = Heatmap::new(&view);
+            let flat = heatmap.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |Sum(abc)
+-4   |█
+-1   |░
+0    | 
+2    |▒
+4    |█"#
+            );
+        }
+
+        #[test]
+        fn breakdown() {
+            let schema: Schema2<u8, u8> = Schemas::two("abc", "something long");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 2))
+                .add((2, 3))
+                .add((3, 4))
+                .add((3, 4))
+                .build();
+            let view = dataset.breakdown_2nd();
+            let heatmap = Heatmap::new(&view);
+            let flat = heatmap.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      Sum(something long)
+abc  |2 3 4|
+1    |░    |
+2    |  ▒  |
+3    |    █|"#
+            );
+        }
+
+        #[test]
+        fn count_breakdown_show_aggregate() {
+            let schema: Schema3<&str, u8, &str> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 2, "left"))
+                .add(("a", 3, "right"))
+                .add(("a", 3, "right"))
+                .add(("b", 4, "left"))
+                .add(("b", 4, "middle"))
+                .add(("b", 4, "middle"))
+                .add(("b", 4, "middle"))
+                .build();
+            let view = dataset.count_breakdown_3rd();
+            let heatmap = Heatmap::new(&view);
+            let flat = heatmap.render(Render {
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+          ghi
+          Sum(Count)
+abc Sum  | left  middle right |
+a   [3]  |▒▒▒▒▒▒        ▓▓▓▓▓▓|
+b   [4]  |▒▒▒▒▒▒ ██████       |"#
+            );
+        }
+
+        #[test]
+        fn abbreviate_breakdown() {
+            let schema: Schema3<&str, u8, &str> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 2, "left"))
+                .add(("a", 3, "right"))
+                .add(("a", 3, "right"))
+                .add(("b", 4, "left"))
+                .add(("b", 4, "middle"))
+                .add(("b", 4, "middle"))
+                .add(("b", 4, "middle"))
+                .build();
+            let view = dataset.count_breakdown_3rd();
+            let heatmap = Heatmap::new(&view);
+            let flat = heatmap.render(Render {
+                width_hint: 20,
+                abbreviate_breakdown: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      ghi
+      Sum(Count)
+abc  |l.. m.. r..|
+a    |▒▒▒     ▓▓▓|
+b    |▒▒▒ ███    |"#
+            );
+        }
+    }
+
+    #[cfg(feature = "pointer_impls")]
+    mod pointer_impls {
+        use crate::{DatasetBuilder, Schema2, Schemas};
+        use crate::{Heatmap, Render};
+        use ordered_float::OrderedFloat;
+
+        #[test]
+        fn view2() {
+            let schema: Schema2<i64, OrderedFloat<f64>> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, OrderedFloat(0.1)))
+                .add((2, OrderedFloat(0.4)))
+                .add((2, OrderedFloat(0.9)))
+                .build();
+            let view = dataset.view_2nd();
+            let heatmap = Heatmap::new(&view);
+            let flat = heatmap.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |Sum(def)
+1    |░
+2    |█"#
+            );
+        }
+    }
+}
@@ -68,63 +68,14 @@ where
 
     /// Generate the flat rendering for this histogram.
     pub fn render(self, config: Render<HistogramConfig>) -> Flat {
-        let bin_ranges: Vec<Bounds<V::PrimaryDimension>> = if self.view.dataset().data().is_empty()
-        {
-            Vec::default()
-        } else {
-            let mut min = None;
-            let mut max = None;
-
-            for dims in self.view.dataset().data() {
-                let primary_dim = self.view.primary_dim(dims);
-
-                let update_min = match &min {
-                    Some(min) => primary_dim < *min,
-                    None => true,
-                };
-
-                if update_min {
-                    min.replace(primary_dim.clone());
-                }
-
-                let update_max = match &max {
-                    Some(max) => primary_dim > *max,
-                    None => true,
-                };
-
-                if update_max {
-                    max.replace(primary_dim.clone());
-                }
-            }
-
-            let min = min.expect("histogram must have some.values");
-            let max = max.expect("histogram must have some.values");
-
-            if min == max {
-                vec![Bounds {
-                    lower: Bound::Inclusive(min.clone()),
-                    upper: Bound::Inclusive(min.clone()),
-                }]
-            } else {
-                let delta = max - min.clone();
-                let size = delta.divide(self.bins);
-                (0..self.bins)
-                    .map(|i| {
-                        if i + 1 == self.bins {
-                            Bounds {
-                                lower: Bound::Inclusive(min.clone() + (size.multiply(i))),
-                                upper: Bound::Inclusive(min.clone() + (size.multiply(i + 1))),
-                            }
-                        } else {
-                            Bounds {
-                                lower: Bound::Inclusive(min.clone() + (size.multiply(i))),
-                                upper: Bound::Exclusive(min.clone() + (size.multiply(i + 1))),
-                            }
-                        }
-                    })
-                    .collect()
-            }
-        };
+        let bin_ranges = bin_ranges(
+            self.view
+                .dataset()
+                .data()
+                .iter()
+                .map(|dims| self.view.primary_dim(dims)),
+            self.bins,
+        );
 
         let mut bin_aggregates: Vec<HashMap<V::BreakdownDimension, Vec<f64>>> =
             (0..self.bins).map(|_| HashMap::default()).collect();
@@ -241,6 +192,7 @@ where
         }
 
         let mut header = Row::default();
+        header.label(self.view.display_headers()[0].clone());
 
         header.push(Value::String(self.view.display_headers()[0].clone()));
 
@@ -278,6 +230,7 @@ where
 
         for (bounds, aggregates) in bin_ranges.into_iter().zip(bin_aggregates) {
             let mut row = Row::default();
+            row.label(bounds.to_string());
             row.push(Value::String(bounds.to_string()));
 
             if self.view.breakdown_label().is_some() {
@@ -296,18 +249,16 @@ where
 
                 if config.show_aggregate {
                     row.push(Value::String(" ".to_string()));
-                    row.push(Value::String("[".to_string()));
-                    row.push(Value::String(minimal_precision_string(
+                    row.push_aggregate(minimal_precision_string(
                         config.aggregate.apply(breakdown_values.as_slice()),
-                    )));
-                    row.push(Value::String("]".to_string()));
+                    ));
                 }
 
                 row.push(Value::String("  ".to_string()));
                 row.push(Value::String("|".to_string()));
 
                 for (k, breakdown_value) in breakdown_values.iter().enumerate() {
-                    row.push(Value::Value(*breakdown_value));
+                    row.push(Value::Bar(*breakdown_value));
 
                     if k + 1 != sort_breakdowns.len() {
                         row.push(Value::String(" ".to_string()));
@@ -326,14 +277,12 @@ where
 
                 if config.show_aggregate {
                     row.push(Value::String(" ".to_string()));
-                    row.push(Value::String("[".to_string()));
-                    row.push(Value::String(minimal_precision_string(value)));
-                    row.push(Value::String("]".to_string()));
+                    row.push_aggregate(minimal_precision_string(value));
                 }
 
                 row.push(Value::String("  ".to_string()));
                 row.push(Value::String("|".to_string()));
-                row.push(Value::Value(value));
+                row.push(Value::Bar(value));
             }
 
             grid.add(row);
@@ -343,6 +292,67 @@ where
     }
 }
 
+/// Divide the range spanned by the `values` into `bins` bounds of equal size.
+/// The last bound is inclusive of the maximum value, while the others exclude their upper value.
+/// When all the values are equal, a single inclusive bound is produced; when there are no values, no bounds are produced.
+pub(crate) fn bin_ranges<T>(values: impl Iterator<Item = T>, bins: usize) -> Vec<Bounds<T>>
+where
+    T: Clone + PartialOrd + Add<T, Output = T> + Sub<T, Output = T> + Binnable,
+{
+    let mut min = None;
+    let mut max = None;
+
+    for value in values {
+        let update_min = match &min {
+            Some(min) => value < *min,
+            None => true,
+        };
+
+        if update_min {
+            min.replace(value.clone());
+        }
+
+        let update_max = match &max {
+            Some(max) => value > *max,
+            None => true,
+        };
+
+        if update_max {
+            max.replace(value);
+        }
+    }
+
+    let (min, max) = match (min, max) {
+        (Some(min), Some(max)) => (min, max),
+        _ => return Vec::default(),
+    };
+
+    if min == max {
+        vec![Bounds {
+            lower: Bound::Inclusive(min.clone()),
+            upper: Bound::Inclusive(min),
+        }]
+    } else {
+        let delta = max - min.clone();
+        let size = delta.divide(bins);
+        (0..bins)
+            .map(|i| {
+                if i + 1 == bins {
+                    Bounds {
+                        lower: Bound::Inclusive(min.clone() + (size.multiply(i))),
+                        upper: Bound::Inclusive(min.clone() + (size.multiply(i + 1))),
+                    }
+                } else {
+                    Bounds {
+                        lower: Bound::Inclusive(min.clone() + (size.multiply(i))),
+                        upper: Bound::Exclusive(min.clone() + (size.multiply(i + 1))),
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
 fn build_preheader(
     config: &Render<HistogramConfig>,
     columns: usize,
@@ -378,7 +388,7 @@ fn build_preheader(
 }
 
 #[derive(Debug)]
-struct Bounds<T: PartialOrd> {
+pub(crate) struct Bounds<T: PartialOrd> {
     lower: Bound<T>,
     upper: Bound<T>,
 }
@@ -398,13 +408,13 @@ impl<T: PartialOrd + Display> Display for Bounds<T> {
 }
 
 impl<T: PartialOrd> Bounds<T> {
-    fn contains(&self, item: &T) -> bool {
+    pub(crate) fn contains(&self, item: &T) -> bool {
         self.lower.left_of(item) && self.upper.right_of(item)
     }
 }
 
 #[derive(Debug)]
-enum Bound<T: PartialOrd> {
+pub(crate) enum Bound<T: PartialOrd> {
     Exclusive(T),
     Inclusive(T),
 }
@@ -431,7 +441,7 @@ mod tests {
     #[cfg(feature = "primitive_impls")]
     mod primitive_impls {
         use crate::{DatasetBuilder, Schema1, Schema2, Schemas};
-        use crate::{Histogram, Render};
+        use crate::{Histogram, Orientation, Render};
 
         #[test]
         fn empty() {
@@ -622,6 +632,33 @@ abc     |2 3 4|
 [3, 4]  |    *|"#
             );
         }
+
+        #[test]
+        fn vertical() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 2))
+                .add((2, 3))
+                .add((3, 4))
+                .build();
+            let view = dataset.view_2nd();
+            let histogram = Histogram::new(&view, 3);
+            let flat = histogram.render(Render {
+                orientation: Orientation::Vertical,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(def)
+                  *
+          *       *
+  *       *       *
+  *       *       *
+[1, 2)  [2, 3)  [3, 4]
+abc"#
+            );
+        }
     }
 
     #[cfg(feature = "pointer_impls")]
@@ -0,0 +1,15 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::Histogram2d;
+
+/// Render configuration specific to [`Histogram2d`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::Histogram2dConfig;
+/// let histogram2d_config = Histogram2dConfig {
+///     ..Histogram2dConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct Histogram2dConfig {}
@@ -0,0 +1,370 @@
+use crate::aggregate::aggregate_apply;
+use crate::histogram::{bin_ranges, Bounds};
+use crate::render::{shade, Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{Binnable, Histogram2dConfig, Schema, View};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::{Heatmap, Histogram};
+
+/// The 2d-histogram widget.
+///
+/// A 2d-histogram bins both the view's primary dimension (1st, x-axis) and breakdown dimension (y-axis) as [`Binnable`] types.
+/// The dataset's values are collected based off the x-by-y bin in which they fall, and each cell is filled with a shade glyph (` ░▒▓█`) in the manner of the [`Heatmap`].
+/// The x-axis bins are drawn as columns, while the y-axis bins are drawn as rows.
+/// The number of bins for each axis is configured at initialization.
+///
+/// The 2d-histogram requires a breakdown view (ex: `count_breakdown_2nd` or `view_3rd_breakdown_2nd`).
+///
+/// See also: [`Histogram`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema2<u32, f64> = Schemas::two("Age", "Height");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add((20, 1.5))
+///     .add((25, 1.6))
+///     .add((30, 1.8))
+///     .add((35, 1.8))
+///     .add((40, 1.7))
+///     .add((45, 1.9))
+///     .add((50, 1.7))
+///     .build();
+/// let view = dataset.count_breakdown_2nd();
+/// let flat = Histogram2d::new(&view, 3, 2)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+///              Age
+///              Sum(Count)
+/// Height      |[20, 30) [30, 40) [40, 50]|
+/// [1.5, 1.7)  |▓▓▓▓▓▓▓▓                  |
+/// [1.7, 1.9]  |         ▓▓▓▓▓▓▓▓ ████████|"#);
+/// # }
+/// ```
+pub struct Histogram2d<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    x_bins: usize,
+    y_bins: usize,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> Histogram2d<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone
+        + Display
+        + PartialEq
+        + PartialOrd
+        + Add<<V as View<S>>::PrimaryDimension, Output = <V as View<S>>::PrimaryDimension>
+        + Sub<<V as View<S>>::PrimaryDimension, Output = <V as View<S>>::PrimaryDimension>
+        + Binnable,
+    <V as View<S>>::BreakdownDimension: Clone
+        + Display
+        + PartialEq
+        + PartialOrd
+        + Add<<V as View<S>>::BreakdownDimension, Output = <V as View<S>>::BreakdownDimension>
+        + Sub<<V as View<S>>::BreakdownDimension, Output = <V as View<S>>::BreakdownDimension>
+        + Binnable,
+{
+    /// Construct a 2d-histogram widget from the provided view and number of bins along the x-axis (primary dimension) and y-axis (breakdown dimension).
+    pub fn new(view: &'a V, x_bins: usize, y_bins: usize) -> Self {
+        Self {
+            view,
+            // Make sure there's always at least 1 bin along each axis.
+            x_bins: std::cmp::max(x_bins, 1),
+            y_bins: std::cmp::max(y_bins, 1),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this 2d-histogram.
+    pub fn render(self, config: Render<Histogram2dConfig>) -> Flat {
+        let data = self.view.dataset().data();
+        let x_ranges = bin_ranges(
+            data.iter().map(|dims| self.view.primary_dim(dims)),
+            self.x_bins,
+        );
+        let y_ranges = bin_ranges(
+            data.iter().map(|dims| self.view.breakdown_dim(dims)),
+            self.y_bins,
+        );
+        let mut aggregate_values: HashMap<(usize, usize), Vec<f64>> = HashMap::default();
+
+        for dims in data {
+            let value = self.view.value(dims);
+            let x = find_bin(&x_ranges, &self.view.primary_dim(dims));
+            let y = find_bin(&y_ranges, &self.view.breakdown_dim(dims));
+            aggregate_values.entry((x, y)).or_default().push(value);
+        }
+
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+        let cells: Vec<Vec<f64>> = (0..y_ranges.len())
+            .map(|y| {
+                (0..x_ranges.len())
+                    .map(|x| {
+                        aggregate_apply(
+                            &config.aggregate,
+                            &aggregate_values,
+                            &(x, y),
+                            &mut minimum_value,
+                            &mut maximum_value,
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+        let extent = f64::max(minimum_value.abs(), maximum_value.abs());
+        let x_labels: Vec<String> = x_ranges.iter().map(|bounds| bounds.to_string()).collect();
+        let cell_width = x_labels
+            .iter()
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap_or(1);
+
+        let mut columns = Columns::default();
+        // y-axis range
+        columns.push(Column::string(Alignment::Left));
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // rendering delimiter |
+        columns.push(Column::string(Alignment::Center));
+
+        for i in 0..x_ranges.len() {
+            // aggregate shade
+            columns.push(Column::string(Alignment::Center));
+
+            if i + 1 < x_ranges.len() {
+                // spacer " "
+                columns.push(Column::string(Alignment::Left));
+            }
+        }
+
+        // rendering right |
+        columns.push(Column::string(Alignment::Center));
+
+        let mut grid = Grid::new(columns);
+        let x_header = self.view.display_headers()[0].clone();
+        let value_label = self.view.value_label();
+
+        if value_label == x_header {
+            grid.add(build_preheader(&config, &x_header, true));
+        } else {
+            grid.add(build_preheader(&config, &x_header, false));
+            grid.add(build_preheader(&config, &value_label, true));
+        }
+
+        let mut header = Row::default();
+        header.push(Value::String(
+            self.view.breakdown_label().unwrap_or_default(),
+        ));
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("|".to_string()));
+
+        for (k, label) in x_labels.iter().enumerate() {
+            header.push(Value::String(label.clone()));
+
+            if k + 1 < x_labels.len() {
+                header.push(Value::String(" ".to_string()));
+            }
+        }
+
+        header.push(Value::String("|".to_string()));
+        grid.add(header);
+
+        for (bounds, values) in y_ranges.iter().zip(cells) {
+            let mut row = Row::default();
+            row.label(bounds.to_string());
+            row.push(Value::String(bounds.to_string()));
+            row.push(Value::String("  ".to_string()));
+            row.push(Value::String("|".to_string()));
+
+            for (k, value) in values.iter().enumerate() {
+                let glyph = shade(*value, extent);
+                row.push(Value::String(
+                    std::iter::repeat_n(glyph, cell_width).collect(),
+                ));
+
+                if k + 1 != values.len() {
+                    row.push(Value::String(" ".to_string()));
+                }
+            }
+
+            row.push(Value::String("|".to_string()));
+            grid.add(row);
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+/// Find the index of the bin which contains the `item`.
+fn find_bin<T: PartialOrd>(bin_ranges: &[Bounds<T>], item: &T) -> usize {
+    bin_ranges
+        .iter()
+        .position(|bounds| bounds.contains(item))
+        .expect("key must map to one of the aggregating bins")
+}
+
+fn build_preheader(config: &Render<Histogram2dConfig>, label: &str, embed: bool) -> Row {
+    let mut row = Row::default();
+    row.push(Value::Empty);
+    row.push(Value::Empty);
+    row.push(Value::Empty);
+
+    if embed {
+        row.push(Value::Plain(format!("{}({label})", config.aggregate)));
+    } else {
+        row.push(Value::Plain(label.to_string()));
+    }
+
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_bins() {
+        let bin_ranges = bin_ranges([0, 10].into_iter(), 2);
+        assert_eq!(bin_ranges[0].to_string(), "[0, 5)");
+        assert_eq!(bin_ranges[1].to_string(), "[5, 10]");
+        assert_eq!(find_bin(&bin_ranges, &0), 0);
+        assert_eq!(find_bin(&bin_ranges, &4), 0);
+        assert_eq!(find_bin(&bin_ranges, &5), 1);
+        assert_eq!(find_bin(&bin_ranges, &10), 1);
+    }
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{Aggregate, Histogram2d, Render};
+        use crate::{DatasetBuilder, Schema2, Schema3, Schemas};
+
+        #[test]
+        fn empty() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.count_breakdown_2nd();
+            let histogram2d = Histogram2d::new(&view, 2, 2);
+            let flat = histogram2d.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      abc
+      Sum(Count)
+def  ||"#
+            );
+        }
+
+        #[test]
+        fn zero_bins() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 10))
+                .add((2, 20))
+                .add((3, 30))
+                .build();
+            let view = dataset.count_breakdown_2nd();
+            let histogram2d = Histogram2d::new(&view, 0, 0);
+            let flat = histogram2d.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+           abc
+           Sum(Count)
+def       |[1, 3]|
+[10, 30]  |██████|"#
+            );
+        }
+
+        #[test]
+        fn single_value() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 10))
+                .add((1, 10))
+                .build();
+            let view = dataset.count_breakdown_2nd();
+            let histogram2d = Histogram2d::new(&view, 3, 3);
+            let flat = histogram2d.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+           abc
+           Sum(Count)
+def       |[1, 1]|
+[10, 10]  |██████|"#
+            );
+        }
+
+        #[test]
+        fn count() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((0, 0))
+                .add((1, 1))
+                .add((2, 2))
+                .add((2, 2))
+                .add((3, 3))
+                .add((4, 4))
+                .add((4, 0))
+                .add((4, 0))
+                .add((4, 0))
+                .add((4, 0))
+                .build();
+            let view = dataset.count_breakdown_2nd();
+            let histogram2d = Histogram2d::new(&view, 2, 2);
+            let flat = histogram2d.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+         abc
+         Sum(Count)
+def     |[0, 2) [2, 4]|
+[0, 2)  |▒▒▒▒▒▒ ██████|
+[2, 4]  |       ██████|"#
+            );
+        }
+
+        #[test]
+        fn value() {
+            let schema: Schema3<f64, f64, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((0.0, 0.0, 1))
+                .add((0.5, 1.0, -8))
+                .add((1.0, 0.0, 4))
+                .add((2.0, 2.0, 2))
+                .add((3.0, 4.0, 3))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let histogram2d = Histogram2d::new(&view, 3, 2);
+            let flat = histogram2d.render(Render {
+                aggregate: Aggregate::Average,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+         abc
+         Average(ghi)
+def     |[0, 1) [1, 2) [2, 3]|
+[0, 2)  |██████ ██████       |
+[2, 4]  |              ▓▓▓▓▓▓|"#
+            );
+        }
+    }
+}
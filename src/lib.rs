@@ -231,23 +231,64 @@
 //! Negative values are rendering using a different character marker (ex: `'⊖'`).
 mod abbreviate;
 mod aggregate;
+mod barchart;
+mod boxplot;
+mod bulletchart;
+mod calendarheatmap;
+mod canvas;
+mod correlationmatrix;
 mod dagchart;
 mod dataset;
+mod density;
+mod ecdf;
+mod flow;
+mod funnel;
+mod heatmap;
 mod histogram;
+mod histogram2d;
+mod linechart;
+mod pareto;
 mod pathchart;
+mod pivottable;
 mod render;
+mod scatterplot;
 mod schema;
+mod sparkline;
+mod stemleaf;
+mod table;
+mod timeline;
 mod view;
+mod waterfall;
 
 pub use aggregate::{minimal_precision_string, Aggregate};
+pub use barchart::*;
+pub use boxplot::*;
+pub use bulletchart::*;
+pub use calendarheatmap::*;
+pub use correlationmatrix::*;
 pub use dagchart::*;
 pub use dataset::*;
+pub use density::*;
+pub use ecdf::*;
+pub use flow::*;
+pub use funnel::*;
+pub use heatmap::*;
 pub use histogram::*;
+pub use histogram2d::*;
+pub use linechart::*;
+pub use pareto::*;
 pub use pathchart::*;
-pub use render::{Flat, Render};
+pub use pivottable::*;
+pub use render::{Alignment, Flat, Orientation, Render};
+pub use scatterplot::*;
 pub use schema::*;
+pub use sparkline::*;
 use std::fmt::{Display, Formatter};
+pub use stemleaf::*;
+pub use table::*;
+pub use timeline::*;
 pub use view::*;
+pub use waterfall::*;
 
 #[cfg(all(feature = "primitive_impls", feature = "pointer_impls"))]
 compile_error!("Users may choose at most one of: [primitive_impls, pointer_impls]");
@@ -0,0 +1,15 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::LineChart;
+
+/// Render configuration specific to [`LineChart`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::LineChartConfig;
+/// let linechart_config = LineChartConfig {
+///     ..LineChartConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct LineChartConfig {}
@@ -0,0 +1,356 @@
+use crate::canvas::{extent, extent_labels, legend, marker, normalize, Axes, Canvas};
+use crate::render::{Flat, Render};
+use crate::{Coordinate, LineChartConfig, Schema, View};
+use std::fmt::Display;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::ScatterPlot;
+
+/// The line-chart widget.
+///
+/// A line-chart draws the aggregate of the view's values at each position of its primary dimension (1st, x-axis).
+/// The primary dimension must be a [`Coordinate`] type.
+/// Consecutive positions are joined by a line, so that trends (ex: over time) are visible.
+///
+/// The chart is sized to the `width_hint` and `height_hint` of the [`Render`] configuration.
+/// Without a breakdown, the line is drawn at the braille (U+2800) sub-character resolution.
+/// With a breakdown, each breakdown value is drawn as a separate series with a distinct marker, labelled in the legend.
+///
+/// See also: [`ScatterPlot`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema2<u32, f64> = Schemas::two("Day", "Temperature");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add((1, 10.0))
+///     .add((2, 14.0))
+///     .add((3, 12.0))
+///     .add((3, 14.0))
+///     .add((4, 20.0))
+///     .build();
+/// let view = dataset.view_2nd();
+/// let flat = LineChart::new(&view)
+///     .render(Render {
+///         aggregate: Aggregate::Average,
+///         width_hint: 16,
+///         height_hint: 8,
+///         ..Render::default()
+///     });
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Average(Temperature)
+/// 20 │          ⢠⠊
+///    │         ⡔⠁
+///    │  ⢀⡠⠒⠢⠤⢄⠊
+/// 10 │⡠⠔⠁
+///    └────────────
+///     1          4
+///     Day"#);
+/// # }
+/// ```
+pub struct LineChart<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> LineChart<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Coordinate,
+    <V as View<S>>::BreakdownDimension: Clone + Display + PartialEq + Ord,
+{
+    /// Construct a line-chart widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this line-chart.
+    pub fn render(self, config: Render<LineChartConfig>) -> Flat {
+        let mut points: Vec<(f64, f64, V::BreakdownDimension)> = Vec::default();
+        let mut sort_breakdowns: Vec<V::BreakdownDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let x = self.view.primary_dim(dims).coordinate();
+            let y = self.view.value(dims);
+            let breakdown_dim = self.view.breakdown_dim(dims);
+
+            if !sort_breakdowns.contains(&breakdown_dim) {
+                sort_breakdowns.push(breakdown_dim.clone());
+            }
+
+            points.push((x, y, breakdown_dim));
+        }
+
+        sort_breakdowns.sort();
+
+        // Aggregate each series at each of its x positions.
+        let series: Vec<Vec<(f64, f64)>> = sort_breakdowns
+            .iter()
+            .map(|breakdown_dim| {
+                let mut series_points: Vec<(f64, f64)> = points
+                    .iter()
+                    .filter(|(_, _, b)| b == breakdown_dim)
+                    .map(|(x, y, _)| (*x, *y))
+                    .collect();
+                series_points.sort_by(|a, b| a.0.total_cmp(&b.0));
+                let mut aggregated: Vec<(f64, f64)> = Vec::default();
+                let mut start = 0;
+
+                while start < series_points.len() {
+                    let x = series_points[start].0;
+                    let end = series_points[start..]
+                        .iter()
+                        .position(|(other, _)| *other != x)
+                        .map_or(series_points.len(), |offset| start + offset);
+                    let values: Vec<f64> =
+                        series_points[start..end].iter().map(|(_, y)| *y).collect();
+                    aggregated.push((x, config.aggregate.apply(values.as_slice())));
+                    start = end;
+                }
+
+                aggregated
+            })
+            .collect();
+
+        let x_extent = extent(series.iter().flatten().map(|(x, _)| *x));
+        let axes = Axes {
+            x_title: self.view.display_headers()[0].clone(),
+            x_labels: extent_labels(x_extent),
+            y_title: format!("{}({})", config.aggregate, self.view.value_label()),
+            y_extent: extent(series.iter().flatten().map(|(_, y)| *y)),
+            legend: self.view.breakdown_label().map(|breakdown_header| {
+                let names: Vec<String> = sort_breakdowns.iter().map(|b| b.to_string()).collect();
+                legend(&breakdown_header, &names)
+            }),
+        };
+        let (x_minimum, x_maximum) = x_extent;
+        let (y_minimum, y_maximum) = axes.y_extent;
+        let (canvas_width, canvas_height) = axes.canvas_size(config.width_hint, config.height_hint);
+        let mut canvas = Canvas::new(canvas_width, canvas_height);
+
+        for (k, series_points) in series.iter().enumerate() {
+            let unit_points: Vec<(f64, f64)> = series_points
+                .iter()
+                .map(|(x, y)| {
+                    (
+                        normalize(*x, x_minimum, x_maximum),
+                        normalize(*y, y_minimum, y_maximum),
+                    )
+                })
+                .collect();
+
+            if let [(x, y)] = unit_points.as_slice() {
+                if self.view.breakdown_label().is_some() {
+                    canvas.marker(*x, *y, marker(k));
+                } else {
+                    canvas.dot(*x, *y);
+                }
+            }
+
+            for segment in unit_points.windows(2) {
+                let [(x1, y1), (x2, y2)] = [segment[0], segment[1]];
+
+                if self.view.breakdown_label().is_some() {
+                    canvas.marker_line(x1, y1, x2, y2, marker(k));
+                } else {
+                    canvas.line(x1, y1, x2, y2);
+                }
+            }
+        }
+
+        Flat::new(config, y_minimum..y_maximum, axes.grid(&canvas))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{Aggregate, DatasetBuilder, Schema2, Schema3, Schemas};
+        use crate::{LineChart, Render};
+
+        #[test]
+        fn empty() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.view_2nd();
+            let linechart = LineChart::new(&view);
+            let flat = linechart.render(Render {
+                width_hint: 10,
+                height_hint: 6,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(def)
+0 │
+0 │
+  └───────
+   0     0
+   abc"#
+            );
+        }
+
+        #[test]
+        fn single() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema).add((1, 1)).build();
+            let view = dataset.view_2nd();
+            let linechart = LineChart::new(&view);
+            let flat = linechart.render(Render {
+                width_hint: 10,
+                height_hint: 6,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(def)
+1 │
+1 │   ⠈
+  └───────
+   1     1
+   abc"#
+            );
+        }
+
+        #[test]
+        fn unordered() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((3, 0))
+                .add((1, 0))
+                .add((2, 4))
+                .build();
+            let view = dataset.view_2nd();
+            let linechart = LineChart::new(&view);
+            let flat = linechart.render(Render {
+                width_hint: 10,
+                height_hint: 6,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(def)
+4 │  ⡠⠚⢄
+0 │⡠⠊   ⠑⢄
+  └───────
+   1     3
+   abc"#
+            );
+        }
+
+        #[test]
+        fn aggregate() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 1))
+                .add((1, 3))
+                .add((2, 8))
+                .add((3, -2))
+                .add((3, -4))
+                .build();
+            let view = dataset.view_2nd();
+            let linechart = LineChart::new(&view);
+            let flat = linechart.render(Render {
+                aggregate: Aggregate::Max,
+                width_hint: 12,
+                height_hint: 7,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Max(def)
+ 8 │ ⢀⡠⠒⠣⡀
+   │⠔⠁   ⠑⡄
+-2 │      ⠈⢆
+   └────────
+    1      3
+    abc"#
+            );
+        }
+
+        #[test]
+        fn breakdown() {
+            let schema: Schema3<u8, &str, u8> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a", 1))
+                .add((2, "a", 2))
+                .add((3, "a", 3))
+                .add((1, "b", 3))
+                .add((3, "b", 1))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let linechart = LineChart::new(&view);
+            let flat = linechart.render(Render {
+                width_hint: 12,
+                height_hint: 9,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(ghi)
+3 │oo     **
+  │  oo **
+  │  **ooo
+1 │**     oo
+  └─────────
+   1       3
+   abc
+   def: * a  o b"#
+            );
+        }
+    }
+
+    #[cfg(feature = "pointer_impls")]
+    mod pointer_impls {
+        use crate::{DatasetBuilder, Schema2, Schemas};
+        use crate::{LineChart, Render};
+        use ordered_float::OrderedFloat;
+
+        #[test]
+        fn view2() {
+            let schema: Schema2<OrderedFloat<f64>, OrderedFloat<f64>> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((OrderedFloat(0.1), OrderedFloat(1.0)))
+                .add((OrderedFloat(0.2), OrderedFloat(2.0)))
+                .add((OrderedFloat(0.3), OrderedFloat(4.0)))
+                .build();
+            let view = dataset.view_2nd();
+            let linechart = LineChart::new(&view);
+            let flat = linechart.render(Render {
+                width_hint: 10,
+                height_hint: 6,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(def)
+4 │    ⢀⠤⠊
+1 │⣀⠤⠔⠒⠁
+  └───────
+   0.1 0.3
+   abc"#
+            );
+        }
+    }
+}
@@ -0,0 +1,22 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::Pareto;
+
+/// Render configuration specific to [`Pareto`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::ParetoConfig;
+/// let pareto_config = ParetoConfig {
+///     threshold: Some(0.8),
+///     ..ParetoConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct ParetoConfig {
+    /// The cumulative share (in the range `[0, 1]`) to mark, on the row at which it is crossed.
+    /// For example, `0.8` marks the row which brings the cumulative percentage to 80% (or more).
+    ///
+    /// Default: `None`.
+    pub threshold: Option<f64>,
+}
@@ -0,0 +1,368 @@
+use crate::aggregate::{aggregate_apply, minimal_precision_string};
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{ParetoConfig, Schema, View};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::BarChart;
+
+/// The pareto widget.
+///
+/// A pareto represents each unique value of the view's primary dimension (1st) as a bar, like the [`BarChart`].
+/// The rows are sorted by their value in descending order, so that the largest contributors are listed first.
+/// The breakdown dimension, if any, is folded into the value of each row.
+///
+/// Each row is annotated with the cumulative percentage of the total (`Cumulative`), down to and including that row.
+/// Negative values contribute by their magnitude: the rows are sorted by their absolute value, and the shares are taken of the total absolute value.
+/// This way, the cumulative percentage never decreases, and it only reaches 100% at the last row.
+/// Set the [`ParetoConfig::threshold`] to mark the row at which the cumulative percentage crosses the threshold (ex: 80%).
+/// Use `show_aggregate` to also display the value of each row.
+///
+/// See also: [`BarChart`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema2<&str, u32> = Schemas::two("Cause", "Incidents");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("config", 6))
+///     .add(("deploy", 14))
+///     .add(("disk", 3))
+///     .add(("network", 22))
+///     .add(("dns", 5))
+///     .build();
+/// let view = dataset.view_2nd();
+/// let flat = Pareto::new(&view)
+///     .render(Render {
+///         widget_config: ParetoConfig {
+///             threshold: Some(0.8),
+///         },
+///         ..Render::default()
+///     });
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+///           Sum(Incidents)
+/// Cause    |                        Cumulative
+/// network  |**********************       44.0%
+/// deploy   |**************               72.0%
+/// config   |******                       84.0% ← 80%
+/// dns      |*****                        94.0%
+/// disk     |***                         100.0%"#);
+/// # }
+/// ```
+pub struct Pareto<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> Pareto<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+{
+    /// Construct a pareto widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this pareto.
+    pub fn render(self, config: Render<ParetoConfig>) -> Flat {
+        let mut aggregate_values: HashMap<V::PrimaryDimension, Vec<f64>> = HashMap::default();
+        let mut sort_primaries: Vec<V::PrimaryDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let value = self.view.value(dims);
+            let primary_dim = self.view.primary_dim(dims);
+            let values = aggregate_values.entry(primary_dim.clone()).or_default();
+            values.push(value);
+
+            if !sort_primaries.contains(&primary_dim) {
+                sort_primaries.push(primary_dim);
+            }
+        }
+
+        // Sort by the primary dimension first, so that rows of equal value keep a stable order.
+        sort_primaries.sort();
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+        let mut rows: Vec<(&V::PrimaryDimension, f64)> = sort_primaries
+            .iter()
+            .map(|primary_dim| {
+                let value = aggregate_apply(
+                    &config.aggregate,
+                    &aggregate_values,
+                    primary_dim,
+                    &mut minimum_value,
+                    &mut maximum_value,
+                );
+                (primary_dim, value)
+            })
+            .collect();
+        rows.sort_by(|(_, a), (_, b)| b.abs().total_cmp(&a.abs()));
+        let total: f64 = rows.iter().map(|(_, value)| value.abs()).sum();
+
+        let mut columns = Columns::default();
+        // primary value
+        columns.push(Column::string(Alignment::Left));
+
+        if config.show_aggregate {
+            // spacer " "
+            columns.push(Column::string(Alignment::Center));
+            // total left [
+            columns.push(Column::string(Alignment::Left));
+            // total value
+            columns.push(Column::string(Alignment::Right));
+            // total right ]
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // rendering delimiter |
+        columns.push(Column::string(Alignment::Center));
+        // aggregate count
+        columns.push(Column::count(Alignment::Left));
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // cumulative percentage
+        columns.push(Column::string(Alignment::Right));
+        // spacer " "
+        columns.push(Column::string(Alignment::Center));
+        // threshold marker
+        columns.push(Column::string(Alignment::Left));
+
+        let mut grid = Grid::new(columns);
+        // The aggregate title is drawn above the bars, so that it doesn't push the cumulative column out of alignment.
+        let mut pre_header = Row::default();
+        pre_header.push(Value::Empty);
+
+        if config.show_aggregate {
+            pre_header.push(Value::Empty);
+            pre_header.push(Value::Empty);
+            pre_header.push(Value::Empty);
+            pre_header.push(Value::Empty);
+        }
+
+        pre_header.push(Value::Empty);
+        pre_header.push(Value::Empty);
+        pre_header.push(Value::Plain(format!(
+            "{}({})",
+            config.aggregate,
+            self.view.value_label()
+        )));
+        grid.add(pre_header);
+
+        let mut header = Row::default();
+        header.label(self.view.display_headers()[0].clone());
+        header.push(Value::String(self.view.display_headers()[0].clone()));
+
+        if config.show_aggregate {
+            header.push(Value::Empty);
+            header.push(Value::Overflow(config.aggregate.to_string()));
+            header.push(Value::Skip);
+            header.push(Value::Skip);
+        }
+
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("|".to_string()));
+
+        if !rows.is_empty() {
+            header.push(Value::Empty);
+            header.push(Value::String("  ".to_string()));
+            header.push(Value::String("Cumulative".to_string()));
+        }
+
+        grid.add(header);
+
+        let mut cumulative = 0.0;
+        let mut crossed = false;
+
+        for (primary_dim, value) in rows {
+            cumulative += value.abs();
+            let mut row = Row::default();
+            row.label(primary_dim.to_string());
+            row.push(Value::String(primary_dim.to_string()));
+
+            if config.show_aggregate {
+                row.push(Value::String(" ".to_string()));
+                row.push_aggregate(minimal_precision_string(value));
+            }
+
+            row.push(Value::String("  ".to_string()));
+            row.push(Value::String("|".to_string()));
+            row.push(Value::Bar(value));
+
+            if total != 0.0 {
+                let share = cumulative / total;
+                row.push(Value::String("  ".to_string()));
+                row.push(Value::String(format!("{:.1}%", share * 100.0)));
+
+                if let Some(threshold) = config.widget_config.threshold {
+                    if !crossed && share >= threshold {
+                        crossed = true;
+                        row.push(Value::String(" ".to_string()));
+                        row.push(Value::String(format!(
+                            "← {}%",
+                            minimal_precision_string(threshold * 100.0)
+                        )));
+                    }
+                }
+            }
+
+            grid.add(row);
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{DatasetBuilder, Schema1, Schema2, Schemas};
+        use crate::{Pareto, ParetoConfig, Render};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let pareto = Pareto::new(&view);
+            let flat = pareto.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      Sum(abc)
+abc  |"#
+            );
+        }
+
+        #[test]
+        fn count() {
+            let schema: Schema1<&str> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a",))
+                .add(("b",))
+                .add(("b",))
+                .add(("c",))
+                .add(("c",))
+                .add(("c",))
+                .add(("d",))
+                .add(("d",))
+                .add(("d",))
+                .build();
+            let view = dataset.count();
+            let pareto = Pareto::new(&view);
+            let flat = pareto.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      Sum(Count)
+abc  |     Cumulative
+c    |***       33.3%
+d    |***       66.7%
+b    |**        88.9%
+a    |*        100.0%"#
+            );
+        }
+
+        #[test]
+        fn zero_total() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema).add((1, 0)).add((2, 0)).build();
+            let view = dataset.view_2nd();
+            let pareto = Pareto::new(&view);
+            let flat = pareto.render(Render {
+                widget_config: ParetoConfig {
+                    threshold: Some(0.8),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+      Sum(def)
+abc  |   Cumulative
+1    |
+2    |"#
+            );
+        }
+
+        #[test]
+        fn threshold() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 2))
+                .add((2, 10))
+                .add((3, 5))
+                .add((3, 1))
+                .add((4, 2))
+                .build();
+            let view = dataset.view_2nd();
+            let pareto = Pareto::new(&view);
+            let flat = pareto.render(Render {
+                show_aggregate: true,
+                widget_config: ParetoConfig {
+                    threshold: Some(0.8),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+           Sum(def)
+abc Sum   |            Cumulative
+2   [10]  |**********       50.0%
+3   [ 6]  |******           80.0% ← 80%
+1   [ 2]  |**               90.0%
+4   [ 2]  |**              100.0%"#
+            );
+        }
+
+        #[test]
+        fn negative() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 6))
+                .add((2, -8))
+                .add((3, 4))
+                .add((4, 2))
+                .build();
+            let view = dataset.view_2nd();
+            let pareto = Pareto::new(&view);
+            let flat = pareto.render(Render {
+                show_aggregate: true,
+                widget_config: ParetoConfig {
+                    threshold: Some(0.5),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+           Sum(def)
+abc Sum   |          Cumulative
+2   [-8]  |⊖⊖⊖⊖⊖⊖⊖⊖       40.0%
+1   [ 6]  |******         70.0% ← 50%
+3   [ 4]  |****           90.0%
+4   [ 2]  |**            100.0%"#
+            );
+        }
+    }
+}
@@ -8,8 +8,37 @@ use super::PathChart;
 /// ```
 /// # use flat::PathChartConfig;
 /// let pathchart_config = PathChartConfig {
+///     tree: true,
 ///     ..PathChartConfig::default()
 /// };
 /// ```
-#[derive(Debug, Default)]
-pub struct PathChartConfig {}
+#[derive(Debug)]
+pub struct PathChartConfig {
+    /// Whether to draw the paths with tree connectors (`├──`, `└──`, `│`) or not.
+    /// When `false`, each path part is indented by two spaces per level and prefixed with the `separator`.
+    ///
+    /// ```ignore
+    /// // tree: false     tree: true
+    /// r#"
+    /// /shark           shark
+    ///   /medium        ├── medium
+    ///   /small         └── small"#
+    /// ```
+    ///
+    /// Default: `false`.
+    pub tree: bool,
+    /// The character used to separate the parts of the paths.
+    /// With `tree` connectors, the separator only appears in the header.
+    ///
+    /// Default: `'/'`.
+    pub separator: char,
+}
+
+impl Default for PathChartConfig {
+    fn default() -> Self {
+        Self {
+            tree: false,
+            separator: '/',
+        }
+    }
+}
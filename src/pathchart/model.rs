@@ -15,6 +15,7 @@ use crate::DagChart;
 ///
 /// A path-chart represents each unique tuple of the view's display dimensions as a path in a directory listing.
 /// Paths with common sub-paths, starting from the primary dimension (1st), are collapsed in the frame.
+/// Use [`PathChartConfig::tree`] to draw the paths with tree connectors, which helps to follow deep hierarchies.
 ///
 /// See also: [`DagChart`]
 ///
@@ -112,12 +113,12 @@ where
             let values = aggregate_values.entry(aggregate_dims.clone()).or_default();
             values.push(value);
 
-            if !lookup.contains_key(&root) {
-                // Notice, the breakdown_dim will be different in the case of an `is_breakdown` schema.
-                // But in that case, we don't actually use the breakdown from `lookup`.
-                // We really only need this so we can get the `Nothing` breakdown for non-`is_breakdown` schemas.
-                lookup.insert(root, (primary_dim.clone(), breakdown_dims.clone()));
-            }
+            // Notice, the breakdown_dim will be different in the case of an `is_breakdown` schema.
+            // But in that case, we don't actually use the breakdown from `lookup`.
+            // We really only need this so we can get the `Nothing` breakdown for non-`is_breakdown` schemas.
+            lookup
+                .entry(root)
+                .or_insert_with(|| (primary_dim.clone(), breakdown_dims.clone()));
 
             if !sort_breakdowns.contains(&breakdown_dims) {
                 sort_breakdowns.push(breakdown_dims);
@@ -196,10 +197,11 @@ where
         }
 
         let mut header = Row::default();
+        header.label(self.view.display_headers()[0].clone());
         let mut combined = String::default();
 
         for (j, name) in self.view.display_headers().iter().enumerate() {
-            combined.push('/');
+            combined.push(config.widget_config.separator);
             combined.push_str(name);
 
             if j + 1 < self.view.display_headers().len() {
@@ -241,20 +243,18 @@ where
         let mut minimum_value = f64::MAX;
         let mut maximum_value = f64::MIN;
 
+        // Each path is stacked along with whether each of its parts is the last among its siblings.
         let mut stack = Vec::default();
-        stack.push(Path { path: vec![] });
-
-        while !stack.is_empty() {
-            let current = stack.pop().unwrap();
+        stack.push((Path { path: vec![] }, Vec::default()));
 
+        while let Some((current, lasts)) = stack.pop() {
             if !current.path.is_empty() {
                 let part = current.path.last().unwrap();
-                let depth = current.path.len();
-                let width = (depth - 1) * 2;
                 let mut row = Row::default();
-                row.push(Value::String(format!("{:width$}/{part}", "")));
+                row.push(Value::String(indent(&config.widget_config, &lasts, part)));
 
                 if current.path.len() == 1 {
+                    row.label(part.clone());
                     let (primary_dim, breakdown_dim) = lookup
                         .get(part)
                         .expect("sort dimensions must be mapped to dimensions");
@@ -276,18 +276,16 @@ where
 
                         if config.show_aggregate {
                             row.push(Value::String(" ".to_string()));
-                            row.push(Value::String("[".to_string()));
-                            row.push(Value::String(minimal_precision_string(
+                            row.push_aggregate(minimal_precision_string(
                                 config.aggregate.apply(breakdown_values.as_slice()),
-                            )));
-                            row.push(Value::String("]".to_string()));
+                            ));
                         }
 
                         row.push(Value::String("  ".to_string()));
                         row.push(Value::String("|".to_string()));
 
                         for (k, breakdown_value) in breakdown_values.iter().enumerate() {
-                            row.push(Value::Value(*breakdown_value));
+                            row.push(Value::Bar(*breakdown_value));
 
                             if k + 1 != breakdown_values.len() {
                                 row.push(Value::String(" ".to_string()));
@@ -307,14 +305,12 @@ where
 
                         if config.show_aggregate {
                             row.push(Value::String(" ".to_string()));
-                            row.push(Value::String("[".to_string()));
-                            row.push(Value::String(minimal_precision_string(value)));
-                            row.push(Value::String("]".to_string()));
+                            row.push_aggregate(minimal_precision_string(value));
                         }
 
                         row.push(Value::String("  ".to_string()));
                         row.push(Value::String("|".to_string()));
-                        row.push(Value::Value(value));
+                        row.push(Value::Bar(value));
 
                         if value < minimum_value {
                             minimum_value = value;
@@ -334,9 +330,7 @@ where
                         .aggregate
                         .apply(partial_aggregate_values[&partial_path].as_slice());
                     row.push(Value::String(" ".to_string()));
-                    row.push(Value::String("[".to_string()));
-                    row.push(Value::String(minimal_precision_string(value)));
-                    row.push(Value::String("]".to_string()));
+                    row.push_aggregate(minimal_precision_string(value));
                 }
 
                 grid.add(row);
@@ -345,10 +339,12 @@ where
             if let Some(children) = dag.get_mut(&current) {
                 children.sort();
 
-                for child in children.iter().rev() {
+                for (k, child) in children.iter().enumerate().rev() {
                     let mut sub_path = current.clone();
                     sub_path.path.push(child.clone());
-                    stack.push(sub_path);
+                    let mut sub_lasts = lasts.clone();
+                    sub_lasts.push(k + 1 == children.len());
+                    stack.push((sub_path, sub_lasts));
                 }
             }
         }
@@ -357,6 +353,35 @@ where
     }
 }
 
+/// Indent the `part` at the end of a path, where `lasts` marks whether each part of the path is the last among its siblings.
+fn indent(config: &PathChartConfig, lasts: &[bool], part: &str) -> String {
+    let depth = lasts.len();
+
+    if !config.tree {
+        let width = (depth - 1) * 2;
+        return format!("{:width$}{}{part}", "", config.separator);
+    }
+
+    if depth == 1 {
+        return part.to_string();
+    }
+
+    let mut out = String::default();
+
+    // The roots aren't connected to anything, so their continuation is skipped.
+    for last in &lasts[1..depth - 1] {
+        out.push_str(if *last { "    " } else { "│   " });
+    }
+
+    out.push_str(if lasts[depth - 1] {
+        "└── "
+    } else {
+        "├── "
+    });
+    out.push_str(part);
+    out
+}
+
 fn build_preheader(
     config: &Render<PathChartConfig>,
     columns: usize,
@@ -398,11 +423,29 @@ struct Path {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn indent_paths() {
+        let config = PathChartConfig::default();
+        assert_eq!(indent(&config, &[true], "a"), "/a");
+        assert_eq!(indent(&config, &[false, true, false], "c"), "    /c");
+
+        let config = PathChartConfig {
+            tree: true,
+            separator: '.',
+        };
+        assert_eq!(indent(&config, &[true], "a"), "a");
+        assert_eq!(indent(&config, &[false, false], "b"), "├── b");
+        assert_eq!(indent(&config, &[false, true], "b"), "└── b");
+        assert_eq!(indent(&config, &[false, false, true], "c"), "│   └── c");
+        assert_eq!(indent(&config, &[false, true, false], "c"), "    ├── c");
+    }
 
     #[cfg(feature = "primitive_impls")]
     mod primitive_impls {
         use crate::{DatasetBuilder, Schema1, Schema2, Schemas};
-        use crate::{PathChart, Render};
+        use crate::{Orientation, PathChart, PathChartConfig, Render};
 
         #[test]
         fn empty() {
@@ -496,6 +539,30 @@ mod tests {
             );
         }
 
+        #[test]
+        fn diverging() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, -2))
+                .add((2, 3))
+                .add((3, -1))
+                .build();
+            let view = dataset.view_2nd();
+            let barchart = PathChart::new(&view);
+            let flat = barchart.render(Render {
+                diverging: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+/abc  |Sum(def)
+/1    |⊖⊖│
+/2    |  │***
+/3    | ⊖│"#
+            );
+        }
+
         #[test]
         fn breakdown() {
             let schema: Schema2<u8, u8> = Schemas::two("abc", "something long");
@@ -541,6 +608,62 @@ mod tests {
             );
         }
 
+        #[test]
+        fn vertical() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((-2,))
+                .add((0,))
+                .add((3,))
+                .build();
+            let view = dataset.reflect_1st();
+            let pathchart = PathChart::new(&view);
+            let flat = pathchart.render(Render {
+                orientation: Orientation::Vertical,
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(abc)
+            *
+            *
+            *
+──────────────
+ ⊖
+ ⊖
+ -2    0    3
+[-2]  [0]  [3]
+abc"#
+            );
+        }
+
+        #[test]
+        fn vertical_depth_2() {
+            let schema = Schemas::two("A", "B");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a1", "b1"))
+                .add(("a1", "b2"))
+                .add(("a2", "b1"))
+                .build();
+            let view = dataset.count();
+            let pathchart = PathChart::new(&view);
+            let flat = pathchart.render(Render {
+                orientation: Orientation::Vertical,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(Count)
+*
+*   *
+a1  a2
+A"#
+            );
+        }
+
         #[test]
         fn depth_3_combo111() {
             let schema = Schemas::three("A", "B", "C");
@@ -816,6 +939,75 @@ mod tests {
     /c1  [1]"#
             );
         }
+
+        #[test]
+        fn depth_3_tree() {
+            let schema = Schemas::three("A", "B", "C");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a1", "b3", "c1"))
+                .add(("a1", "b2", "c1"))
+                .add(("a1", "b1", "c3"))
+                .add(("a1", "b1", "c2"))
+                .add(("a2", "b1", "c1"))
+                .build();
+            let view = dataset.count();
+            let barchart = PathChart::new(&view);
+            let flat = barchart.render(Render {
+                show_aggregate: true,
+                widget_config: PathChartConfig {
+                    tree: true,
+                    ..PathChartConfig::default()
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+/A /B /C   Sum  |Sum(Count)
+a1         [4]  |****
+├── b1     [2]
+│   ├── c2 [1]
+│   └── c3 [1]
+├── b2     [1]
+│   └── c1 [1]
+└── b3     [1]
+    └── c1 [1]
+a2         [1]  |*
+└── b1     [1]
+    └── c1 [1]"#
+            );
+        }
+
+        #[test]
+        fn depth_3_separator() {
+            let schema = Schemas::three("A", "B", "C");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a1", "b1", "c1"))
+                .add(("a1", "b1", "c2"))
+                .add(("a2", "b1", "c1"))
+                .build();
+            let view = dataset.count();
+            let barchart = PathChart::new(&view);
+            let flat = barchart.render(Render {
+                widget_config: PathChartConfig {
+                    separator: '.',
+                    ..PathChartConfig::default()
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+.A .B .C  |Sum(Count)
+.a1       |**
+  .b1
+    .c1
+    .c2
+.a2       |*
+  .b1
+    .c1"#
+            );
+        }
     }
 
     #[cfg(feature = "pointer_impls")]
@@ -0,0 +1,30 @@
+use crate::minimal_precision_string;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::PivotTable;
+
+/// Render configuration specific to [`PivotTable`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::PivotTableConfig;
+/// let pivottable_config = PivotTableConfig {
+///     formatter: |value| format!("{value:.2}"),
+///     ..PivotTableConfig::default()
+/// };
+/// ```
+#[derive(Debug)]
+pub struct PivotTableConfig {
+    /// The function used to format each of the aggregated values (including the totals).
+    ///
+    /// Default: [`minimal_precision_string`].
+    pub formatter: fn(f64) -> String,
+}
+
+impl Default for PivotTableConfig {
+    fn default() -> Self {
+        Self {
+            formatter: minimal_precision_string,
+        }
+    }
+}
@@ -0,0 +1,353 @@
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{PivotTableConfig, Schema, View};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::Heatmap;
+
+/// The label of the total row and column.
+const TOTAL: &str = "Total";
+
+/// The pivot-table widget.
+///
+/// A pivot-table shows the aggregate of each primary (1st) x breakdown cell as a formatted number, rather than as a relative rendering.
+/// Each row is followed by its total, and the final row holds the total of each column, as well as the grand total.
+/// The totals aggregate all the underlying values of their row/column, using the same `aggregate` as the cells.
+/// Cells without any data are left blank.
+///
+/// See also: [`Heatmap`]
+///
+/// ```
+/// use flat::*;
+///
+/// let schema = Schemas::two("Animal", "Size");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("whale".to_string(), "large".to_string()))
+///     .add(("shark".to_string(), "medium".to_string()))
+///     .add(("shark".to_string(), "small".to_string()))
+///     .add(("tiger".to_string(), "medium".to_string()))
+///     .add(("tiger".to_string(), "medium".to_string()))
+///     .add(("tiger".to_string(), "small".to_string()))
+///     .build();
+/// let view = dataset.count_breakdown_2nd();
+/// let flat = PivotTable::new(&view)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+///         Size
+///         Sum(Count)
+/// Animal  large  medium  small  Total
+/// shark               1      1      2
+/// tiger               2      1      3
+/// whale       1                     1
+/// Total       1       3      2      6"#);
+/// ```
+pub struct PivotTable<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> PivotTable<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+    <V as View<S>>::BreakdownDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+{
+    /// Construct a pivot-table widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this pivot-table.
+    pub fn render(self, config: Render<PivotTableConfig>) -> Flat {
+        let mut aggregate_values: HashMap<(V::PrimaryDimension, V::BreakdownDimension), Vec<f64>> =
+            HashMap::default();
+        let mut primary_values: HashMap<V::PrimaryDimension, Vec<f64>> = HashMap::default();
+        let mut breakdown_values: HashMap<V::BreakdownDimension, Vec<f64>> = HashMap::default();
+        let mut all_values: Vec<f64> = Vec::default();
+        let mut sort_primaries: Vec<V::PrimaryDimension> = Vec::default();
+        let mut sort_breakdowns: Vec<V::BreakdownDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let value = self.view.value(dims);
+            let primary_dim = self.view.primary_dim(dims);
+            let breakdown_dim = self.view.breakdown_dim(dims);
+
+            if !sort_primaries.contains(&primary_dim) {
+                sort_primaries.push(primary_dim.clone());
+            }
+
+            if !sort_breakdowns.contains(&breakdown_dim) {
+                sort_breakdowns.push(breakdown_dim.clone());
+            }
+
+            aggregate_values
+                .entry((primary_dim.clone(), breakdown_dim.clone()))
+                .or_default()
+                .push(value);
+            primary_values.entry(primary_dim).or_default().push(value);
+            breakdown_values
+                .entry(breakdown_dim)
+                .or_default()
+                .push(value);
+            all_values.push(value);
+        }
+
+        sort_primaries.sort();
+        sort_breakdowns.sort();
+
+        let is_breakdown = self.view.breakdown_label().is_some();
+        let format = |values: Option<&Vec<f64>>| match values {
+            Some(values) => Value::String((config.widget_config.formatter)(
+                config.aggregate.apply(values),
+            )),
+            None => Value::Empty,
+        };
+        // Without a breakdown, the row total is the same as its only cell.
+        let value_columns = if is_breakdown {
+            sort_breakdowns.len() + 1
+        } else {
+            1
+        };
+        let mut columns = Columns::default();
+        // primary dimension
+        columns.push(Column::string(Alignment::Left));
+
+        for _ in 0..value_columns {
+            // spacer "  "
+            columns.push(Column::string(Alignment::Left));
+            // aggregate value
+            columns.push(Column::string(Alignment::Right));
+        }
+
+        let mut grid = Grid::new(columns);
+
+        if let Some(breakdown_label) = self.view.breakdown_label() {
+            for pre_header in [
+                breakdown_label,
+                format!("{}({})", config.aggregate, self.view.value_label()),
+            ] {
+                let mut row = Row::default();
+                row.push(Value::Empty);
+                row.push(Value::Empty);
+                row.push(Value::Plain(pre_header));
+                grid.add(row);
+            }
+        }
+
+        let mut header = Row::default();
+        header.push(Value::String(self.view.display_headers()[0].clone()));
+
+        if is_breakdown {
+            for breakdown_dim in sort_breakdowns.iter() {
+                header.push(Value::String("  ".to_string()));
+                header.push(Value::String(breakdown_dim.to_string()));
+            }
+
+            header.push(Value::String("  ".to_string()));
+            header.push(Value::String(TOTAL.to_string()));
+        } else {
+            header.push(Value::String("  ".to_string()));
+            header.push(Value::String(format!(
+                "{}({})",
+                config.aggregate,
+                self.view.value_label()
+            )));
+        }
+
+        grid.add(header);
+
+        for primary_dim in sort_primaries.iter() {
+            let mut row = Row::default();
+            row.push(Value::String(primary_dim.to_string()));
+
+            if is_breakdown {
+                for breakdown_dim in sort_breakdowns.iter() {
+                    row.push(Value::String("  ".to_string()));
+                    row.push(format(
+                        aggregate_values.get(&(primary_dim.clone(), breakdown_dim.clone())),
+                    ));
+                }
+            }
+
+            row.push(Value::String("  ".to_string()));
+            row.push(format(primary_values.get(primary_dim)));
+            grid.add(row);
+        }
+
+        let mut totals = Row::default();
+        totals.push(Value::String(TOTAL.to_string()));
+
+        if is_breakdown {
+            for breakdown_dim in sort_breakdowns.iter() {
+                totals.push(Value::String("  ".to_string()));
+                totals.push(format(breakdown_values.get(breakdown_dim)));
+            }
+        }
+
+        totals.push(Value::String("  ".to_string()));
+        totals.push(format((!all_values.is_empty()).then_some(&all_values)));
+        grid.add(totals);
+
+        Flat::new(config, 0.0..0.0, grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{Aggregate, DatasetBuilder, Schema1, Schema2, Schema3, Schemas};
+        use crate::{PivotTable, PivotTableConfig, Render};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let flat = PivotTable::new(&view).render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    Sum(abc)
+Total  "#
+            );
+        }
+
+        #[test]
+        fn view1() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1,))
+                .add((2,))
+                .add((2,))
+                .build();
+            let view = dataset.reflect_1st();
+            let flat = PivotTable::new(&view).render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    Sum(abc)
+1             1
+2             4
+Total         5"#
+            );
+        }
+
+        #[test]
+        fn view2_average() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 1))
+                .add((1, 2))
+                .add((2, -4))
+                .build();
+            let view = dataset.view_2nd();
+            let flat = PivotTable::new(&view).render(Render {
+                aggregate: Aggregate::Average,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    Average(def)
+1               1.5
+2                -4
+Total          -0.3"#
+            );
+        }
+
+        #[test]
+        fn breakdown_average() {
+            let schema: Schema3<i64, String, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a".to_string(), 1))
+                .add((1, "b".to_string(), 3))
+                .add((1, "b".to_string(), 5))
+                .add((2, "a".to_string(), 20))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let flat = PivotTable::new(&view).render(Render {
+                aggregate: Aggregate::Average,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+       def
+       Average(ghi)
+abc       a  b  Total
+1         1  4      3
+2        20        20
+Total  10.5  4    7.3"#
+            );
+        }
+
+        #[test]
+        fn formatter() {
+            let schema: Schema3<i64, String, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a".to_string(), 1))
+                .add((1, "b".to_string(), 3))
+                .add((2, "a".to_string(), 20))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let flat = PivotTable::new(&view).render(Render {
+                widget_config: PivotTableConfig {
+                    formatter: |value| format!("{value:.1}"),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+       def
+       Sum(ghi)
+abc       a    b  Total
+1       1.0  3.0    4.0
+2      20.0        20.0
+Total  21.0  3.0   24.0"#
+            );
+        }
+    }
+
+    #[cfg(feature = "pointer_impls")]
+    mod pointer_impls {
+        use crate::{DatasetBuilder, Schema2, Schemas};
+        use crate::{PivotTable, Render};
+        use ordered_float::OrderedFloat;
+
+        #[test]
+        fn view2() {
+            let schema: Schema2<i64, OrderedFloat<f64>> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, OrderedFloat(0.1)))
+                .add((2, OrderedFloat(0.4)))
+                .add((2, OrderedFloat(0.9)))
+                .build();
+            let view = dataset.view_2nd();
+            let flat = PivotTable::new(&view).render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    Sum(def)
+1           0.1
+2           1.3
+Total       1.4"#
+            );
+        }
+    }
+}
@@ -9,12 +9,16 @@ use std::ops::Range;
 ///
 /// ### Example
 /// ```
-/// # use flat::{Aggregate, HistogramConfig, Render};
+/// # use flat::{Aggregate, HistogramConfig, Orientation, Render};
 /// let config = Render {
 ///     aggregate: Aggregate::Average,
 ///     width_hint: 10,
+///     height_hint: 10,
+///     orientation: Orientation::Vertical,
 ///     show_aggregate: true,
 ///     abbreviate_breakdown: true,
+///     stack_breakdown: true,
+///     diverging: true,
 ///     positive_marker: '+',
 ///     negative_marker: '-',
 ///     widget_config: HistogramConfig::default(),
@@ -32,8 +36,21 @@ pub struct Render<C> {
     /// * If the rendering can reasonably fit in a smaller width, the `width_hint` is ignored.
     /// * If the rendering cannot reasonably fit the `width_hint`, then it is minimally extended (such that a reasonable rendering may be produced).
     ///
+    /// The [`Orientation::Vertical`] rendering ignores the `width_hint`, since each primary value is laid out at the width of its label.
+    ///
     /// Default: `160`.
     pub width_hint: usize,
+    /// The hint to use to determine the height of the rendering.
+    /// This is the vertical counterpart to `width_hint`, and applies to [`Orientation::Vertical`] as well as plotting widgets (ex: [`crate::ScatterPlot`]).
+    /// `Flat` will try to make the rendering at most `height_hint` tall, with the same exceptions as the `width_hint`.
+    ///
+    /// Default: `40`.
+    pub height_hint: usize,
+    /// The direction in which the rendering grows.
+    /// See [`Orientation`] for more details.
+    ///
+    /// Default: `Orientation::Horizontal`.
+    pub orientation: Orientation,
     /// Whether to show the aggregated result for the *primary* dimension of the dataset.
     /// While the *rendered* data in `flat` uses a relative representation, this option extends the widget to show the absolute values of the data.
     /// ```ignore
@@ -57,6 +74,30 @@ pub struct Render<C> {
     ///
     /// Default: `false`.
     pub abbreviate_breakdown: bool,
+    /// Whether to stack the breakdown into a single bar per row, rather than laying out each breakdown value in its own column.
+    /// Each breakdown value is drawn with a distinct marker, which is labelled in the legend (in place of the breakdown column headings).
+    /// Use this option when the breakdown has many values, since the rendering then only needs the width of a single bar.
+    /// The first breakdown value is drawn with the `positive_marker`.
+    /// The first 69 (or more) breakdown values are drawn with distinct markers, and any breakdown values beyond those are all drawn with `?`.
+    /// Negative segments are drawn to the left of the zero axis when `diverging`, and otherwise follow the positive segments with the `negative_marker`.
+    ///
+    /// This option applies to the widgets which draw the breakdown as bars (ex: [`crate::BarChart`], [`crate::DagChart`]), and is ignored in the [`Orientation::Vertical`] rendering.
+    ///
+    /// Default: `false`.
+    pub stack_breakdown: bool,
+    /// Whether to lay out the rendering around a zero axis (`│`), where negative values extend to the left and positive values extend to the right.
+    /// Use this option for mixed-sign data (ex: profit/loss, or deltas).
+    /// ```ignore
+    /// r#"
+    /// A  |  ⊖⊖│
+    /// B  |    │***
+    /// C  |⊖⊖⊖⊖│*"#
+    /// ```
+    ///
+    /// The scale is shared by both sides of the axis, and is ignored in the [`Orientation::Vertical`] rendering.
+    ///
+    /// Default: `false`.
+    pub diverging: bool,
     /// The marker character for positive values of the rendering.
     ///
     /// Default: `'*'`.
@@ -76,8 +117,12 @@ impl<C: Default> Default for Render<C> {
         Self {
             aggregate: Aggregate::Sum,
             width_hint: 160,
+            height_hint: 40,
+            orientation: Orientation::Horizontal,
             show_aggregate: false,
             abbreviate_breakdown: false,
+            stack_breakdown: false,
+            diverging: false,
             positive_marker: '*',
             negative_marker: '⊖',
             widget_config: C::default(),
@@ -85,10 +130,68 @@ impl<C: Default> Default for Render<C> {
     }
 }
 
+/// The direction in which the rendering of a `flat` widget grows.
+///
+/// ### Example
+/// ```
+/// use flat::*;
+///
+/// let schema = Schemas::one("Animal");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("whale".to_string(), ))
+///     .add(("shark".to_string(), ))
+///     .add(("shark".to_string(), ))
+///     .add(("tiger".to_string(), ))
+///     .add(("tiger".to_string(), ))
+///     .add(("tiger".to_string(), ))
+///     .build();
+/// let view = dataset.count();
+/// let flat = BarChart::new(&view).render(Render {
+///     orientation: Orientation::Vertical,
+///     ..Render::default()
+/// });
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Sum(Count)
+///          *
+///   *      *
+///   *      *      *
+/// shark  tiger  whale
+/// Animal"#);
+/// ```
+#[derive(Debug)]
+pub enum Orientation {
+    /// The rendering grows from left to right, with the frame on the left.
+    /// ```text
+    /// r#"
+    /// Frame..  | Rendering..
+    /// Frame..  | Rendering.."#
+    /// ```
+    Horizontal,
+    /// The rendering grows from the bottom up, with the primary dimension labelled along the bottom.
+    /// ```text
+    /// r#"
+    ///   Rendering..
+    ///   Rendering..   Rendering..
+    /// primary_A     primary_B"#
+    /// ```
+    ///
+    /// In the vertical orientation, the frame is reduced to just the primary dimension (along with the aggregate, when `show_aggregate` is set).
+    /// Negative values hang below a zero row (`─`).
+    /// The rendering is sized to the `height_hint`, while the `width_hint` is ignored.
+    /// Widgets which cannot be transposed (ex: those without a primary dimension) ignore this option.
+    Vertical,
+}
+
 #[derive(Debug)]
 struct Config {
     width_hint: usize,
+    height_hint: usize,
+    orientation: Orientation,
     abbreviate_breakdown: bool,
+    stack_breakdown: bool,
+    diverging: bool,
     positive_marker: char,
     negative_marker: char,
 }
@@ -97,7 +200,11 @@ impl<T> From<Render<T>> for Config {
     fn from(value: Render<T>) -> Self {
         Self {
             width_hint: value.width_hint,
+            height_hint: value.height_hint,
+            orientation: value.orientation,
             abbreviate_breakdown: value.abbreviate_breakdown,
+            stack_breakdown: value.stack_breakdown,
+            diverging: value.diverging,
             positive_marker: value.positive_marker,
             negative_marker: value.negative_marker,
         }
@@ -140,10 +247,14 @@ impl Column {
     }
 }
 
-#[derive(Debug)]
-pub(crate) enum Alignment {
+/// The horizontal alignment of a column in the rendering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    /// Align the content to the left, padding on the right.
     Left,
+    /// Center the content, padding on both sides.
     Center,
+    /// Align the content to the right, padding on the left.
     Right,
 }
 
@@ -251,7 +362,12 @@ pub(crate) enum Value {
     String(String),
     Overflow(String),
     Plain(String),
-    Value(f64),
+    /// A bar anchored at zero.
+    Bar(f64),
+    /// The breakdown values, stacked into a single bar.
+    Stack(Vec<f64>),
+    /// A bar floating between the (from, to) values, rather than being anchored at zero.
+    Float(f64, f64),
     Skip,
 }
 
@@ -260,7 +376,11 @@ impl Value {
         match &self {
             Value::Empty => Some(0),
             Value::String(string) | Value::Overflow(string) => Some(string.chars().count()),
-            Value::Plain(_) | Value::Value(_) | Value::Skip => None,
+            Value::Plain(_)
+            | Value::Bar(_)
+            | Value::Stack(_)
+            | Value::Float(_, _)
+            | Value::Skip => None,
         }
     }
 
@@ -279,7 +399,7 @@ impl Value {
                 }
             }
             Value::Overflow(string) | Value::Plain(string) => string.clone(),
-            Value::Value(value) => {
+            Value::Bar(value) => {
                 let value = value.round();
 
                 let marker = if value.is_sign_positive() {
@@ -288,9 +408,113 @@ impl Value {
                     view.negative_marker
                 };
 
-                iter::repeat(marker)
-                    .take((value.abs() * view.scale) as usize)
-                    .collect::<String>()
+                let bar =
+                    iter::repeat_n(marker, (value.abs() * view.scale) as usize).collect::<String>();
+
+                match view.zero_axis {
+                    // Breakdowns are padded out, so that the axis stays put when centered in the column.
+                    Some((negative_width, positive_width)) if is_breakdown => {
+                        if value.is_sign_negative() {
+                            format!("{bar:>negative_width$}{ZERO_AXIS}{:positive_width$}", "")
+                        } else {
+                            format!("{:negative_width$}{ZERO_AXIS}{bar:positive_width$}", "")
+                        }
+                    }
+                    Some((negative_width, _)) => {
+                        if value.is_sign_negative() {
+                            format!("{bar:>negative_width$}{ZERO_AXIS}")
+                        } else {
+                            format!("{:negative_width$}{ZERO_AXIS}{bar}", "")
+                        }
+                    }
+                    None => bar,
+                }
+            }
+            Value::Stack(values) => {
+                let values: Vec<(usize, f64)> = values
+                    .iter()
+                    .map(|value| value.round())
+                    .enumerate()
+                    .filter(|(_, value)| *value != 0.0)
+                    .collect();
+                let positives = values.iter().filter(|(_, value)| *value > 0.0);
+                let negatives = values.iter().filter(|(_, value)| *value < 0.0);
+
+                match view.zero_axis {
+                    Some((negative_width, _)) => {
+                        // The negative segments extend out to the left of the zero axis.
+                        let glyphs = |(k, value): &(usize, f64)| {
+                            (
+                                value.abs(),
+                                stack_marker(*k, view.positive_marker, view.negative_marker),
+                            )
+                        };
+                        let negative = segments(negatives.map(glyphs), view.scale)
+                            .chars()
+                            .rev()
+                            .collect::<String>();
+                        let positive = segments(positives.map(glyphs), view.scale);
+                        format!("{negative:>negative_width$}{ZERO_AXIS}{positive}")
+                    }
+                    None => {
+                        // Without a zero axis, the negative segments follow the positive segments.
+                        let positive = positives.map(|(k, value)| {
+                            (
+                                value.abs(),
+                                stack_marker(*k, view.positive_marker, view.negative_marker),
+                            )
+                        });
+                        let negative =
+                            negatives.map(|(_, value)| (value.abs(), view.negative_marker));
+                        segments(positive.chain(negative), view.scale)
+                    }
+                }
+            }
+            Value::Float(from, to) => {
+                let (from, to) = (from.round(), to.round());
+                let marker = if to >= from {
+                    view.positive_marker
+                } else {
+                    view.negative_marker
+                };
+
+                match view.zero_axis {
+                    Some((negative_width, positive_width)) => {
+                        // Position relative to the left edge of the rendering, which sits left of zero.
+                        let position = |value: f64| {
+                            (negative_width as isize + (value * view.scale) as isize).max(0)
+                                as usize
+                        };
+                        let mut cells = vec![' '; negative_width + positive_width];
+
+                        for cell in cells
+                            .iter_mut()
+                            .take(position(from.max(to)))
+                            .skip(position(from.min(to)))
+                        {
+                            *cell = marker;
+                        }
+
+                        cells.insert(negative_width, ZERO_AXIS);
+                        let bar = cells.into_iter().collect::<String>();
+
+                        if is_breakdown {
+                            bar
+                        } else {
+                            bar.trim_end().to_string()
+                        }
+                    }
+                    None => {
+                        // Without a zero axis, anything below zero is clipped.
+                        let start = (from.min(to).max(0.0) * view.scale) as usize;
+                        let end = (from.max(to).max(0.0) * view.scale) as usize;
+                        format!(
+                            "{:start$}{}",
+                            "",
+                            iter::repeat_n(marker, end - start).collect::<String>()
+                        )
+                    }
+                }
             }
         }
     }
@@ -306,6 +530,10 @@ impl Columns {
         self.types.push(column);
     }
 
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
     fn get(&self, index: usize) -> &Column {
         &self.types[index]
     }
@@ -318,6 +546,8 @@ impl Columns {
 #[derive(Debug, Default)]
 pub(crate) struct Row {
     cells: Vec<Cell>,
+    label: Option<String>,
+    aggregate: Option<String>,
 }
 
 impl Row {
@@ -327,6 +557,21 @@ impl Row {
             value,
         });
     }
+
+    /// Label this row with its primary dimension (or the primary header, in the case of the header row).
+    /// Only labelled rows take part in the [`Orientation::Vertical`] rendering.
+    pub fn label(&mut self, label: impl Into<String>) {
+        self.label.replace(label.into());
+    }
+
+    /// Push the aggregate of this row (`show_aggregate`), enclosed in `[` and `]`.
+    /// The [`Orientation::Vertical`] rendering shows the aggregate beneath the label of the row.
+    pub fn push_aggregate(&mut self, aggregate: String) {
+        self.push(Value::String("[".to_string()));
+        self.push(Value::String(aggregate.clone()));
+        self.push(Value::String("]".to_string()));
+        self.aggregate.replace(aggregate);
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -339,6 +584,8 @@ struct Overflow {
 pub(crate) struct Grid {
     columns: Columns,
     rows: Vec<HashMap<usize, Cell>>,
+    labels: Vec<Option<String>>,
+    aggregates: Vec<Option<String>>,
     overflows: Vec<Overflow>,
     breakdown_values: HashSet<String>,
     /// The minimum width of all the columns (before abbreviation).
@@ -352,6 +599,8 @@ impl Grid {
         Self {
             columns,
             rows: Vec::default(),
+            labels: Vec::default(),
+            aggregates: Vec::default(),
             overflows: Vec::default(),
             breakdown_values: HashSet::default(),
             minimum_breakdown_width: usize::MAX,
@@ -422,10 +671,121 @@ impl Grid {
             });
         }
 
+        self.labels.push(row.label);
+        self.aggregates.push(row.aggregate);
         self.rows
             .push(row.cells.into_iter().map(|c| (c.column, c)).collect());
     }
 
+    fn has_breakdown(&self) -> bool {
+        self.columns
+            .types
+            .iter()
+            .any(|c| matches!(c.column_type, ColumnType::Breakdown))
+    }
+
+    /// Collapse the breakdown columns into a single column of stacked bars.
+    /// The breakdown column headings are replaced by a legend of the markers.
+    /// Columns after the breakdown (ex: the breakdown right delimiter `|`) are dropped.
+    ///
+    /// Returns the stacked grid, along with the range of its stacked values.
+    /// When `diverging`, the range spans the negative and positive stacks on either side of zero.
+    /// Otherwise, the range spans the total length of the stacks.
+    fn stacked(
+        self,
+        positive_marker: char,
+        negative_marker: char,
+        diverging: bool,
+    ) -> (Grid, Range<f64>) {
+        let first = self
+            .columns
+            .types
+            .iter()
+            .position(|c| matches!(c.column_type, ColumnType::Breakdown))
+            .expect("must only stack a grid with a breakdown");
+        let mut columns = Columns::default();
+
+        for column in self.columns.types.iter().take(first) {
+            columns.push(match column.column_type {
+                ColumnType::String(_) => Column::string(column.alignment),
+                ColumnType::Count => Column::count(column.alignment),
+                ColumnType::Breakdown => unreachable!("the first breakdown is not taken"),
+            });
+        }
+
+        // stacked aggregate counts
+        columns.push(Column::count(Alignment::Left));
+        let mut grid = Grid::new(columns);
+        let mut minimum_value: f64 = 0.0;
+        let mut maximum_value: f64 = 0.0;
+
+        for ((mut row_cells, label), aggregate) in
+            self.rows.into_iter().zip(self.labels).zip(self.aggregates)
+        {
+            let mut row = Row {
+                cells: Vec::default(),
+                label,
+                aggregate,
+            };
+            let mut values = Vec::default();
+            let mut names = Vec::default();
+
+            for j in 0..first {
+                if let Some(cell) = row_cells.remove(&j) {
+                    row.push(cell.value);
+                }
+            }
+
+            let mut remaining: Vec<Cell> = row_cells.into_values().collect();
+            remaining.sort_by_key(|cell| cell.column);
+
+            for cell in remaining.iter() {
+                if matches!(
+                    self.columns.get(cell.column).column_type,
+                    ColumnType::Breakdown
+                ) {
+                    match &cell.value {
+                        Value::Bar(value) => values.push(*value),
+                        Value::String(name) => names.push(name.clone()),
+                        _ => {}
+                    }
+                }
+            }
+
+            if !values.is_empty() {
+                let negative: f64 = values.iter().map(|v| v.round().min(0.0)).sum();
+                let positive: f64 = values.iter().map(|v| v.round().max(0.0)).sum();
+
+                if diverging {
+                    minimum_value = minimum_value.min(negative);
+                    maximum_value = maximum_value.max(positive);
+                } else {
+                    maximum_value = maximum_value.max(positive - negative);
+                }
+
+                row.push(Value::Stack(values));
+            } else if !names.is_empty() {
+                let legend: Vec<String> = names
+                    .iter()
+                    .enumerate()
+                    .map(|(k, name)| {
+                        format!(
+                            "{} {name}",
+                            stack_marker(k, positive_marker, negative_marker)
+                        )
+                    })
+                    .collect();
+                row.push(Value::Plain(legend.join("  ")));
+            } else if let Some(cell) = remaining.into_iter().find(|cell| cell.column == first) {
+                row.push(cell.value);
+            }
+
+            grid.add(row);
+        }
+
+        (grid, minimum_value..maximum_value)
+    }
+
     /// Build the overflow overrides, which is a map from column index to "override width".
     fn build_overflow_overrides(&mut self) -> HashMap<usize, usize> {
         let mut overflow_overrides = HashMap::default();
@@ -511,20 +871,255 @@ pub struct Flat {
 }
 
 impl Flat {
-    pub(crate) fn new<C>(render: Render<C>, value_range: Range<f64>, mut grid: Grid) -> Self {
+    pub(crate) fn new<C>(render: Render<C>, value_range: Range<f64>, grid: Grid) -> Self {
+        let config: Config = render.into();
+        let (mut grid, value_range) = if config.stack_breakdown
+            && matches!(config.orientation, Orientation::Horizontal)
+            && grid.has_breakdown()
+        {
+            grid.stacked(
+                config.positive_marker,
+                config.negative_marker,
+                config.diverging,
+            )
+        } else {
+            (grid, value_range)
+        };
         let overflow_overrides = grid.build_overflow_overrides();
 
         Self {
-            config: render.into(),
+            config,
             value_range,
             grid,
             overflow_overrides,
         }
     }
+
+    /// Write the transposition of the grid, where each labelled row becomes a column of the rendering.
+    fn fmt_vertical(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut titles: Vec<String> = Vec::default();
+        let mut axis_label: Option<String> = None;
+        let mut breakdown_names: Vec<String> = Vec::default();
+        let mut groups: Vec<(String, Vec<f64>)> = Vec::default();
+        let mut aggregates: Vec<Option<String>> = Vec::default();
+
+        for ((row, label), aggregate) in self
+            .grid
+            .rows
+            .iter()
+            .zip(self.grid.labels.iter())
+            .zip(self.grid.aggregates.iter())
+        {
+            let mut cells: Vec<&Cell> = row.values().collect();
+            cells.sort_by_key(|cell| cell.column);
+            let values: Vec<f64> = cells
+                .iter()
+                .filter_map(|cell| match &cell.value {
+                    Value::Bar(value) => Some(*value),
+                    Value::Float(from, to) => Some(to - from),
+                    _ => None,
+                })
+                .collect();
+            let aggregate = aggregate
+                .as_ref()
+                .map(|aggregate| format!("[{}]", aggregate.trim()));
+
+            if values.is_empty() {
+                for cell in cells.iter() {
+                    match (&cell.value, &self.grid.columns.get(cell.column).column_type) {
+                        (Value::Plain(title), _) => titles.push(title.clone()),
+                        (Value::String(name), ColumnType::Breakdown) if label.is_some() => {
+                            breakdown_names.push(name.clone());
+                        }
+                        _ => {}
+                    }
+                }
+
+                if let Some(label) = label {
+                    axis_label.replace(label.clone());
+                }
+            } else if let Some(label) = label {
+                groups.push((label.clone(), values));
+                aggregates.push(aggregate);
+            }
+        }
+
+        let show_aggregate = aggregates.iter().any(Option::is_some);
+
+        let breakdown_abbreviations =
+            if self.config.abbreviate_breakdown && !breakdown_names.is_empty() {
+                let (_, abbreviations) = find_abbreviations(
+                    self.grid.minimum_breakdown_width,
+                    self.grid.maximum_breakdown_width,
+                    &self.grid.breakdown_values,
+                );
+                abbreviations
+            } else {
+                HashMap::default()
+            };
+        let breakdown_names: Vec<String> = breakdown_names
+            .into_iter()
+            .map(|name| breakdown_abbreviations.get(&name).cloned().unwrap_or(name))
+            .collect();
+        let sub_width = breakdown_names
+            .iter()
+            .map(|name| name.chars().count())
+            .max()
+            .unwrap_or(1);
+        let sub_columns = groups
+            .iter()
+            .map(|(_, values)| values.len())
+            .max()
+            .unwrap_or(1);
+        let inner_width = sub_columns * sub_width + (sub_columns - 1);
+
+        // The extents above and below the zero row.
+        let negative_extent = (-self.value_range.start).max(0.0).round() as i128;
+        let positive_extent = self.value_range.end.max(0.0).round() as i128;
+        let reserved = titles.len()
+            + usize::from(!breakdown_names.is_empty())
+            + 1
+            + usize::from(show_aggregate)
+            + usize::from(axis_label.is_some())
+            + usize::from(negative_extent > 0);
+        let mut view_height = self.config.height_hint.saturating_sub(reserved);
+
+        if view_height < 2 {
+            view_height = 2;
+        }
+
+        let mut value_height = negative_extent + positive_extent;
+
+        if value_height == 0 {
+            value_height = 1;
+        }
+
+        let mut scale = view_height as f64 / value_height as f64;
+
+        if scale >= 1.0 {
+            scale = 1.0;
+        }
+
+        // The signed height of each bar, where negative bars hang below the zero row.
+        let heights: Vec<Vec<isize>> = groups
+            .iter()
+            .map(|(_, values)| {
+                values
+                    .iter()
+                    .map(|value| (value.round() * scale) as isize)
+                    .collect()
+            })
+            .collect();
+        let maximum_height = heights.iter().flatten().copied().max().unwrap_or(0);
+        let minimum_height = heights.iter().flatten().copied().min().unwrap_or(0);
+        let group_widths: Vec<usize> = groups
+            .iter()
+            .zip(aggregates.iter())
+            .map(|((label, _), aggregate)| {
+                let aggregate_width = aggregate.as_ref().map_or(0, |a| a.chars().count());
+                label.chars().count().max(inner_width).max(aggregate_width)
+            })
+            .collect();
+        let draw_level = |level: isize| {
+            heights
+                .iter()
+                .zip(group_widths.iter())
+                .map(|(bars, group_width)| {
+                    let inner = bars
+                        .iter()
+                        .map(|height| {
+                            let cell = if level > 0 && *height >= level {
+                                self.config.positive_marker.to_string()
+                            } else if level < 0 && *height <= level {
+                                self.config.negative_marker.to_string()
+                            } else {
+                                String::default()
+                            };
+                            format!("{cell:^sub_width$}")
+                        })
+                        .collect::<Vec<String>>()
+                        .join(" ");
+                    format!("{inner:^group_width$}")
+                })
+                .collect::<Vec<String>>()
+                .join("  ")
+        };
+        let mut lines = titles;
+
+        for level in (1..=maximum_height).rev() {
+            lines.push(draw_level(level));
+        }
+
+        if minimum_height < 0 {
+            let width = group_widths.iter().sum::<usize>() + 2 * (group_widths.len() - 1);
+            lines.push(iter::repeat_n(VERTICAL_ZERO_AXIS, width).collect());
+
+            for level in 1..=-minimum_height {
+                lines.push(draw_level(-level));
+            }
+        }
+
+        if !groups.is_empty() {
+            if !breakdown_names.is_empty() {
+                let inner = breakdown_names
+                    .iter()
+                    .map(|name| format!("{name:^sub_width$}"))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                let line = group_widths
+                    .iter()
+                    .map(|group_width| format!("{inner:^group_width$}"))
+                    .collect::<Vec<String>>()
+                    .join("  ");
+                lines.push(line);
+            }
+
+            let line = groups
+                .iter()
+                .zip(group_widths.iter())
+                .map(|((label, _), group_width)| format!("{label:^group_width$}"))
+                .collect::<Vec<String>>()
+                .join("  ");
+            lines.push(line);
+
+            if show_aggregate {
+                let line = aggregates
+                    .iter()
+                    .zip(group_widths.iter())
+                    .map(|(aggregate, group_width)| {
+                        let aggregate = aggregate.clone().unwrap_or_default();
+                        format!("{aggregate:^group_width$}")
+                    })
+                    .collect::<Vec<String>>()
+                    .join("  ");
+                lines.push(line);
+            }
+        }
+
+        if let Some(axis_label) = axis_label {
+            lines.push(axis_label);
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            f.write_str(line.trim_end())?;
+
+            if i + 1 != lines.len() {
+                f.write_char('\n')?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Display for Flat {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if matches!(self.config.orientation, Orientation::Vertical)
+            && self.grid.labels.iter().any(Option::is_some)
+        {
+            return self.fmt_vertical(f);
+        }
+
         let mut view_width = self.config.width_hint.saturating_sub(
             self.grid
                 .columns
@@ -555,10 +1150,20 @@ impl Display for Flat {
             view_width = 2;
         }
 
-        let mut value_width = std::cmp::max(
-            self.value_range.start.abs().round() as i128,
-            self.value_range.end.abs().round() as i128,
-        );
+        // The extents on either side of the zero axis.
+        let negative_extent = (-self.value_range.start).max(0.0).round() as i128;
+        let positive_extent = self.value_range.end.max(0.0).round() as i128;
+
+        let mut value_width = if self.config.diverging {
+            // Make room for the zero axis itself.
+            view_width -= 1;
+            negative_extent + positive_extent
+        } else {
+            std::cmp::max(
+                self.value_range.start.abs().round() as i128,
+                self.value_range.end.abs().round() as i128,
+            )
+        };
 
         if value_width == 0 {
             value_width = 1;
@@ -571,6 +1176,13 @@ impl Display for Flat {
         } else {
             view_width
         };
+        let zero_axis = if self.config.diverging {
+            let negative_width = (negative_extent as f64 * scale) as usize;
+            Some((negative_width, width.saturating_sub(negative_width)))
+        } else {
+            None
+        };
+        let width = width + usize::from(self.config.diverging);
 
         let (abbreviation_width, breakdown_abbreviations) = match (
             self.config.abbreviate_breakdown,
@@ -603,6 +1215,7 @@ impl Display for Flat {
         let view = View {
             breakdown_abbreviations,
             scale,
+            zero_axis,
             positive_marker: self.config.positive_marker,
             negative_marker: self.config.negative_marker,
         };
@@ -651,6 +1264,8 @@ impl Display for Flat {
 struct View {
     breakdown_abbreviations: HashMap<String, String>,
     scale: f64,
+    /// The widths on the (negative, positive) sides of the zero axis, when the rendering is diverging.
+    zero_axis: Option<(usize, usize)>,
     positive_marker: char,
     negative_marker: char,
 }
@@ -662,7 +1277,7 @@ enum WrappedCell<'a> {
     Fill,
 }
 
-fn filled<'a>(rows: &'a HashMap<usize, Cell>) -> Vec<(usize, WrappedCell)> {
+fn filled(rows: &HashMap<usize, Cell>) -> Vec<(usize, WrappedCell<'_>)> {
     let maximum_j: usize = *rows.keys().max().expect("Row must not be empty");
     let mut out = Vec::default();
     let mut candidates = Vec::default();
@@ -697,6 +1312,61 @@ fn filled<'a>(rows: &'a HashMap<usize, Cell>) -> Vec<(usize, WrappedCell)> {
     out
 }
 
+const ZERO_AXIS: char = '│';
+
+/// The markers of the stacked breakdown segments, following the first segment (which uses the `positive_marker`).
+const STACK_MARKERS: &str =
+    "o+x#@%&=~*abcdefghijklmnpqrstuvwyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// The marker of the stacked breakdown segments beyond the [`STACK_MARKERS`], which can't be told apart.
+const STACK_OVERFLOW_MARKER: char = '?';
+
+/// The marker of the `k`th stacked breakdown segment.
+/// The `negative_marker` is left out, so that the segments aren't confused with the negative bars.
+fn stack_marker(k: usize, positive_marker: char, negative_marker: char) -> char {
+    if k == 0 {
+        positive_marker
+    } else {
+        STACK_MARKERS
+            .chars()
+            .filter(|marker| *marker != positive_marker && *marker != negative_marker)
+            .nth(k - 1)
+            .unwrap_or(STACK_OVERFLOW_MARKER)
+    }
+}
+
+/// Draw the (magnitude, marker) segments end to end.
+/// The cumulative boundaries are scaled, so that the rounding doesn't accumulate across the segments.
+fn segments(segments: impl Iterator<Item = (f64, char)>, scale: f64) -> String {
+    let mut cumulative = 0.0;
+    let mut start = 0;
+
+    segments
+        .flat_map(|(magnitude, marker)| {
+            cumulative += magnitude;
+            let end = (cumulative * scale) as usize;
+            let length = end - start;
+            start = end;
+            iter::repeat_n(marker, length)
+        })
+        .collect()
+}
+
+const VERTICAL_ZERO_AXIS: char = '─';
+
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+/// Pick the shade glyph for the magnitude of `value`, relative to the largest magnitude `extent`.
+/// Only zero values are left blank, so that any non-zero value remains visible.
+pub(crate) fn shade(value: f64, extent: f64) -> char {
+    if extent == 0.0 {
+        return SHADES[0];
+    }
+
+    let index = (value.abs() / extent * (SHADES.len() - 1) as f64).ceil() as usize;
+    SHADES[std::cmp::min(index, SHADES.len() - 1)]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -706,6 +1376,7 @@ mod tests {
         let view = View {
             breakdown_abbreviations: Default::default(),
             scale: 1.0,
+            zero_axis: None,
             positive_marker: '+',
             negative_marker: '-',
         };
@@ -721,6 +1392,7 @@ mod tests {
         let view = View {
             breakdown_abbreviations: Default::default(),
             scale: 1.0,
+            zero_axis: None,
             positive_marker: '+',
             negative_marker: '-',
         };
@@ -733,6 +1405,7 @@ mod tests {
         let view = View {
             breakdown_abbreviations: HashMap::from([("abc".to_string(), "12345".to_string())]),
             scale: 1.0,
+            zero_axis: None,
             positive_marker: '+',
             negative_marker: '-',
         };
@@ -746,6 +1419,7 @@ mod tests {
         let view = View {
             breakdown_abbreviations: Default::default(),
             scale: 1.0,
+            zero_axis: None,
             positive_marker: '+',
             negative_marker: '-',
         };
@@ -758,6 +1432,7 @@ mod tests {
         let view = View {
             breakdown_abbreviations: HashMap::from([("abc".to_string(), "12345".to_string())]),
             scale: 1.0,
+            zero_axis: None,
             positive_marker: '+',
             negative_marker: '-',
         };
@@ -771,6 +1446,7 @@ mod tests {
         let view = View {
             breakdown_abbreviations: Default::default(),
             scale: 1.0,
+            zero_axis: None,
             positive_marker: '+',
             negative_marker: '-',
         };
@@ -786,16 +1462,17 @@ mod tests {
         let view = View {
             breakdown_abbreviations: Default::default(),
             scale: 1.0,
+            zero_axis: None,
             positive_marker: '+',
             negative_marker: '-',
         };
 
-        let value = Value::Value(1.49);
+        let value = Value::Bar(1.49);
         assert_eq!(value.render_width(), None);
         assert_eq!(value.render(&view, false), "+");
         assert_eq!(value.render(&view, true), "+");
 
-        let value = Value::Value(1.5);
+        let value = Value::Bar(1.5);
         assert_eq!(value.render_width(), None);
         assert_eq!(value.render(&view, false), "++");
         assert_eq!(value.render(&view, true), "++");
@@ -803,20 +1480,129 @@ mod tests {
         let view = View {
             breakdown_abbreviations: HashMap::default(),
             scale: 2.0,
+            zero_axis: None,
             positive_marker: '+',
             negative_marker: '-',
         };
-        let value = Value::Value(1.49);
+        let value = Value::Bar(1.49);
         assert_eq!(value.render_width(), None);
         assert_eq!(value.render(&view, false), "++");
         assert_eq!(value.render(&view, true), "++");
 
-        let value = Value::Value(-1.49);
+        let value = Value::Bar(-1.49);
         assert_eq!(value.render_width(), None);
         assert_eq!(value.render(&view, false), "--");
         assert_eq!(value.render(&view, true), "--");
     }
 
+    #[test]
+    fn render_stack() {
+        let view = View {
+            breakdown_abbreviations: Default::default(),
+            scale: 1.0,
+            zero_axis: None,
+            positive_marker: '+',
+            negative_marker: '-',
+        };
+
+        let value = Value::Stack(vec![1.0, 0.0, 2.0, -1.49]);
+        assert_eq!(value.render_width(), None);
+        assert_eq!(value.render(&view, false), "+xx-");
+
+        let view = View {
+            breakdown_abbreviations: HashMap::default(),
+            scale: 0.5,
+            zero_axis: None,
+            positive_marker: '+',
+            negative_marker: '-',
+        };
+        let value = Value::Stack(vec![4.0, 2.0]);
+        assert_eq!(value.render(&view, false), "++o");
+
+        let view = View {
+            breakdown_abbreviations: HashMap::default(),
+            scale: 1.0,
+            zero_axis: Some((3, 4)),
+            positive_marker: '+',
+            negative_marker: '-',
+        };
+        let value = Value::Stack(vec![2.0, -1.0, 1.0, -1.0]);
+        assert_eq!(value.render(&view, false), " #o│++x");
+    }
+
+    #[test]
+    fn stack_markers() {
+        assert_eq!(stack_marker(0, '*', '⊖'), '*');
+        assert_eq!(stack_marker(1, '*', '⊖'), 'o');
+        assert_eq!(stack_marker(10, '*', '⊖'), 'a');
+        assert_eq!(stack_marker(1, 'o', '⊖'), '+');
+        assert_eq!(stack_marker(1, '*', 'o'), '+');
+        assert_eq!(stack_marker(2, 'o', '+'), '#');
+
+        // Each of the categories has a distinct marker, until the markers run out.
+        let markers: HashSet<char> = (0..60).map(|k| stack_marker(k, '*', '-')).collect();
+        assert_eq!(markers.len(), 60);
+        assert_eq!(stack_marker(1000, '*', '-'), STACK_OVERFLOW_MARKER);
+    }
+
+    #[test]
+    fn render_diverging() {
+        let view = View {
+            breakdown_abbreviations: HashMap::default(),
+            scale: 1.0,
+            zero_axis: Some((3, 4)),
+            positive_marker: '+',
+            negative_marker: '-',
+        };
+
+        let value = Value::Bar(-2.0);
+        assert_eq!(value.render(&view, false), " --│");
+        assert_eq!(value.render(&view, true), " --│    ");
+
+        let value = Value::Bar(2.0);
+        assert_eq!(value.render(&view, false), "   │++");
+        assert_eq!(value.render(&view, true), "   │++  ");
+
+        let value = Value::Bar(0.0);
+        assert_eq!(value.render(&view, false), "   │");
+    }
+
+    #[test]
+    fn render_float() {
+        let view = View {
+            breakdown_abbreviations: HashMap::default(),
+            scale: 1.0,
+            zero_axis: None,
+            positive_marker: '+',
+            negative_marker: '-',
+        };
+
+        let value = Value::Float(2.0, 4.49);
+        assert_eq!(value.render_width(), None);
+        assert_eq!(value.render(&view, false), "  ++");
+
+        let value = Value::Float(3.0, 1.0);
+        assert_eq!(value.render(&view, false), " --");
+
+        let value = Value::Float(1.0, -2.0);
+        assert_eq!(value.render(&view, false), "-");
+
+        let view = View {
+            breakdown_abbreviations: HashMap::default(),
+            scale: 1.0,
+            zero_axis: Some((2, 3)),
+            positive_marker: '+',
+            negative_marker: '-',
+        };
+
+        let value = Value::Float(1.0, -2.0);
+        assert_eq!(value.render(&view, false), "--│-");
+        assert_eq!(value.render(&view, true), "--│-  ");
+
+        let value = Value::Float(1.0, 2.0);
+        assert_eq!(value.render(&view, false), "  │ +");
+    }
+
     #[test]
     fn render_width_skip() {
         let value = Value::Skip;
@@ -829,6 +1615,7 @@ mod tests {
         let view = View {
             breakdown_abbreviations: Default::default(),
             scale: 1.0,
+            zero_axis: None,
             positive_marker: '+',
             negative_marker: '-',
         };
@@ -1011,7 +1798,7 @@ mod tests {
         let mut row = Row::default();
         row.push(Value::Overflow("abc".to_string()));
         row.push(Value::Skip);
-        row.push(Value::Value(0.0));
+        row.push(Value::Bar(0.0));
 
         // Execute
         grid.add(row);
@@ -1048,7 +1835,7 @@ mod tests {
                     2,
                     Cell {
                         column: 2,
-                        value: Value::Value(0.0),
+                        value: Value::Bar(0.0),
                     }
                 )
             ])]
@@ -1177,7 +1964,7 @@ mod tests {
         columns.push(Column::string(Alignment::Center));
         let mut grid = Grid::new(columns);
         let mut row = Row::default();
-        row.push(Value::Value(0.0));
+        row.push(Value::Bar(0.0));
 
         // Execute
         grid.add(row);
@@ -1193,7 +1980,7 @@ mod tests {
                 0,
                 Cell {
                     column: 0,
-                    value: Value::Value(0.0),
+                    value: Value::Bar(0.0),
                 }
             )])]
         );
@@ -1544,7 +2331,7 @@ mod tests {
         columns.push(Column::count(Alignment::Center));
         let mut grid = Grid::new(columns);
         let mut row = Row::default();
-        row.push(Value::Value(0.0));
+        row.push(Value::Bar(0.0));
 
         // Execute
         grid.add(row);
@@ -1560,7 +2347,7 @@ mod tests {
                 0,
                 Cell {
                     column: 0,
-                    value: Value::Value(0.0),
+                    value: Value::Bar(0.0),
                 }
             )])]
         );
@@ -1725,7 +2512,7 @@ mod tests {
         columns.push(Column::breakdown(Alignment::Center));
         let mut grid = Grid::new(columns);
         let mut row = Row::default();
-        row.push(Value::Value(0.0));
+        row.push(Value::Bar(0.0));
 
         // Execute
         grid.add(row);
@@ -1741,7 +2528,7 @@ mod tests {
                 0,
                 Cell {
                     column: 0,
-                    value: Value::Value(0.0),
+                    value: Value::Bar(0.0),
                 }
             )])]
         );
@@ -1770,16 +2557,27 @@ mod tests {
         columns.push(Column::string(Alignment::Left));
         let mut grid = Grid::new(columns);
         let mut row1 = Row::default();
-        row1.push(Value::Value(1.0));
+        row1.push(Value::Bar(1.0));
         grid.add(row1);
         let mut row2 = Row::default();
-        row2.push(Value::Value(0.0));
+        row2.push(Value::Bar(0.0));
         grid.add(row2);
         let mut row3 = Row::default();
-        row3.push(Value::Value(2.0));
+        row3.push(Value::Bar(2.0));
         grid.add(row3);
         let flat = Flat::new(render, value_range, grid);
 
         assert_eq!(flat.to_string(), "*\n\n**");
     }
+
+    #[test]
+    fn shade_extent() {
+        assert_eq!(shade(0.0, 0.0), ' ');
+        assert_eq!(shade(0.0, 4.0), ' ');
+        assert_eq!(shade(0.1, 4.0), '░');
+        assert_eq!(shade(1.0, 4.0), '░');
+        assert_eq!(shade(2.0, 4.0), '▒');
+        assert_eq!(shade(-3.0, 4.0), '▓');
+        assert_eq!(shade(4.0, 4.0), '█');
+    }
 }
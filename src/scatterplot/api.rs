@@ -0,0 +1,79 @@
+use ordered_float::OrderedFloat;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::ScatterPlot;
+
+/// Render configuration specific to [`ScatterPlot`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::ScatterPlotConfig;
+/// let scatterplot_config = ScatterPlotConfig {
+///     ..ScatterPlotConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct ScatterPlotConfig {}
+
+/// Allows a type `T` to be used as a numeric axis, such as the *primary* dimension of a [`ScatterPlot`].
+/// Consumers may choose to implement this to plot non-standard types.
+pub trait Coordinate {
+    /// Get the position of this value (`self: T`) along the axis.
+    fn coordinate(&self) -> f64;
+}
+
+impl<T: Coordinate> Coordinate for &T {
+    fn coordinate(&self) -> f64 {
+        (*self).coordinate()
+    }
+}
+
+macro_rules! impl_coordinate {
+    ($T:ty) => {
+        impl Coordinate for $T {
+            fn coordinate(&self) -> f64 {
+                *self as f64
+            }
+        }
+    };
+}
+
+impl_coordinate!(f64);
+impl_coordinate!(f32);
+impl_coordinate!(isize);
+impl_coordinate!(i64);
+impl_coordinate!(i32);
+impl_coordinate!(i16);
+impl_coordinate!(i8);
+impl_coordinate!(usize);
+impl_coordinate!(u64);
+impl_coordinate!(u32);
+impl_coordinate!(u16);
+impl_coordinate!(u8);
+
+impl Coordinate for OrderedFloat<f64> {
+    fn coordinate(&self) -> f64 {
+        self.0
+    }
+}
+
+impl Coordinate for OrderedFloat<f32> {
+    fn coordinate(&self) -> f64 {
+        self.0 as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coordinate() {
+        assert_eq!(1.5f64.coordinate(), 1.5);
+        assert_eq!(1.5f32.coordinate(), 1.5);
+        assert_eq!((-2i64).coordinate(), -2.0);
+        assert_eq!(3u8.coordinate(), 3.0);
+        assert_eq!(OrderedFloat(0.25f64).coordinate(), 0.25);
+        assert_eq!(<&usize as Coordinate>::coordinate(&&4), 4.0);
+    }
+}
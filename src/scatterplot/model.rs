@@ -0,0 +1,306 @@
+use crate::canvas::{extent, extent_labels, legend, marker, normalize, Axes, Canvas};
+use crate::render::{Flat, Render};
+use crate::{Coordinate, ScatterPlotConfig, Schema, View};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// The scatter-plot widget.
+///
+/// A scatter-plot draws each data point of the view at the position of its primary dimension (1st, x-axis) and value (y-axis).
+/// The primary dimension must be a [`Coordinate`] type.
+/// Points are not aggregated.
+///
+/// The plot is sized to the `width_hint` and `height_hint` of the [`Render`] configuration.
+/// Without a breakdown, points are drawn at the braille (U+2800) sub-character resolution.
+/// With a breakdown, each breakdown value is drawn with a distinct marker, labelled in the legend.
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema2<f64, f64> = Schemas::two("Length", "Width");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add((4.0, 2.0))
+///     .add((5.0, 2.5))
+///     .add((6.0, 3.0))
+///     .add((7.0, 4.0))
+///     .build();
+/// let view = dataset.view_2nd();
+/// let flat = ScatterPlot::new(&view)
+///     .render(Render {
+///         width_hint: 16,
+///         height_hint: 8,
+///         ..Render::default()
+///     });
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Width
+/// 4 │            ⠈
+///   │
+///   │    ⡀   ⠈
+/// 2 │⡀
+///   └─────────────
+///    4           7
+///    Length"#);
+/// # }
+/// ```
+pub struct ScatterPlot<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> ScatterPlot<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Coordinate,
+    <V as View<S>>::BreakdownDimension: Clone + Display + PartialEq + Ord,
+{
+    /// Construct a scatter-plot widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this scatter-plot.
+    pub fn render(self, config: Render<ScatterPlotConfig>) -> Flat {
+        let mut points: Vec<(f64, f64, V::BreakdownDimension)> = Vec::default();
+        let mut sort_breakdowns: Vec<V::BreakdownDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let x = self.view.primary_dim(dims).coordinate();
+            let y = self.view.value(dims);
+            let breakdown_dim = self.view.breakdown_dim(dims);
+
+            if !sort_breakdowns.contains(&breakdown_dim) {
+                sort_breakdowns.push(breakdown_dim.clone());
+            }
+
+            points.push((x, y, breakdown_dim));
+        }
+
+        sort_breakdowns.sort();
+
+        let x_extent = extent(points.iter().map(|(x, _, _)| *x));
+        let axes = Axes {
+            x_title: self.view.display_headers()[0].clone(),
+            x_labels: extent_labels(x_extent),
+            y_title: self.view.value_label(),
+            y_extent: extent(points.iter().map(|(_, y, _)| *y)),
+            legend: self.view.breakdown_label().map(|breakdown_header| {
+                let names: Vec<String> = sort_breakdowns.iter().map(|b| b.to_string()).collect();
+                legend(&breakdown_header, &names)
+            }),
+        };
+        let (x_minimum, x_maximum) = x_extent;
+        let (y_minimum, y_maximum) = axes.y_extent;
+        let (canvas_width, canvas_height) = axes.canvas_size(config.width_hint, config.height_hint);
+        let mut canvas = Canvas::new(canvas_width, canvas_height);
+
+        for (x, y, breakdown_dim) in points.iter() {
+            let x = normalize(*x, x_minimum, x_maximum);
+            let y = normalize(*y, y_minimum, y_maximum);
+
+            if self.view.breakdown_label().is_some() {
+                let k = sort_breakdowns
+                    .iter()
+                    .position(|b| b == breakdown_dim)
+                    .expect("breakdown must be sorted");
+                canvas.marker(x, y, marker(k));
+            } else {
+                canvas.dot(x, y);
+            }
+        }
+
+        Flat::new(config, y_minimum..y_maximum, axes.grid(&canvas))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{DatasetBuilder, Schema2, Schema3, Schemas};
+        use crate::{Render, ScatterPlot};
+
+        #[test]
+        fn empty() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.view_2nd();
+            let scatterplot = ScatterPlot::new(&view);
+            let flat = scatterplot.render(Render {
+                width_hint: 10,
+                height_hint: 6,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+def
+0 │
+0 │
+  └───────
+   0     0
+   abc"#
+            );
+        }
+
+        #[test]
+        fn single() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema).add((1, 1)).build();
+            let view = dataset.view_2nd();
+            let scatterplot = ScatterPlot::new(&view);
+            let flat = scatterplot.render(Render {
+                width_hint: 10,
+                height_hint: 6,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+def
+1 │
+1 │   ⠈
+  └───────
+   1     1
+   abc"#
+            );
+        }
+
+        #[test]
+        fn diagonal() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((0, 0))
+                .add((1, 1))
+                .add((2, 2))
+                .add((3, 3))
+                .add((4, 4))
+                .add((5, 5))
+                .add((6, 6))
+                .add((7, 7))
+                .build();
+            let view = dataset.view_2nd();
+            let scatterplot = ScatterPlot::new(&view);
+            let flat = scatterplot.render(Render {
+                width_hint: 10,
+                height_hint: 6,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+def
+7 │   ⢀⠠⠐⠈
+0 │⡀⠄⠂⠁
+  └───────
+   0     7
+   abc"#
+            );
+        }
+
+        #[test]
+        fn negatives() {
+            let schema: Schema2<f64, f64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((-1.5, 10.0))
+                .add((0.0, -10.0))
+                .add((1.5, 0.0))
+                .build();
+            let view = dataset.view_2nd();
+            let scatterplot = ScatterPlot::new(&view);
+            let flat = scatterplot.render(Render {
+                width_hint: 14,
+                height_hint: 7,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+def
+ 10 │⠁
+    │        ⠠
+-10 │    ⢀
+    └─────────
+     -1.5  1.5
+     abc"#
+            );
+        }
+
+        #[test]
+        fn breakdown() {
+            let schema: Schema3<u8, &str, u8> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a", 1))
+                .add((2, "a", 2))
+                .add((3, "a", 3))
+                .add((1, "b", 3))
+                .add((3, "b", 1))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let scatterplot = ScatterPlot::new(&view);
+            let flat = scatterplot.render(Render {
+                width_hint: 12,
+                height_hint: 8,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+ghi
+3 │o       *
+  │    *
+1 │*       o
+  └─────────
+   1       3
+   abc
+   def: * a  o b"#
+            );
+        }
+    }
+
+    #[cfg(feature = "pointer_impls")]
+    mod pointer_impls {
+        use crate::{DatasetBuilder, Schema2, Schemas};
+        use crate::{Render, ScatterPlot};
+        use ordered_float::OrderedFloat;
+
+        #[test]
+        fn view2() {
+            let schema: Schema2<OrderedFloat<f64>, OrderedFloat<f64>> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((OrderedFloat(0.1), OrderedFloat(1.0)))
+                .add((OrderedFloat(0.2), OrderedFloat(2.0)))
+                .add((OrderedFloat(0.3), OrderedFloat(4.0)))
+                .build();
+            let view = dataset.view_2nd();
+            let scatterplot = ScatterPlot::new(&view);
+            let flat = scatterplot.render(Render {
+                width_hint: 10,
+                height_hint: 6,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+def
+4 │      ⠈
+1 │⡀  ⠐
+  └───────
+   0.1 0.3
+   abc"#
+            );
+        }
+    }
+}
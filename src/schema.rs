@@ -196,6 +196,46 @@ impl<T, U, V, W> Schema for Schema4<T, U, V, W> {
     type Dimensions = (T, U, V, W);
 }
 
+/// Allows a schema to describe the names of its dimensions, such as for the headers of a [`crate::Table`].
+/// Consumers may choose to implement this for their custom [`Schema`]s.
+pub trait Headers {
+    /// Get the names of the dimensions, in order.
+    fn headers(&self) -> Vec<String>;
+}
+
+impl<T> Headers for Schema1<T> {
+    fn headers(&self) -> Vec<String> {
+        vec![self.dimension_0.clone()]
+    }
+}
+
+impl<T, U> Headers for Schema2<T, U> {
+    fn headers(&self) -> Vec<String> {
+        vec![self.dimension_0.clone(), self.dimension_1.clone()]
+    }
+}
+
+impl<T, U, V> Headers for Schema3<T, U, V> {
+    fn headers(&self) -> Vec<String> {
+        vec![
+            self.dimension_0.clone(),
+            self.dimension_1.clone(),
+            self.dimension_2.clone(),
+        ]
+    }
+}
+
+impl<T, U, V, W> Headers for Schema4<T, U, V, W> {
+    fn headers(&self) -> Vec<String> {
+        vec![
+            self.dimension_0.clone(),
+            self.dimension_1.clone(),
+            self.dimension_2.clone(),
+            self.dimension_3.clone(),
+        ]
+    }
+}
+
 // TODO
 // pub struct Schema5<T, U, V, W, X>;
 // pub struct Schema6<T, U, V, W, X, Y>;
@@ -0,0 +1,15 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::Sparkline;
+
+/// Render configuration specific to [`Sparkline`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::SparklineConfig;
+/// let sparkline_config = SparklineConfig {
+///     ..SparklineConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct SparklineConfig {}
@@ -0,0 +1,362 @@
+use crate::aggregate::{aggregate_apply, minimal_precision_string};
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{Schema, SparklineConfig, View};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::DagChart;
+
+const GLYPHS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// The sparkline widget.
+///
+/// A sparkline draws the aggregate of each unique value of the view's primary dimension (1st) as a single glyph, in the sort order of the primary dimension.
+/// This fits a whole view onto one line, which suits status lines and log summaries where a [`DagChart`] is too tall.
+/// In the case of a breakdown, one sparkline is drawn per breakdown value.
+///
+/// See also: [`DagChart`]
+///
+/// ```
+/// use flat::*;
+///
+/// let schema = Schemas::one("Hour");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add((1, ))
+///     .add((2, ))
+///     .add((2, ))
+///     .add((3, ))
+///     .add((3, ))
+///     .add((3, ))
+///     .add((3, ))
+///     .add((4, ))
+///     .build();
+/// let view = dataset.count();
+/// let flat = Sparkline::new(&view)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Sum(Count)  ▃▅█▃"#);
+/// ```
+pub struct Sparkline<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> Sparkline<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone + PartialEq + Eq + Hash + Ord,
+    <V as View<S>>::BreakdownDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+{
+    /// Construct a sparkline widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this sparkline.
+    pub fn render(self, config: Render<SparklineConfig>) -> Flat {
+        let mut aggregate_values: HashMap<(V::PrimaryDimension, V::BreakdownDimension), Vec<f64>> =
+            HashMap::default();
+        let mut sort_primaries: Vec<V::PrimaryDimension> = Vec::default();
+        let mut sort_breakdowns: Vec<V::BreakdownDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let value = self.view.value(dims);
+            let primary_dim = self.view.primary_dim(dims);
+            let breakdown_dim = self.view.breakdown_dim(dims);
+            let aggregate_dims = (primary_dim.clone(), breakdown_dim.clone());
+            let values = aggregate_values.entry(aggregate_dims).or_default();
+            values.push(value);
+
+            if !sort_primaries.contains(&primary_dim) {
+                sort_primaries.push(primary_dim);
+            }
+
+            if !sort_breakdowns.contains(&breakdown_dim) {
+                sort_breakdowns.push(breakdown_dim);
+            }
+        }
+
+        sort_primaries.sort();
+        sort_breakdowns.sort();
+
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+        // Primary values without any data for the breakdown are left as gaps in the sparkline.
+        let mut lines: Vec<Vec<Option<f64>>> = sort_breakdowns
+            .iter()
+            .map(|breakdown_dim| {
+                sort_primaries
+                    .iter()
+                    .map(|primary_dim| {
+                        let aggregate_dims = (primary_dim.clone(), breakdown_dim.clone());
+
+                        if aggregate_values.contains_key(&aggregate_dims) {
+                            Some(aggregate_apply(
+                                &config.aggregate,
+                                &aggregate_values,
+                                &aggregate_dims,
+                                &mut minimum_value,
+                                &mut maximum_value,
+                            ))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        if self.view.breakdown_label().is_none() && lines.is_empty() {
+            lines.push(Vec::default());
+        }
+
+        // Glyphs are anchored at zero, the same as the bars of the other widgets.
+        let lower = minimum_value.min(0.0);
+        let upper = maximum_value.max(0.0);
+
+        let mut columns = Columns::default();
+        // line label
+        columns.push(Column::string(Alignment::Left));
+
+        if config.show_aggregate {
+            // spacer " "
+            columns.push(Column::string(Alignment::Center));
+            // total left [
+            columns.push(Column::string(Alignment::Left));
+            // total value
+            columns.push(Column::string(Alignment::Right));
+            // total right ]
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // sparkline
+        columns.push(Column::string(Alignment::Left));
+
+        let mut grid = Grid::new(columns);
+        let value_label = format!("{}({})", config.aggregate, self.view.value_label());
+
+        if let Some(breakdown_header) = self.view.breakdown_label() {
+            let mut header = Row::default();
+            header.push(Value::String(breakdown_header));
+
+            if config.show_aggregate {
+                header.push(Value::Empty);
+                header.push(Value::Overflow(config.aggregate.to_string()));
+                header.push(Value::Skip);
+                header.push(Value::Skip);
+            }
+
+            header.push(Value::String("  ".to_string()));
+            header.push(Value::Plain(value_label.clone()));
+            grid.add(header);
+        }
+
+        for (i, values) in lines.iter().enumerate() {
+            let mut row = Row::default();
+
+            if self.view.breakdown_label().is_some() {
+                row.push(Value::String(sort_breakdowns[i].to_string()));
+            } else {
+                row.push(Value::String(value_label.clone()));
+            }
+
+            if config.show_aggregate {
+                row.push(Value::String(" ".to_string()));
+                let values: Vec<f64> = values.iter().flatten().copied().collect();
+                row.push_aggregate(minimal_precision_string(
+                    config.aggregate.apply(values.as_slice()),
+                ));
+            }
+
+            row.push(Value::String("  ".to_string()));
+            row.push(Value::String(
+                values
+                    .iter()
+                    .map(|value| match value {
+                        Some(value) => glyph(*value, lower, upper),
+                        None => ' ',
+                    })
+                    .collect(),
+            ));
+            grid.add(row);
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+fn glyph(value: f64, lower: f64, upper: f64) -> char {
+    if upper == lower {
+        return GLYPHS[0];
+    }
+
+    let index = ((value - lower) / (upper - lower) * (GLYPHS.len() - 1) as f64).round() as usize;
+    GLYPHS[index.min(GLYPHS.len() - 1)]
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{DatasetBuilder, Schema1, Schema2, Schema3, Schemas};
+        use crate::{Render, Sparkline};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.count();
+            let sparkline = Sparkline::new(&view);
+            let flat = sparkline.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(Count)  "#
+            );
+        }
+
+        #[test]
+        fn zero() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).add((0,)).add((0,)).build();
+            let view = dataset.reflect_1st();
+            let sparkline = Sparkline::new(&view);
+            let flat = sparkline.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(abc)  ▁"#
+            );
+        }
+
+        #[test]
+        fn negatives_and_positives() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((-4,))
+                .add((-1,))
+                .add((0,))
+                .add((2,))
+                .add((4,))
+                .build();
+            let view = dataset.reflect_1st();
+            let sparkline = Sparkline::new(&view);
+            let flat = sparkline.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(abc)  ▁▄▅▆█"#
+            );
+        }
+
+        #[test]
+        fn show_aggregate() {
+            let schema: Schema2<u8, u8> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 2))
+                .add((2, 4))
+                .add((2, 4))
+                .add((3, 1))
+                .build();
+            let view = dataset.view_2nd();
+            let sparkline = Sparkline::new(&view);
+            let flat = sparkline.render(Render {
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(def) [11]  ▃█▂"#
+            );
+        }
+
+        #[test]
+        fn breakdown() {
+            let schema: Schema3<u8, &str, u8> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a", 2))
+                .add((2, "a", 4))
+                .add((3, "a", 8))
+                .add((1, "bb", 8))
+                .add((3, "bb", 1))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let sparkline = Sparkline::new(&view);
+            let flat = sparkline.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+def  Sum(ghi)
+a    ▃▅█
+bb   █ ▂"#
+            );
+        }
+
+        #[test]
+        fn breakdown_show_aggregate() {
+            let schema: Schema3<u8, &str, u8> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a", 2))
+                .add((2, "a", 4))
+                .add((3, "a", 8))
+                .add((1, "bb", 8))
+                .add((3, "bb", 1))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let sparkline = Sparkline::new(&view);
+            let flat = sparkline.render(Render {
+                show_aggregate: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+def Sum   Sum(ghi)
+a   [14]  ▃▅█
+bb  [ 9]  █ ▂"#
+            );
+        }
+    }
+
+    #[cfg(feature = "pointer_impls")]
+    mod pointer_impls {
+        use crate::{DatasetBuilder, Schema2, Schemas};
+        use crate::{Render, Sparkline};
+        use ordered_float::OrderedFloat;
+
+        #[test]
+        fn view2() {
+            let schema: Schema2<i64, OrderedFloat<f64>> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, OrderedFloat(1.0)))
+                .add((2, OrderedFloat(2.5)))
+                .add((3, OrderedFloat(4.0)))
+                .build();
+            let view = dataset.view_2nd();
+            let sparkline = Sparkline::new(&view);
+            let flat = sparkline.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+Sum(def)  ▃▅█"#
+            );
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::StemLeaf;
+
+/// Render configuration specific to [`StemLeaf`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::StemLeafConfig;
+/// let stemleaf_config = StemLeafConfig {
+///     stem_unit: Some(10.0),
+///     ..StemLeafConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct StemLeafConfig {
+    /// The unit of the stems, which must be a power of 10 (ex: `0.1`, `1`, `10`).
+    /// Each leaf is then the next digit, in units of `stem_unit / 10`.
+    /// For example, a `stem_unit` of `10` draws the value `123` as `12 | 3`.
+    ///
+    /// When `None`, the stem unit is chosen so that the values span at most about 10 stems.
+    /// The automatic stem unit is also used when the `stem_unit` is not positive (or finite), or when it would draw more than 100 stems.
+    ///
+    /// Default: `None`.
+    pub stem_unit: Option<f64>,
+}
@@ -0,0 +1,514 @@
+use crate::aggregate::minimal_precision_string;
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{Coordinate, Schema, StemLeafConfig, View};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::Histogram;
+
+/// The number of stems the automatic stem unit aims to stay within.
+const TARGET_STEMS: f64 = 10.0;
+
+/// The number of stems a configured stem unit may span, beyond which the automatic stem unit is used instead.
+const MAXIMUM_STEMS: f64 = 10.0 * TARGET_STEMS;
+
+/// The stem-and-leaf widget.
+///
+/// A stem-and-leaf plot draws each value of the view's primary dimension (1st) as a leaf digit on the stem of its leading digits (ex: `12 | 3` for `123`).
+/// This keeps the actual values in the rendering, while still showing the shape of the distribution.
+/// The primary dimension must be a [`Coordinate`] type (ex: the views from `reflect_1st` or `count`).
+/// The stem unit is chosen automatically, unless it is set in the [`StemLeafConfig`].
+/// The key at the bottom of the rendering shows how to read the stems and leaves.
+///
+/// With a breakdown, the plots are drawn back-to-back: the first breakdown value extends to the left of the stems, and the others to the right.
+///
+/// See also: [`Histogram`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema1<u32> = Schemas::one("Score");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add((58,))
+///     .add((63,))
+///     .add((67,))
+///     .add((71,))
+///     .add((74,))
+///     .add((74,))
+///     .add((92,))
+///     .build();
+/// let view = dataset.reflect_1st();
+/// let flat = StemLeaf::new(&view)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Score
+/// 5 | 8
+/// 6 | 3 7
+/// 7 | 1 4 4
+/// 8 |
+/// 9 | 2
+/// Key: 1 | 2 = 12"#);
+/// # }
+/// ```
+pub struct StemLeaf<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> StemLeaf<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Coordinate,
+    <V as View<S>>::BreakdownDimension: Clone + Display + PartialEq + Ord,
+{
+    /// Construct a stem-and-leaf widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this stem-and-leaf plot.
+    pub fn render(self, config: Render<StemLeafConfig>) -> Flat {
+        let mut values: Vec<(f64, V::BreakdownDimension)> = Vec::default();
+        let mut sort_breakdowns: Vec<V::BreakdownDimension> = Vec::default();
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+
+        for dims in self.view.dataset().data() {
+            let value = self.view.primary_dim(dims).coordinate();
+            let breakdown_dim = self.view.breakdown_dim(dims);
+            minimum_value = minimum_value.min(value);
+            maximum_value = maximum_value.max(value);
+
+            if !sort_breakdowns.contains(&breakdown_dim) {
+                sort_breakdowns.push(breakdown_dim.clone());
+            }
+
+            values.push((value, breakdown_dim));
+        }
+
+        if values.is_empty() {
+            minimum_value = 0.0;
+            maximum_value = 0.0;
+        }
+
+        sort_breakdowns.sort();
+
+        let leaf_unit =
+            choose_stem_unit(config.widget_config.stem_unit, minimum_value, maximum_value) / 10.0;
+        let mut leaves: HashMap<(i64, usize), Vec<u8>> = HashMap::default();
+
+        for (value, breakdown_dim) in values.iter() {
+            let (stem, leaf) = split(*value, leaf_unit);
+            let k = sort_breakdowns
+                .iter()
+                .position(|b| b == breakdown_dim)
+                .expect("breakdown must be observed");
+            leaves.entry((stem, k)).or_default().push(leaf);
+        }
+
+        for stem_leaves in leaves.values_mut() {
+            stem_leaves.sort();
+        }
+
+        let stems = leaves.keys().map(|(stem, _)| *stem);
+        let stem_range = stems
+            .clone()
+            .min()
+            .zip(stems.max())
+            .map(|(minimum, maximum)| minimum..=maximum)
+            .into_iter()
+            .flatten();
+        let is_breakdown = self.view.breakdown_label().is_some();
+        let mut columns = Columns::default();
+
+        if is_breakdown {
+            // first breakdown leaves
+            columns.push(Column::string(Alignment::Right));
+            // delimiter |
+            columns.push(Column::string(Alignment::Center));
+            // stem
+            columns.push(Column::string(Alignment::Right));
+
+            for _ in 1..sort_breakdowns.len() {
+                // delimiter |
+                columns.push(Column::string(Alignment::Center));
+                // breakdown leaves
+                columns.push(Column::string(Alignment::Left));
+            }
+        } else {
+            // stem
+            columns.push(Column::string(Alignment::Right));
+            // delimiter |
+            columns.push(Column::string(Alignment::Center));
+            // leaves
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        let mut grid = Grid::new(columns);
+        let mut title = Row::default();
+        title.push(Value::Plain(self.view.display_headers()[0].clone()));
+        grid.add(title);
+
+        if is_breakdown {
+            let mut header = Row::default();
+
+            for (k, breakdown_dim) in sort_breakdowns.iter().enumerate() {
+                if k == 0 {
+                    header.push(Value::String(format!("{breakdown_dim} ")));
+                    header.push(Value::Empty);
+                    header.push(Value::Empty);
+                } else {
+                    header.push(Value::Empty);
+                    header.push(Value::String(format!(" {breakdown_dim}")));
+                }
+            }
+
+            grid.add(header);
+        }
+
+        for stem in stem_range {
+            let draw = |k: usize, reverse: bool| -> Value {
+                match leaves.get(&(stem, k)) {
+                    Some(stem_leaves) => {
+                        let mut digits: Vec<String> =
+                            stem_leaves.iter().map(|leaf| leaf.to_string()).collect();
+
+                        if reverse {
+                            digits.reverse();
+                            Value::String(format!("{} ", digits.join(" ")))
+                        } else {
+                            Value::String(format!(" {}", digits.join(" ")))
+                        }
+                    }
+                    None => Value::Empty,
+                }
+            };
+            let mut row = Row::default();
+
+            if is_breakdown {
+                row.push(draw(0, true));
+                row.push(Value::String("|".to_string()));
+                row.push(Value::String(format!(" {} ", stem_label(stem))));
+
+                for k in 1..sort_breakdowns.len() {
+                    row.push(Value::String("|".to_string()));
+                    row.push(draw(k, false));
+                }
+            } else {
+                row.push(Value::String(format!("{} ", stem_label(stem))));
+                row.push(Value::String("|".to_string()));
+                row.push(draw(0, false));
+            }
+
+            grid.add(row);
+        }
+
+        let mut key = Row::default();
+        key.push(Value::Plain(format!(
+            "Key: 1 | 2 = {}",
+            minimal_precision_string(12.0 * leaf_unit)
+        )));
+        grid.add(key);
+
+        Flat::new(config, 0.0..0.0, grid)
+    }
+}
+
+/// Choose the configured stem unit, falling back to the automatic stem unit when the configured unit is not positive (or finite), or when it spans more than [`MAXIMUM_STEMS`] stems.
+fn choose_stem_unit(configured: Option<f64>, minimum: f64, maximum: f64) -> f64 {
+    match configured {
+        Some(stem_unit)
+            if stem_unit.is_finite()
+                && stem_unit > 0.0
+                && (maximum - minimum) / stem_unit <= MAXIMUM_STEMS =>
+        {
+            stem_unit
+        }
+        _ => automatic_stem_unit(minimum, maximum),
+    }
+}
+
+/// Choose the power of 10 stem unit, so that the values span at most (about) [`TARGET_STEMS`] stems.
+fn automatic_stem_unit(minimum: f64, maximum: f64) -> f64 {
+    let spread = if maximum > minimum {
+        maximum - minimum
+    } else {
+        // A single value (or none at all) is drawn with its last digit as the leaf.
+        maximum.abs().max(TARGET_STEMS)
+    };
+
+    10f64.powf((spread / TARGET_STEMS).log10().ceil())
+}
+
+/// Split the `value` into its stem and leaf, in units of the `leaf_unit`.
+///
+/// The stems are ordinal, so that the negative stems sort before (and apart from) the positive stems.
+/// Specifically, the stem `-0` (ex: `-0.5`) is `-1`, the stem `-1` is `-2`, and so on.
+fn split(value: f64, leaf_unit: f64) -> (i64, u8) {
+    let scaled = value.abs() / leaf_unit;
+    // Guard against the floating point representation (ex: `0.3 / 0.1 = 2.9999999999999996`).
+    let scaled = if (scaled - scaled.round()).abs() < 1e-9 {
+        scaled.round()
+    } else {
+        scaled.floor()
+    } as i64;
+    let magnitude = scaled / 10;
+    let leaf = (scaled % 10) as u8;
+
+    if value < 0.0 {
+        (-magnitude - 1, leaf)
+    } else {
+        (magnitude, leaf)
+    }
+}
+
+/// Get the label of the ordinal `stem`.
+fn stem_label(stem: i64) -> String {
+    if stem < 0 {
+        format!("-{}", -stem - 1)
+    } else {
+        stem.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stem_unit() {
+        assert_eq!(automatic_stem_unit(0.0, 99.0), 10.0);
+        assert_eq!(automatic_stem_unit(100.0, 1100.0), 100.0);
+        assert_eq!(automatic_stem_unit(1.2, 6.2), 1.0);
+        assert_eq!(automatic_stem_unit(12.0, 12.0), 10.0);
+        assert_eq!(automatic_stem_unit(3.7, 3.7), 1.0);
+    }
+
+    #[test]
+    fn configured_stem_unit() {
+        assert_eq!(choose_stem_unit(Some(1.0), 0.0, 99.0), 1.0);
+        assert_eq!(choose_stem_unit(Some(100.0), 0.0, 99.0), 100.0);
+        assert_eq!(choose_stem_unit(None, 0.0, 99.0), 10.0);
+        assert_eq!(choose_stem_unit(Some(0.0), 0.0, 99.0), 10.0);
+        assert_eq!(choose_stem_unit(Some(-1.0), 0.0, 99.0), 10.0);
+        assert_eq!(choose_stem_unit(Some(f64::NAN), 0.0, 99.0), 10.0);
+        assert_eq!(choose_stem_unit(Some(f64::INFINITY), 0.0, 99.0), 10.0);
+        assert_eq!(choose_stem_unit(Some(1.0), 0.0, 1e12), 1e11);
+    }
+
+    #[test]
+    fn split_values() {
+        assert_eq!(split(123.0, 1.0), (12, 3));
+        assert_eq!(split(7.0, 1.0), (0, 7));
+        assert_eq!(split(0.3, 0.1), (0, 3));
+        assert_eq!(split(4.56, 0.1), (4, 5));
+        assert_eq!(split(-7.0, 1.0), (-1, 7));
+        assert_eq!(split(-12.0, 1.0), (-2, 2));
+    }
+
+    #[test]
+    fn stem_labels() {
+        assert_eq!(stem_label(12), "12");
+        assert_eq!(stem_label(0), "0");
+        assert_eq!(stem_label(-1), "-0");
+        assert_eq!(stem_label(-2), "-1");
+    }
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{DatasetBuilder, Schema1, Schema2, Schemas};
+        use crate::{Render, StemLeaf, StemLeafConfig};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let stemleaf = StemLeaf::new(&view);
+            let flat = stemleaf.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc
+Key: 1 | 2 = 1.2"#
+            );
+        }
+
+        #[test]
+        fn gaps() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((12,))
+                .add((48,))
+                .add((15,))
+                .add((11,))
+                .build();
+            let view = dataset.reflect_1st();
+            let stemleaf = StemLeaf::new(&view);
+            let flat = stemleaf.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc
+1 | 1 2 5
+2 |
+3 |
+4 | 8
+Key: 1 | 2 = 12"#
+            );
+        }
+
+        #[test]
+        fn negatives() {
+            let schema: Schema1<f64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((-1.2,))
+                .add((-0.4,))
+                .add((0.3,))
+                .add((1.5,))
+                .add((1.1,))
+                .build();
+            let view = dataset.reflect_1st();
+            let stemleaf = StemLeaf::new(&view);
+            let flat = stemleaf.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc
+-1 | 2
+-0 | 4
+ 0 | 3
+ 1 | 1 5
+Key: 1 | 2 = 1.2"#
+            );
+        }
+
+        #[test]
+        fn stem_unit() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((123,))
+                .add((127,))
+                .add((131,))
+                .build();
+            let view = dataset.reflect_1st();
+            let stemleaf = StemLeaf::new(&view);
+            let flat = stemleaf.render(Render {
+                widget_config: StemLeafConfig {
+                    stem_unit: Some(100.0),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc
+1 | 2 2 3
+Key: 1 | 2 = 120"#
+            );
+        }
+
+        #[test]
+        fn stem_unit_invalid() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((12,))
+                .add((48,))
+                .add((15,))
+                .build();
+            let view = dataset.reflect_1st();
+            let stemleaf = StemLeaf::new(&view);
+            let flat = stemleaf.render(Render {
+                widget_config: StemLeafConfig {
+                    stem_unit: Some(0.0),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc
+1 | 2 5
+2 |
+3 |
+4 | 8
+Key: 1 | 2 = 12"#
+            );
+        }
+
+        #[test]
+        fn stem_unit_too_many_stems() {
+            let schema: Schema1<f64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((0.0,))
+                .add((4e11,))
+                .add((1e12,))
+                .build();
+            let view = dataset.reflect_1st();
+            let stemleaf = StemLeaf::new(&view);
+            let flat = stemleaf.render(Render {
+                widget_config: StemLeafConfig {
+                    stem_unit: Some(1.0),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc
+ 0 | 0
+ 1 |
+ 2 |
+ 3 |
+ 4 | 0
+ 5 |
+ 6 |
+ 7 |
+ 8 |
+ 9 |
+10 | 0
+Key: 1 | 2 = 1.2e11"#
+            );
+        }
+
+        #[test]
+        fn back_to_back() {
+            let schema: Schema2<i64, &str> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((12, "a"))
+                .add((15, "a"))
+                .add((27, "a"))
+                .add((11, "b"))
+                .add((34, "b"))
+                .add((36, "b"))
+                .build();
+            let view = dataset.count_breakdown_2nd();
+            let stemleaf = StemLeaf::new(&view);
+            let flat = stemleaf.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc
+  a       b
+5 2 | 1 | 1
+  7 | 2 |
+    | 3 | 4 6
+Key: 1 | 2 = 12"#
+            );
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::Alignment;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::Table;
+
+/// Render configuration specific to [`Table`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::{Alignment, TableConfig};
+/// let table_config = TableConfig {
+///     alignments: vec![Alignment::Left, Alignment::Right],
+///     abbreviate: true,
+///     row_limit: Some(10),
+///     ..TableConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct TableConfig {
+    /// The alignment of each column, from left to right.
+    /// Columns without a specified alignment use the default: [`Alignment::Left`] for dimensions, and [`Alignment::Right`] for aggregated values.
+    ///
+    /// Default: `vec![]`.
+    pub alignments: Vec<Alignment>,
+    /// Whether to abbreviate the dimensional values in the rendering or not.
+    /// Use this option when the dimensions have long [`std::fmt::Display`] forms.
+    ///
+    /// The abbreviation is bounded by the column header widths, in the same manner as [`crate::DagChartConfig::abbreviate`].
+    ///
+    /// Default: `false`.
+    pub abbreviate: bool,
+    /// The maximum number of rows to render.
+    /// When rows are omitted, the rendering ends with a line noting how many.
+    ///
+    /// Default: `None`.
+    pub row_limit: Option<usize>,
+}
@@ -0,0 +1,494 @@
+use crate::abbreviate::find_abbreviations;
+use crate::aggregate::minimal_precision_string;
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{Dataset, Dimensions, Headers, Schema, TableConfig, View};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// The table widget.
+///
+/// A table renders data as aligned text, with the dimension names as the column headers.
+/// It may be constructed from either:
+/// * The raw rows of a [`Dataset`], via [`Table::dataset`].
+/// * The aggregated rows of a [`View`], via [`Table::new`].
+///   Each unique tuple of the view's display dimensions forms a row, followed by its aggregate value (one per breakdown value, if any).
+///
+/// ```
+/// use flat::*;
+///
+/// let schema = Schemas::two("Animal", "Size");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("whale".to_string(), "large".to_string()))
+///     .add(("shark".to_string(), "medium".to_string()))
+///     .add(("tiger".to_string(), "medium".to_string()))
+///     .add(("tiger".to_string(), "medium".to_string()))
+///     .add(("tiger".to_string(), "small".to_string()))
+///     .build();
+/// let flat = Table::dataset(&dataset)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Animal  Size
+/// whale   large
+/// shark   medium
+/// tiger   medium
+/// tiger   medium
+/// tiger   small"#);
+///
+/// let view = dataset.count();
+/// let flat = Table::new(&view)
+///     .render(Render::default());
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Animal  Size    Sum(Count)
+/// shark   medium           1
+/// tiger   medium           2
+/// tiger   small            1
+/// whale   large            1"#);
+/// ```
+pub struct Table {
+    headers: Vec<String>,
+    value_headers: Vec<String>,
+    pre_headers: Vec<String>,
+    rows: Vec<TableRow>,
+}
+
+struct TableRow {
+    dimensions: Vec<String>,
+    values: Vec<Vec<f64>>,
+}
+
+impl Table {
+    /// Construct a table widget from the raw rows of the provided dataset.
+    pub fn dataset<S>(dataset: &Dataset<S>) -> Self
+    where
+        S: Schema + Headers,
+        <S as Schema>::Dimensions: Dimensions,
+    {
+        Self {
+            headers: dataset.schema.headers(),
+            value_headers: Vec::default(),
+            pre_headers: Vec::default(),
+            rows: dataset
+                .data()
+                .iter()
+                .map(|dims| TableRow {
+                    dimensions: dims.as_strings(),
+                    values: Vec::default(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Construct a table widget from the aggregated rows of the provided view.
+    pub fn new<S, V>(view: &V) -> Self
+    where
+        S: Schema,
+        V: View<S>,
+        <V as View<S>>::BreakdownDimension: Clone + Display + PartialEq + Eq + Hash + Ord,
+        <V as View<S>>::DisplayDimensions: Clone + PartialEq + Eq + Hash + Ord,
+    {
+        let mut aggregate_values: HashMap<(V::DisplayDimensions, V::BreakdownDimension), Vec<f64>> =
+            HashMap::default();
+        let mut sort_display: Vec<V::DisplayDimensions> = Vec::default();
+        let mut sort_breakdowns: Vec<V::BreakdownDimension> = Vec::default();
+
+        for dims in view.dataset().data() {
+            let display_dims = view.display_dims(dims);
+            let breakdown_dim = view.breakdown_dim(dims);
+
+            if !sort_display.contains(&display_dims) {
+                sort_display.push(display_dims.clone());
+            }
+
+            if !sort_breakdowns.contains(&breakdown_dim) {
+                sort_breakdowns.push(breakdown_dim.clone());
+            }
+
+            let values = aggregate_values
+                .entry((display_dims, breakdown_dim))
+                .or_default();
+            values.push(view.value(dims));
+        }
+
+        sort_display.sort();
+        sort_breakdowns.sort();
+
+        let (value_headers, pre_headers) = match view.breakdown_label() {
+            Some(breakdown_label) => (
+                sort_breakdowns.iter().map(|b| b.to_string()).collect(),
+                vec![breakdown_label, view.value_label()],
+            ),
+            None => (vec![view.value_label()], Vec::default()),
+        };
+        let rows = sort_display
+            .into_iter()
+            .map(|display_dims| {
+                let values = sort_breakdowns
+                    .iter()
+                    .map(|breakdown_dim| {
+                        aggregate_values
+                            .remove(&(display_dims.clone(), breakdown_dim.clone()))
+                            .unwrap_or_default()
+                    })
+                    .collect();
+
+                TableRow {
+                    dimensions: display_dims.as_strings(),
+                    values,
+                }
+            })
+            .collect();
+
+        Self {
+            headers: view.display_headers(),
+            value_headers,
+            pre_headers,
+            rows,
+        }
+    }
+
+    /// Generate the flat rendering for this table.
+    pub fn render(self, config: Render<TableConfig>) -> Flat {
+        let limit = config.widget_config.row_limit.unwrap_or(self.rows.len());
+        let omitted = self.rows.len().saturating_sub(limit);
+        let mut dimension_abbreviations: Vec<HashMap<String, String>> =
+            self.headers.iter().map(|_| HashMap::default()).collect();
+
+        if config.widget_config.abbreviate && !self.rows.is_empty() {
+            let max_header_length = self
+                .headers
+                .iter()
+                .map(|h| h.chars().count())
+                .max()
+                .unwrap();
+
+            for (j, header) in self.headers.iter().enumerate() {
+                let values: HashSet<String> = self
+                    .rows
+                    .iter()
+                    .take(limit)
+                    .map(|row| row.dimensions[j].clone())
+                    .collect();
+                let (_, abbreviations) =
+                    find_abbreviations(header.chars().count(), max_header_length, &values);
+                dimension_abbreviations[j] = abbreviations;
+            }
+        }
+
+        let total_columns = self.headers.len() + self.value_headers.len();
+        let mut columns = Columns::default();
+
+        for j in 0..total_columns {
+            let alignment = match config.widget_config.alignments.get(j) {
+                Some(alignment) => *alignment,
+                None if j < self.headers.len() => Alignment::Left,
+                None => Alignment::Right,
+            };
+            columns.push(Column::string(alignment));
+
+            if j + 1 < total_columns {
+                // spacer "  "
+                columns.push(Column::string(Alignment::Left));
+            }
+        }
+
+        let mut grid = Grid::new(columns);
+
+        for (i, pre_header) in self.pre_headers.iter().enumerate() {
+            let mut row = Row::default();
+
+            for _ in 0..self.headers.len() {
+                row.push(Value::Empty);
+                row.push(Value::Empty);
+            }
+
+            if i + 1 == self.pre_headers.len() {
+                row.push(Value::Plain(format!("{}({pre_header})", config.aggregate)));
+            } else {
+                row.push(Value::Plain(pre_header.clone()));
+            }
+
+            grid.add(row);
+        }
+
+        let mut header = Row::default();
+        let names = self.headers.iter().chain(self.value_headers.iter());
+
+        for (j, name) in names.enumerate() {
+            if j < self.headers.len() || !self.pre_headers.is_empty() {
+                header.push(Value::String(name.clone()));
+            } else {
+                header.push(Value::String(format!("{}({name})", config.aggregate)));
+            }
+
+            if j + 1 < total_columns {
+                header.push(Value::String("  ".to_string()));
+            }
+        }
+
+        grid.add(header);
+
+        for table_row in self.rows.iter().take(limit) {
+            let mut row = Row::default();
+            let dimensions = table_row.dimensions.iter().enumerate().map(|(j, value)| {
+                if config.widget_config.abbreviate {
+                    Value::String(dimension_abbreviations[j][value].clone())
+                } else {
+                    Value::String(value.clone())
+                }
+            });
+            // Breakdown values without any data are left blank.
+            let values = table_row.values.iter().map(|values| {
+                if values.is_empty() {
+                    Value::Empty
+                } else {
+                    Value::String(minimal_precision_string(config.aggregate.apply(values)))
+                }
+            });
+
+            for (j, value) in dimensions.chain(values).enumerate() {
+                row.push(value);
+
+                if j + 1 < total_columns {
+                    row.push(Value::String("  ".to_string()));
+                }
+            }
+
+            grid.add(row);
+        }
+
+        if omitted > 0 {
+            let mut row = Row::default();
+            let noun = if omitted == 1 { "row" } else { "rows" };
+            row.push(Value::Plain(format!("... {omitted} more {noun}")));
+            grid.add(row);
+        }
+
+        Flat::new(config, 0.0..0.0, grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{Aggregate, Alignment, DatasetBuilder, Schema1, Schema2, Schema3, Schemas};
+        use crate::{Render, Table, TableConfig};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let flat = Table::dataset(&dataset).render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc"#
+            );
+        }
+
+        #[test]
+        fn empty_view() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let flat = Table::new(&view).render(Render {
+                widget_config: TableConfig {
+                    abbreviate: true,
+                    ..TableConfig::default()
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  Sum(abc)"#
+            );
+        }
+
+        #[test]
+        fn dataset3() {
+            let schema: Schema3<i64, String, f64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a".to_string(), 0.5))
+                .add((-20, "bcd".to_string(), 10.25))
+                .add((1, "a".to_string(), 0.5))
+                .build();
+            let flat = Table::dataset(&dataset).render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  def  ghi
+1    a    0.5
+-20  bcd  10.25
+1    a    0.5"#
+            );
+        }
+
+        #[test]
+        fn alignments() {
+            let schema: Schema3<i64, String, f64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a".to_string(), 0.5))
+                .add((-20, "bcdef".to_string(), 10.25))
+                .build();
+            let flat = Table::dataset(&dataset).render(Render {
+                widget_config: TableConfig {
+                    alignments: vec![Alignment::Right, Alignment::Center],
+                    ..TableConfig::default()
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc   def   ghi
+  1    a    0.5
+-20  bcdef  10.25"#
+            );
+        }
+
+        #[test]
+        fn abbreviate() {
+            let schema: Schema2<String, String> = Schemas::two("abc", "defghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("alpha".to_string(), "alpha".to_string()))
+                .add(("beta".to_string(), "betamax".to_string()))
+                .build();
+            let flat = Table::dataset(&dataset).render(Render {
+                widget_config: TableConfig {
+                    abbreviate: true,
+                    ..TableConfig::default()
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  defghi
+a..  alpha
+b..  betamax"#
+            );
+        }
+
+        #[test]
+        fn row_limit() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1,))
+                .add((2,))
+                .add((3,))
+                .add((4,))
+                .build();
+            let flat = Table::dataset(&dataset).render(Render {
+                widget_config: TableConfig {
+                    row_limit: Some(2),
+                    ..TableConfig::default()
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc
+1
+2
+... 2 more rows"#
+            );
+        }
+
+        #[test]
+        fn row_limit_singular() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).add((1,)).build();
+            let flat = Table::dataset(&dataset).render(Render {
+                widget_config: TableConfig {
+                    row_limit: Some(0),
+                    ..TableConfig::default()
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc
+... 1 more row"#
+            );
+        }
+
+        #[test]
+        fn view2() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((2, 1))
+                .add((1, 3))
+                .add((2, -4))
+                .build();
+            let view = dataset.view_2nd();
+            let flat = Table::new(&view).render(Render {
+                aggregate: Aggregate::Average,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  Average(def)
+1               3
+2            -1.5"#
+            );
+        }
+
+        #[test]
+        fn view3_breakdown() {
+            let schema: Schema3<i64, String, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, "a".to_string(), 1))
+                .add((1, "b".to_string(), 3))
+                .add((1, "b".to_string(), 5))
+                .add((2, "a".to_string(), 20))
+                .build();
+            let view = dataset.view_3rd_breakdown_2nd();
+            let flat = Table::new(&view).render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+     def
+     Sum(ghi)
+abc   a  b
+1     1  8
+2    20  "#
+            );
+        }
+    }
+
+    #[cfg(feature = "pointer_impls")]
+    mod pointer_impls {
+        use crate::{DatasetBuilder, Schema2, Schemas};
+        use crate::{Render, Table};
+        use ordered_float::OrderedFloat;
+
+        #[test]
+        fn view2() {
+            let schema: Schema2<i64, OrderedFloat<f64>> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, OrderedFloat(0.1)))
+                .add((2, OrderedFloat(0.4)))
+                .add((2, OrderedFloat(0.9)))
+                .build();
+            let view = dataset.view_2nd();
+            let flat = Table::new(&view).render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  Sum(def)
+1         0.1
+2         1.3"#
+            );
+        }
+    }
+}
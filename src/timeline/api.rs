@@ -0,0 +1,22 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::Timeline;
+
+/// Render configuration specific to [`Timeline`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::TimelineConfig;
+/// let timeline_config = TimelineConfig {
+///     abbreviate: true,
+///     ..TimelineConfig::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct TimelineConfig {
+    /// Whether to abbreviate the dimensional values in the frame or not.
+    /// This has the same behaviour as [`crate::DagChartConfig::abbreviate`].
+    ///
+    /// Default: `false`.
+    pub abbreviate: bool,
+}
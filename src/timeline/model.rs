@@ -0,0 +1,383 @@
+use crate::aggregate::minimal_precision_string;
+use crate::canvas::{axis, normalize};
+use crate::dagchart::DagFrame;
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{IntervalView, Schema, TimelineConfig};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::DagChart;
+
+/// The smallest width at which the spans are drawn.
+const MINIMUM_WIDTH: usize = 8;
+
+/// The marker used to draw each span.
+const SPAN: char = '▒';
+
+/// The timeline (gantt) widget.
+///
+/// A timeline draws the intervals of the view for each unique tuple of display dimensions as `▒▒▒` spans.
+/// All the spans share the same horizontal (time) axis, which is labelled with its bounds in the header.
+/// When a tuple of display dimensions has multiple intervals, they are all drawn on its line.
+///
+/// The frame is drawn in the same manner as the [`DagChart`].
+/// For example, each task is drawn on its own line, grouped by the worker (primary dimension) that ran it.
+/// Use `show_aggregate` to display the aggregate of the interval durations for each primary dimension value.
+/// The timeline is sized to the `width_hint` of the [`Render`] configuration.
+///
+/// See also: [`DagChart`]
+///
+/// ```
+/// use flat::*;
+///
+/// let schema: Schema4<&str, u32, u32, &str> = Schemas::four("Task", "Start", "End", "Worker");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("fetch", 0, 4, "w1"))
+///     .add(("build", 4, 14, "w1"))
+///     .add(("lint", 2, 6, "w2"))
+///     .add(("test", 14, 20, "w2"))
+///     .build();
+/// let view = dataset.interval_2nd_3rd();
+/// let flat = Timeline::new(&view)
+///     .render(Render {
+///         width_hint: 40,
+///         ..Render::default()
+///     });
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Task     Worker  |0   (Start, End)    20
+/// build  - w1      |    ▒▒▒▒▒▒▒▒▒▒▒▒
+/// fetch  ┘         |▒▒▒▒▒
+/// lint   - w2      |  ▒▒▒▒▒
+/// test   ┘         |               ▒▒▒▒▒▒▒"#);
+/// ```
+pub struct Timeline<'a, S, V>
+where
+    S: Schema,
+    V: IntervalView<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> Timeline<'a, S, V>
+where
+    S: Schema,
+    V: IntervalView<S>,
+    <V as IntervalView<S>>::PrimaryDimension: Clone + PartialEq + Eq + Hash,
+    <V as IntervalView<S>>::DisplayDimensions: Clone + PartialEq + Eq + Hash + Ord,
+{
+    /// Construct a timeline widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this timeline.
+    pub fn render(self, config: Render<TimelineConfig>) -> Flat {
+        let mut display_intervals: HashMap<V::DisplayDimensions, Vec<(f64, f64)>> =
+            HashMap::default();
+        let mut primary_durations: HashMap<V::PrimaryDimension, Vec<f64>> = HashMap::default();
+        let mut lookup: HashMap<V::DisplayDimensions, V::PrimaryDimension> = HashMap::default();
+        let mut frame = DagFrame::new(
+            self.view.display_headers(),
+            config.widget_config.abbreviate,
+            false,
+        );
+        let mut minimum_value = f64::MAX;
+        let mut maximum_value = f64::MIN;
+
+        for dims in self.view.dataset().data() {
+            let (start, end) = self.view.interval(dims);
+            let primary_dim = self.view.primary_dim(dims);
+            let display_dims = self.view.display_dims(dims);
+            frame.observe(&display_dims, end - start);
+
+            let durations = primary_durations.entry(primary_dim.clone()).or_default();
+            durations.push(end - start);
+
+            let intervals = display_intervals.entry(display_dims.clone()).or_default();
+            intervals.push((start, end));
+
+            lookup.entry(display_dims).or_insert(primary_dim);
+
+            minimum_value = minimum_value.min(start.min(end));
+            maximum_value = maximum_value.max(start.max(end));
+        }
+
+        if display_intervals.is_empty() {
+            minimum_value = 0.0;
+            maximum_value = 0.0;
+        }
+
+        let aggregates: HashMap<&V::PrimaryDimension, f64> = primary_durations
+            .iter()
+            .map(|(primary_dim, durations)| {
+                (primary_dim, config.aggregate.apply(durations.as_slice()))
+            })
+            .collect();
+        let mut frame_width = frame.width(&config.aggregate) + 3;
+
+        if config.show_aggregate {
+            let aggregate_width = aggregates
+                .values()
+                .map(|aggregate| minimal_precision_string(*aggregate).len())
+                .max()
+                .unwrap_or(0);
+            frame_width += std::cmp::max(aggregate_width, config.aggregate.to_string().len()) + 3;
+        }
+
+        let width = std::cmp::max(config.width_hint.saturating_sub(frame_width), MINIMUM_WIDTH);
+        let mut columns = Columns::default();
+        frame.columns(&mut columns);
+        let frame_columns = columns.len();
+
+        if config.show_aggregate {
+            // spacer " "
+            columns.push(Column::string(Alignment::Center));
+            // total left [
+            columns.push(Column::string(Alignment::Left));
+            // total value
+            columns.push(Column::string(Alignment::Right));
+            // total right ]
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // rendering delimiter |
+        columns.push(Column::string(Alignment::Center));
+        // spans
+        columns.push(Column::string(Alignment::Left));
+
+        let mut grid = Grid::new(columns);
+        let mut header = Row::default();
+        frame.header(&mut header, &config.aggregate);
+
+        if config.show_aggregate {
+            header.push(Value::Empty);
+            header.push(Value::Overflow(config.aggregate.to_string()));
+            header.push(Value::Skip);
+            header.push(Value::Skip);
+        }
+
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("|".to_string()));
+        header.push(Value::String(axis(
+            &self.view.interval_label(),
+            minimum_value,
+            maximum_value,
+            width,
+        )));
+        grid.add(header);
+
+        for frame_row in frame.rows(&config.aggregate) {
+            let mut row = Row::default();
+            let frame_cells = frame_row.cells.len();
+
+            for value in frame_row.cells.into_iter() {
+                row.push(value);
+            }
+
+            // Lines below the primary dimension are drawn with fewer frame cells.
+            for _ in frame_cells..frame_columns {
+                row.push(Value::Empty);
+            }
+
+            // The aggregate is shown on the line where the primary dimension is drawn.
+            if config.show_aggregate {
+                if frame_row.label.is_some() {
+                    let primary_dim = lookup
+                        .get(&frame_row.display_dims)
+                        .expect("display dimensions must be mapped to the primary dimension");
+                    row.push(Value::String(" ".to_string()));
+                    row.push_aggregate(minimal_precision_string(aggregates[primary_dim]));
+                } else {
+                    row.push(Value::Empty);
+                    row.push(Value::Empty);
+                    row.push(Value::Empty);
+                    row.push(Value::Empty);
+                }
+            }
+
+            // Each line draws the spans of its own display dimensions.
+            row.push(Value::String("  ".to_string()));
+            row.push(Value::String("|".to_string()));
+            row.push(Value::String(draw(
+                &display_intervals[&frame_row.display_dims],
+                minimum_value,
+                maximum_value,
+                width,
+            )));
+            grid.add(row);
+        }
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+/// Draw the `intervals` onto an axis of `width` characters spanning `minimum..maximum`.
+fn draw(intervals: &[(f64, f64)], minimum: f64, maximum: f64, width: usize) -> String {
+    let position = |value: f64| -> usize {
+        (normalize(value, minimum, maximum) * (width - 1) as f64).round() as usize
+    };
+    let mut line = vec![' '; width];
+
+    for (start, end) in intervals {
+        let (start, end) = (position(start.min(*end)), position(start.max(*end)));
+
+        for c in line.iter_mut().take(end + 1).skip(start) {
+            *c = SPAN;
+        }
+    }
+
+    line.into_iter().collect::<String>().trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_spans() {
+        assert_eq!(draw(&[(0.0, 10.0)], 0.0, 10.0, 11), "▒▒▒▒▒▒▒▒▒▒▒");
+        assert_eq!(draw(&[(2.0, 4.0)], 0.0, 10.0, 11), "  ▒▒▒");
+        assert_eq!(draw(&[(4.0, 2.0)], 0.0, 10.0, 11), "  ▒▒▒");
+        assert_eq!(draw(&[(0.0, 1.0), (8.0, 9.0)], 0.0, 10.0, 11), "▒▒      ▒▒");
+    }
+
+    #[test]
+    fn draw_degenerate() {
+        assert_eq!(draw(&[(3.0, 3.0)], 3.0, 3.0, 9), "    ▒");
+        assert_eq!(draw(&[], 0.0, 10.0, 11), "");
+    }
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{Aggregate, Render, Timeline, TimelineConfig};
+        use crate::{DatasetBuilder, Schema3, Schema4, Schemas};
+
+        #[test]
+        fn empty() {
+            let schema: Schema3<i64, i64, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.interval_2nd_3rd();
+            let timeline = Timeline::new(&view);
+            let flat = timeline.render(Render {
+                width_hint: 20,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |0 (def, ghi) 0"#
+            );
+        }
+
+        #[test]
+        fn intervals() {
+            let schema: Schema3<i64, i64, i64> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 0, 5))
+                .add((2, 5, 10))
+                .add((3, 2, 3))
+                .add((3, 7, 9))
+                .build();
+            let view = dataset.interval_2nd_3rd();
+            let timeline = Timeline::new(&view);
+            let flat = timeline.render(Render {
+                width_hint: 20,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc  |0           10
+1    |▒▒▒▒▒▒▒▒
+2    |       ▒▒▒▒▒▒▒
+3    |   ▒▒    ▒▒▒▒"#
+            );
+        }
+
+        #[test]
+        fn grouped() {
+            let schema: Schema4<i64, i64, i64, &str> = Schemas::four("abc", "def", "ghi", "jkl");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 0, 5, "a"))
+                .add((2, 5, 10, "a"))
+                .add((3, 2, 3, "b"))
+                .build();
+            let view = dataset.interval_2nd_3rd();
+            let timeline = Timeline::new(&view);
+            let flat = timeline.render(Render {
+                width_hint: 30,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    jkl  |0  (def, ghi)  10
+1    - a    |▒▒▒▒▒▒▒▒▒
+2    ┘      |        ▒▒▒▒▒▒▒▒▒
+3    - b    |   ▒▒▒"#
+            );
+        }
+
+        #[test]
+        fn grouped_adjacent_overlapping() {
+            let schema: Schema4<&str, i64, i64, &str> = Schemas::four("abc", "def", "ghi", "jkl");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("x", 0, 4, "a"))
+                .add(("y", 4, 8, "a"))
+                .add(("z", 2, 6, "a"))
+                .add(("w", 6, 10, "b"))
+                .build();
+            let view = dataset.interval_2nd_3rd();
+            let timeline = Timeline::new(&view);
+            let flat = timeline.render(Render {
+                width_hint: 30,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    jkl  |0  (def, ghi)  10
+x    ┐      |▒▒▒▒▒▒▒
+y    - a    |      ▒▒▒▒▒▒▒▒
+z    ┘      |   ▒▒▒▒▒▒▒▒
+w    - b    |          ▒▒▒▒▒▒▒"#
+            );
+        }
+
+        #[test]
+        fn show_aggregate() {
+            let schema: Schema4<i64, i64, i64, &str> = Schemas::four("abc", "def", "ghi", "jkl");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 0, 5, "a"))
+                .add((1, 6, 8, "a"))
+                .add((2, 5, 10, "a"))
+                .build();
+            let view = dataset.interval_2nd_3rd();
+            let timeline = Timeline::new(&view);
+            let flat = timeline.render(Render {
+                aggregate: Aggregate::Sum,
+                width_hint: 30,
+                show_aggregate: true,
+                widget_config: TimelineConfig { abbreviate: true },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    jkl Sum   |0        10
+1    - a   [12]  |▒▒▒▒▒▒▒▒▒
+2    ┘           |     ▒▒▒▒▒▒"#
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Dimensions, Nothing, Schema, Schema1, Schema2, Schema3, Schema4};
+use crate::{Coordinate, Dimensions, Nothing, Schema, Schema1, Schema2, Schema3, Schema4};
 use std::fmt::Display;
 // We use this in the doc strings.
 #[allow(unused_imports)]
@@ -98,6 +98,95 @@ pub trait View<S: Schema> {
     fn display_headers(&self) -> Vec<String>;
 }
 
+/// Trait which defines how to render a [`Dataset`] of intervals across different [`Schema`]s.
+/// Unlike the [`View`], which extracts a single value, an interval view extracts a (start, end) pair from each input vector.
+/// Consumers may choose to implement this trait to provide custom interval views over datasets.
+pub trait IntervalView<S: Schema> {
+    /// The primary dimension - see [`IntervalView::primary_dim`] for more details.
+    type PrimaryDimension;
+    /// The display dimension(s) - see [`IntervalView::display_dims`] for more details.
+    /// The first must always be the `PrimaryDimension` for the `IntervalView`.
+    type DisplayDimensions: Dimensions;
+
+    /// Get the data associated with this view.
+    fn dataset(&self) -> &Dataset<S>;
+
+    /// Extract the (start, end) interval for this view from the input vector.
+    /// ```text
+    /// Dimensions.. | Rendering
+    /// primary_A    |   ▒▒▒▒▒▒
+    ///                  ^    ^
+    ///              START    END
+    /// ```
+    fn interval(&self, dims: &S::Dimensions) -> (f64, f64);
+
+    /// Get the label for the intervals in this view.
+    fn interval_label(&self) -> String;
+
+    /// Extract the primary dimension for this view from the input vector.
+    /// This has the same meaning as [`View::primary_dim`].
+    fn primary_dim(&self, dims: &S::Dimensions) -> Self::PrimaryDimension;
+
+    /// Extract the display dimensions for this view from the input vector.
+    /// This has the same meaning as [`View::display_dims`].
+    ///
+    /// The first display dimension must always be the primary dimension.
+    /// The length of the display dimensions must match the length of [`IntervalView::display_headers`].
+    fn display_dims(&self, dims: &S::Dimensions) -> Self::DisplayDimensions;
+
+    /// Get the dimensional headers for this view.
+    /// This has the same meaning as [`View::display_headers`].
+    ///
+    /// The length of the headers must match the length of [`IntervalView::display_dims`].
+    fn display_headers(&self) -> Vec<String>;
+}
+
+/// Trait which defines how to render a [`Dataset`] of bullets across different [`Schema`]s.
+/// Alongside its [`View`], a bullet view extracts the target and the band thresholds from each input vector, so that each row may be measured against its own.
+/// Consumers may choose to implement this trait to provide custom bullet views over datasets.
+pub trait BulletView<S: Schema> {
+    /// Extract the target for this view from the input vector, if any.
+    /// ```text
+    /// Dimensions.. | Rendering
+    /// primary_A    | ████▒▒▒|░░
+    ///                       ^
+    ///                  TARGET
+    /// ```
+    fn target(&self, dims: &S::Dimensions) -> Option<f64>;
+
+    /// Extract the band thresholds for this view from the input vector, if any.
+    /// ```text
+    /// Dimensions.. | Rendering
+    /// primary_A    | ████▒▒▒|░░
+    ///                    ^  ^
+    ///                  BANDS..
+    /// ```
+    fn bands(&self, dims: &S::Dimensions) -> Option<Vec<f64>>;
+}
+
+/// Trait which defines how to render a [`Dataset`] of several numeric variables across different [`Schema`]s.
+/// Unlike the [`View`], which extracts a single value, a matrix view extracts a value for each of its variables from each input vector.
+/// Consumers may choose to implement this trait to provide custom matrix views over datasets.
+pub trait MatrixView<S: Schema> {
+    /// Get the data associated with this view.
+    fn dataset(&self) -> &Dataset<S>;
+
+    /// Extract the value of each variable for this view from the input vector.
+    /// ```text
+    ///           | VARIABLE_A  VARIABLE_B  ..
+    /// VARIABLE_A|
+    /// VARIABLE_B|
+    /// ```
+    ///
+    /// The length of the variables must match the length of [`MatrixView::variable_headers`].
+    fn variables(&self, dims: &S::Dimensions) -> Vec<f64>;
+
+    /// Get the headers for the variables in this view.
+    ///
+    /// The length of the headers must match the length of [`MatrixView::variables`].
+    fn variable_headers(&self) -> Vec<String>;
+}
+
 #[doc(hidden)]
 pub struct View1Full<'a, S: Schema> {
     pub(crate) dataset: &'a Dataset<S>,
@@ -996,12 +1085,383 @@ where
 // pub struct View6<T, U, V, W, X, Y>;
 // pub struct View7<T, U, V, W, X, Y, Z>;
 
+#[doc(hidden)]
+pub struct View3Interval<'a, S: Schema> {
+    pub(crate) dataset: &'a Dataset<S>,
+}
+
+impl<'a, T, U, V> IntervalView<Schema3<T, U, V>> for View3Interval<'a, Schema3<T, U, V>>
+where
+    T: Clone + Display,
+    U: Coordinate,
+    V: Coordinate,
+{
+    type PrimaryDimension = T;
+    type DisplayDimensions = (T,);
+
+    fn dataset(&self) -> &Dataset<Schema3<T, U, V>> {
+        self.dataset
+    }
+
+    fn interval(&self, dims: &<Schema3<T, U, V> as Schema>::Dimensions) -> (f64, f64) {
+        (dims.1.coordinate(), dims.2.coordinate())
+    }
+
+    fn interval_label(&self) -> String {
+        format!(
+            "({}, {})",
+            self.dataset.schema.dimension_1, self.dataset.schema.dimension_2
+        )
+    }
+
+    fn primary_dim(
+        &self,
+        dims: &<Schema3<T, U, V> as Schema>::Dimensions,
+    ) -> Self::PrimaryDimension {
+        dims.0.clone()
+    }
+
+    fn display_dims(
+        &self,
+        dims: &<Schema3<T, U, V> as Schema>::Dimensions,
+    ) -> Self::DisplayDimensions {
+        (dims.0.clone(),)
+    }
+
+    fn display_headers(&self) -> Vec<String> {
+        vec![self.dataset.schema.dimension_0.clone()]
+    }
+}
+
+#[doc(hidden)]
+pub struct View4Interval<'a, S: Schema> {
+    pub(crate) dataset: &'a Dataset<S>,
+}
+
+impl<'a, T, U, V, W> IntervalView<Schema4<T, U, V, W>> for View4Interval<'a, Schema4<T, U, V, W>>
+where
+    T: Clone + Display,
+    U: Coordinate,
+    V: Coordinate,
+    W: Clone + Display,
+{
+    type PrimaryDimension = W;
+    type DisplayDimensions = (W, T);
+
+    fn dataset(&self) -> &Dataset<Schema4<T, U, V, W>> {
+        self.dataset
+    }
+
+    fn interval(&self, dims: &<Schema4<T, U, V, W> as Schema>::Dimensions) -> (f64, f64) {
+        (dims.1.coordinate(), dims.2.coordinate())
+    }
+
+    fn interval_label(&self) -> String {
+        format!(
+            "({}, {})",
+            self.dataset.schema.dimension_1, self.dataset.schema.dimension_2
+        )
+    }
+
+    fn primary_dim(
+        &self,
+        dims: &<Schema4<T, U, V, W> as Schema>::Dimensions,
+    ) -> Self::PrimaryDimension {
+        dims.3.clone()
+    }
+
+    fn display_dims(
+        &self,
+        dims: &<Schema4<T, U, V, W> as Schema>::Dimensions,
+    ) -> Self::DisplayDimensions {
+        (dims.3.clone(), dims.0.clone())
+    }
+
+    fn display_headers(&self) -> Vec<String> {
+        vec![
+            self.dataset.schema.dimension_3.clone(),
+            self.dataset.schema.dimension_0.clone(),
+        ]
+    }
+}
+
+#[doc(hidden)]
+pub struct View3Matrix<'a, S: Schema> {
+    pub(crate) dataset: &'a Dataset<S>,
+}
+
+impl<'a, T, U, V> MatrixView<Schema3<T, U, V>> for View3Matrix<'a, Schema3<T, U, V>>
+where
+    T: Coordinate,
+    U: Coordinate,
+    V: Coordinate,
+{
+    fn dataset(&self) -> &Dataset<Schema3<T, U, V>> {
+        self.dataset
+    }
+
+    fn variables(&self, dims: &<Schema3<T, U, V> as Schema>::Dimensions) -> Vec<f64> {
+        vec![
+            dims.0.coordinate(),
+            dims.1.coordinate(),
+            dims.2.coordinate(),
+        ]
+    }
+
+    fn variable_headers(&self) -> Vec<String> {
+        vec![
+            self.dataset.schema.dimension_0.clone(),
+            self.dataset.schema.dimension_1.clone(),
+            self.dataset.schema.dimension_2.clone(),
+        ]
+    }
+}
+
+#[doc(hidden)]
+pub struct View4Matrix<'a, S: Schema> {
+    pub(crate) dataset: &'a Dataset<S>,
+}
+
+impl<'a, T, U, V, W> MatrixView<Schema4<T, U, V, W>> for View4Matrix<'a, Schema4<T, U, V, W>>
+where
+    T: Coordinate,
+    U: Coordinate,
+    V: Coordinate,
+    W: Coordinate,
+{
+    fn dataset(&self) -> &Dataset<Schema4<T, U, V, W>> {
+        self.dataset
+    }
+
+    fn variables(&self, dims: &<Schema4<T, U, V, W> as Schema>::Dimensions) -> Vec<f64> {
+        vec![
+            dims.0.coordinate(),
+            dims.1.coordinate(),
+            dims.2.coordinate(),
+            dims.3.coordinate(),
+        ]
+    }
+
+    fn variable_headers(&self) -> Vec<String> {
+        vec![
+            self.dataset.schema.dimension_0.clone(),
+            self.dataset.schema.dimension_1.clone(),
+            self.dataset.schema.dimension_2.clone(),
+            self.dataset.schema.dimension_3.clone(),
+        ]
+    }
+}
+
+#[doc(hidden)]
+pub struct View3Matrix2nd3rd<'a, S: Schema> {
+    pub(crate) dataset: &'a Dataset<S>,
+}
+
+impl<'a, T, U, V> MatrixView<Schema3<T, U, V>> for View3Matrix2nd3rd<'a, Schema3<T, U, V>>
+where
+    U: Coordinate,
+    V: Coordinate,
+{
+    fn dataset(&self) -> &Dataset<Schema3<T, U, V>> {
+        self.dataset
+    }
+
+    fn variables(&self, dims: &<Schema3<T, U, V> as Schema>::Dimensions) -> Vec<f64> {
+        vec![dims.1.coordinate(), dims.2.coordinate()]
+    }
+
+    fn variable_headers(&self) -> Vec<String> {
+        vec![
+            self.dataset.schema.dimension_1.clone(),
+            self.dataset.schema.dimension_2.clone(),
+        ]
+    }
+}
+
+#[doc(hidden)]
+pub struct View4Matrix2nd3rd4th<'a, S: Schema> {
+    pub(crate) dataset: &'a Dataset<S>,
+}
+
+impl<'a, T, U, V, W> MatrixView<Schema4<T, U, V, W>>
+    for View4Matrix2nd3rd4th<'a, Schema4<T, U, V, W>>
+where
+    U: Coordinate,
+    V: Coordinate,
+    W: Coordinate,
+{
+    fn dataset(&self) -> &Dataset<Schema4<T, U, V, W>> {
+        self.dataset
+    }
+
+    fn variables(&self, dims: &<Schema4<T, U, V, W> as Schema>::Dimensions) -> Vec<f64> {
+        vec![
+            dims.1.coordinate(),
+            dims.2.coordinate(),
+            dims.3.coordinate(),
+        ]
+    }
+
+    fn variable_headers(&self) -> Vec<String> {
+        vec![
+            self.dataset.schema.dimension_1.clone(),
+            self.dataset.schema.dimension_2.clone(),
+            self.dataset.schema.dimension_3.clone(),
+        ]
+    }
+}
+
+#[doc(hidden)]
+pub struct View3Bullet<'a, S: Schema> {
+    pub(crate) dataset: &'a Dataset<S>,
+}
+
+impl<'a, T, U, V> View<Schema3<T, U, V>> for View3Bullet<'a, Schema3<T, U, V>>
+where
+    T: Clone + Display,
+    U: Coordinate,
+    V: Coordinate,
+{
+    type PrimaryDimension = T;
+    type BreakdownDimension = Nothing;
+    type DisplayDimensions = (T,);
+
+    fn dataset(&self) -> &Dataset<Schema3<T, U, V>> {
+        self.dataset
+    }
+
+    fn value(&self, dims: &<Schema3<T, U, V> as Schema>::Dimensions) -> f64 {
+        dims.1.coordinate()
+    }
+
+    fn value_label(&self) -> String {
+        self.dataset.schema.dimension_1.clone()
+    }
+
+    fn primary_dim(
+        &self,
+        dims: &<Schema3<T, U, V> as Schema>::Dimensions,
+    ) -> Self::PrimaryDimension {
+        dims.0.clone()
+    }
+
+    fn breakdown_dim(
+        &self,
+        _dims: &<Schema3<T, U, V> as Schema>::Dimensions,
+    ) -> Self::BreakdownDimension {
+        Nothing
+    }
+
+    fn breakdown_label(&self) -> Option<String> {
+        None
+    }
+
+    fn display_dims(
+        &self,
+        dims: &<Schema3<T, U, V> as Schema>::Dimensions,
+    ) -> Self::DisplayDimensions {
+        (dims.0.clone(),)
+    }
+
+    fn display_headers(&self) -> Vec<String> {
+        vec![self.dataset.schema.dimension_0.clone()]
+    }
+}
+
+impl<'a, T, U, V> BulletView<Schema3<T, U, V>> for View3Bullet<'a, Schema3<T, U, V>>
+where
+    V: Coordinate,
+{
+    fn target(&self, dims: &<Schema3<T, U, V> as Schema>::Dimensions) -> Option<f64> {
+        Some(dims.2.coordinate())
+    }
+
+    fn bands(&self, _dims: &<Schema3<T, U, V> as Schema>::Dimensions) -> Option<Vec<f64>> {
+        None
+    }
+}
+
+#[doc(hidden)]
+pub struct View4Bullet<'a, S: Schema> {
+    pub(crate) dataset: &'a Dataset<S>,
+}
+
+impl<'a, T, U, V, W> View<Schema4<T, U, V, W>> for View4Bullet<'a, Schema4<T, U, V, W>>
+where
+    T: Clone + Display,
+    U: Coordinate,
+    V: Coordinate,
+    W: AsRef<[f64]>,
+{
+    type PrimaryDimension = T;
+    type BreakdownDimension = Nothing;
+    type DisplayDimensions = (T,);
+
+    fn dataset(&self) -> &Dataset<Schema4<T, U, V, W>> {
+        self.dataset
+    }
+
+    fn value(&self, dims: &<Schema4<T, U, V, W> as Schema>::Dimensions) -> f64 {
+        dims.1.coordinate()
+    }
+
+    fn value_label(&self) -> String {
+        self.dataset.schema.dimension_1.clone()
+    }
+
+    fn primary_dim(
+        &self,
+        dims: &<Schema4<T, U, V, W> as Schema>::Dimensions,
+    ) -> Self::PrimaryDimension {
+        dims.0.clone()
+    }
+
+    fn breakdown_dim(
+        &self,
+        _dims: &<Schema4<T, U, V, W> as Schema>::Dimensions,
+    ) -> Self::BreakdownDimension {
+        Nothing
+    }
+
+    fn breakdown_label(&self) -> Option<String> {
+        None
+    }
+
+    fn display_dims(
+        &self,
+        dims: &<Schema4<T, U, V, W> as Schema>::Dimensions,
+    ) -> Self::DisplayDimensions {
+        (dims.0.clone(),)
+    }
+
+    fn display_headers(&self) -> Vec<String> {
+        vec![self.dataset.schema.dimension_0.clone()]
+    }
+}
+
+impl<'a, T, U, V, W> BulletView<Schema4<T, U, V, W>> for View4Bullet<'a, Schema4<T, U, V, W>>
+where
+    V: Coordinate,
+    W: AsRef<[f64]>,
+{
+    fn target(&self, dims: &<Schema4<T, U, V, W> as Schema>::Dimensions) -> Option<f64> {
+        Some(dims.2.coordinate())
+    }
+
+    fn bands(&self, dims: &<Schema4<T, U, V, W> as Schema>::Dimensions) -> Option<Vec<f64>> {
+        Some(dims.3.as_ref().to_vec())
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     #[cfg(feature = "primitive_impls")]
     mod primitive_impls {
-        use crate::{DatasetBuilder, Nothing, Schema1, Schema2, Schema3, Schema4, Schemas, View};
+        use crate::{
+            BulletView, DatasetBuilder, IntervalView, MatrixView, Nothing, Schema1, Schema2,
+            Schema3, Schema4, Schemas, View,
+        };
 
         #[test]
         fn view1_reflective() {
@@ -1346,5 +1806,131 @@ mod tests {
             assert_eq!(view.value_label(), "Count".to_string());
             assert_eq!(view.breakdown_label(), Some("jkl".to_string()));
         }
+
+        #[test]
+        fn view3_interval() {
+            let schema: Schema3<&str, f32, i8> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema).add(("a", 0.5, 3)).build();
+            let view = dataset.interval_2nd_3rd();
+            assert_eq!(view.interval(&("b", 0.5, 3)), (0.5, 3.0));
+            assert_eq!(view.interval_label(), "(def, ghi)".to_string());
+            assert_eq!(view.primary_dim(&("b", 0.5, 3)), "b");
+            assert_eq!(view.display_dims(&("b", 0.5, 3)), ("b",));
+            assert_eq!(view.display_headers(), vec!["abc".to_string()]);
+        }
+
+        #[test]
+        fn view4_interval() {
+            let schema: Schema4<&str, f32, i8, u64> = Schemas::four("abc", "def", "ghi", "jkl");
+            let dataset = DatasetBuilder::new(schema).add(("a", 0.5, 3, 1)).build();
+            let view = dataset.interval_2nd_3rd();
+            assert_eq!(view.interval(&("b", 0.5, 3, 2)), (0.5, 3.0));
+            assert_eq!(view.interval_label(), "(def, ghi)".to_string());
+            assert_eq!(view.primary_dim(&("b", 0.5, 3, 2)), 2);
+            assert_eq!(view.display_dims(&("b", 0.5, 3, 2)), (2, "b"));
+            assert_eq!(
+                view.display_headers(),
+                vec!["jkl".to_string(), "abc".to_string()]
+            );
+        }
+
+        #[test]
+        fn view3_matrix() {
+            let schema: Schema3<u64, f32, i8> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema).add((1, 0.5, -3)).build();
+            let view = dataset.matrix();
+            assert_eq!(view.variables(&(2, 0.5, -3)), vec![2.0, 0.5, -3.0]);
+            assert_eq!(
+                view.variable_headers(),
+                vec!["abc".to_string(), "def".to_string(), "ghi".to_string()]
+            );
+        }
+
+        #[test]
+        fn view4_matrix() {
+            let schema: Schema4<u64, f32, i8, f64> = Schemas::four("abc", "def", "ghi", "jkl");
+            let dataset = DatasetBuilder::new(schema).add((1, 0.5, -3, 0.25)).build();
+            let view = dataset.matrix();
+            assert_eq!(
+                view.variables(&(2, 0.5, -3, 0.25)),
+                vec![2.0, 0.5, -3.0, 0.25]
+            );
+            assert_eq!(
+                view.variable_headers(),
+                vec![
+                    "abc".to_string(),
+                    "def".to_string(),
+                    "ghi".to_string(),
+                    "jkl".to_string()
+                ]
+            );
+        }
+
+        #[test]
+        fn view3_matrix_2nd_3rd() {
+            let schema: Schema3<&str, f32, i8> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema).add(("a", 0.5, -3)).build();
+            let view = dataset.matrix_2nd_3rd();
+            assert_eq!(view.variables(&("b", 0.5, -3)), vec![0.5, -3.0]);
+            assert_eq!(
+                view.variable_headers(),
+                vec!["def".to_string(), "ghi".to_string()]
+            );
+        }
+
+        #[test]
+        fn view4_matrix_2nd_3rd_4th() {
+            let schema: Schema4<&str, f32, i8, f64> = Schemas::four("abc", "def", "ghi", "jkl");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 0.5, -3, 0.25))
+                .build();
+            let view = dataset.matrix_2nd_3rd_4th();
+            assert_eq!(view.variables(&("b", 0.5, -3, 0.25)), vec![0.5, -3.0, 0.25]);
+            assert_eq!(
+                view.variable_headers(),
+                vec!["def".to_string(), "ghi".to_string(), "jkl".to_string()]
+            );
+        }
+
+        #[test]
+        fn view3_bullet() {
+            let schema: Schema3<&str, f32, i8> = Schemas::three("abc", "def", "ghi");
+            let dataset = DatasetBuilder::new(schema).add(("a", 0.5, 3)).build();
+            let view = dataset.bullet_2nd_3rd();
+            assert_eq!(view.primary_dim(&("b", 0.5, 3)), "b");
+            assert_eq!(view.breakdown_dim(&("b", 0.5, 3)), Nothing);
+            assert_eq!(view.display_dims(&("b", 0.5, 3)), ("b",));
+            assert_eq!(view.display_headers(), vec!["abc".to_string()]);
+            assert_eq!(view.value(&("b", 0.5, 3)), 0.5);
+            assert_eq!(view.value_label(), "def".to_string());
+            assert_eq!(view.breakdown_label(), None);
+            assert_eq!(view.target(&("b", 0.5, 3)), Some(3.0));
+            assert_eq!(view.bands(&("b", 0.5, 3)), None);
+        }
+
+        #[test]
+        fn view4_bullet() {
+            let schema: Schema4<&str, f32, i8, Vec<f64>> =
+                Schemas::four("abc", "def", "ghi", "jkl");
+            let dataset = DatasetBuilder::new(schema)
+                .add(("a", 0.5, 3, vec![0.25, 0.75]))
+                .build();
+            let view = dataset.bullet_2nd_3rd_4th();
+            assert_eq!(view.primary_dim(&("b", 0.5, 3, vec![0.25, 0.75])), "b");
+            assert_eq!(
+                view.breakdown_dim(&("b", 0.5, 3, vec![0.25, 0.75])),
+                Nothing
+            );
+            assert_eq!(view.display_dims(&("b", 0.5, 3, vec![0.25, 0.75])), ("b",));
+            assert_eq!(view.display_headers(), vec!["abc".to_string()]);
+            assert_eq!(view.value(&("b", 0.5, 3, vec![0.25, 0.75])), 0.5);
+            assert_eq!(view.value_label(), "def".to_string());
+            assert_eq!(view.breakdown_label(), None);
+            assert_eq!(view.target(&("b", 0.5, 3, vec![0.25, 0.75])), Some(3.0));
+            assert_eq!(
+                view.bands(&("b", 0.5, 3, vec![0.25, 0.75])),
+                Some(vec![0.25, 0.75])
+            );
+        }
     }
 }
@@ -0,0 +1,29 @@
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use super::Waterfall;
+
+/// Render configuration specific to [`Waterfall`]s.
+///
+/// ### Example
+/// ```
+/// # use flat::WaterfallConfig;
+/// let waterfall_config = WaterfallConfig {
+///     total_label: "Net".to_string(),
+///     ..WaterfallConfig::default()
+/// };
+/// ```
+#[derive(Debug)]
+pub struct WaterfallConfig {
+    /// The label of the final row, which shows the total of all the contributions.
+    ///
+    /// Default: `"Total"`.
+    pub total_label: String,
+}
+
+impl Default for WaterfallConfig {
+    fn default() -> Self {
+        Self {
+            total_label: "Total".to_string(),
+        }
+    }
+}
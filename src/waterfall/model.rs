@@ -0,0 +1,329 @@
+use crate::aggregate::{aggregate_apply, minimal_precision_string};
+use crate::render::{Alignment, Column, Columns, Flat, Grid, Render, Row, Value};
+use crate::{Schema, View, WaterfallConfig};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+// We use this in the doc strings.
+#[allow(unused_imports)]
+use crate::BarChart;
+
+/// The waterfall widget.
+///
+/// A waterfall represents each unique value of the view's primary dimension (1st) as a contribution to a running total.
+/// Each contribution is drawn as a bar floating from where the previous running total ended.
+/// The final row draws the total of all the contributions.
+///
+/// Unlike the [`BarChart`], the primary dimension values are kept in the order they first appear in the dataset.
+/// The breakdown dimension, if any, is folded into the contribution of each primary dimension value.
+/// Use `show_aggregate` to display the running total at each row.
+///
+/// Negative running totals are clipped at zero, unless the rendering is `diverging`.
+///
+/// See also: [`BarChart`]
+///
+/// ```
+/// use flat::*;
+///
+/// # #[cfg(feature = "primitive_impls")]
+/// # {
+/// let schema: Schema2<String, i32> = Schemas::two("Change", "Cost");
+/// let dataset = DatasetBuilder::new(schema)
+///     .add(("baseline".to_string(), 10))
+///     .add(("caching".to_string(), -4))
+///     .add(("logging".to_string(), 2))
+///     .add(("retries".to_string(), 3))
+///     .build();
+/// let view = dataset.view_2nd();
+/// let flat = Waterfall::new(&view)
+///     .render(Render {
+///         show_aggregate: true,
+///         ..Render::default()
+///     });
+/// assert_eq!(
+///     format!("\n{}", flat),
+///     r#"
+/// Change   Running  |Sum(Cost)
+/// baseline [10]     |**********
+/// caching  [ 6]     |      ⊖⊖⊖⊖
+/// logging  [ 8]     |      **
+/// retries  [11]     |        ***
+/// Total    [11]     |***********"#);
+/// # }
+/// ```
+pub struct Waterfall<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+{
+    view: &'a V,
+    _phantom: PhantomData<S>,
+}
+
+impl<'a, S, V> Waterfall<'a, S, V>
+where
+    S: Schema,
+    V: View<S>,
+    <V as View<S>>::PrimaryDimension: Clone + Display + PartialEq + Eq + Hash,
+{
+    /// Construct a waterfall widget from the provided view.
+    pub fn new(view: &'a V) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Generate the flat rendering for this waterfall.
+    pub fn render(self, config: Render<WaterfallConfig>) -> Flat {
+        let mut aggregate_values: HashMap<V::PrimaryDimension, Vec<f64>> = HashMap::default();
+        let mut order_primaries: Vec<V::PrimaryDimension> = Vec::default();
+
+        for dims in self.view.dataset().data() {
+            let value = self.view.value(dims);
+            let primary_dim = self.view.primary_dim(dims);
+            let values = aggregate_values.entry(primary_dim.clone()).or_default();
+            values.push(value);
+
+            if !order_primaries.contains(&primary_dim) {
+                order_primaries.push(primary_dim);
+            }
+        }
+
+        let mut columns = Columns::default();
+        // primary value
+        columns.push(Column::string(Alignment::Left));
+
+        if config.show_aggregate {
+            // spacer " "
+            columns.push(Column::string(Alignment::Center));
+            // running left [
+            columns.push(Column::string(Alignment::Left));
+            // running value
+            columns.push(Column::string(Alignment::Right));
+            // running right ]
+            columns.push(Column::string(Alignment::Left));
+        }
+
+        // spacer "  "
+        columns.push(Column::string(Alignment::Center));
+        // rendering delimiter |
+        columns.push(Column::string(Alignment::Center));
+        // floating contribution
+        columns.push(Column::count(Alignment::Left));
+
+        let mut grid = Grid::new(columns);
+        let mut header = Row::default();
+        header.label(self.view.display_headers()[0].clone());
+        header.push(Value::String(self.view.display_headers()[0].clone()));
+
+        if config.show_aggregate {
+            header.push(Value::Empty);
+            header.push(Value::Overflow("Running".to_string()));
+            header.push(Value::Skip);
+            header.push(Value::Skip);
+        }
+
+        header.push(Value::String("  ".to_string()));
+        header.push(Value::String("|".to_string()));
+        header.push(Value::Plain(format!(
+            "{}({})",
+            config.aggregate,
+            self.view.value_label()
+        )));
+        grid.add(header);
+
+        // The contributions themselves don't bound the rendering - the running totals do.
+        let mut minimum_contribution = f64::MAX;
+        let mut maximum_contribution = f64::MIN;
+        let mut minimum_value: f64 = 0.0;
+        let mut maximum_value: f64 = 0.0;
+        let mut running = 0.0;
+
+        for primary_dim in order_primaries.iter() {
+            let contribution = aggregate_apply(
+                &config.aggregate,
+                &aggregate_values,
+                primary_dim,
+                &mut minimum_contribution,
+                &mut maximum_contribution,
+            );
+            let previous = running;
+            running += contribution;
+            minimum_value = minimum_value.min(running);
+            maximum_value = maximum_value.max(running);
+            grid.add(build_row(
+                &config,
+                primary_dim.to_string(),
+                previous,
+                running,
+            ));
+        }
+
+        grid.add(build_row(
+            &config,
+            config.widget_config.total_label.clone(),
+            0.0,
+            running,
+        ));
+
+        Flat::new(config, minimum_value..maximum_value, grid)
+    }
+}
+
+fn build_row(config: &Render<WaterfallConfig>, label: String, from: f64, to: f64) -> Row {
+    let mut row = Row::default();
+    row.label(label.clone());
+    row.push(Value::String(label));
+
+    if config.show_aggregate {
+        row.push(Value::String(" ".to_string()));
+        row.push_aggregate(minimal_precision_string(to));
+    }
+
+    row.push(Value::String("  ".to_string()));
+    row.push(Value::String("|".to_string()));
+    row.push(Value::Float(from, to));
+    row
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "primitive_impls")]
+    mod primitive_impls {
+        use crate::{Aggregate, Render, Waterfall, WaterfallConfig};
+        use crate::{DatasetBuilder, Schema1, Schema2, Schemas};
+
+        #[test]
+        fn empty() {
+            let schema: Schema1<i64> = Schemas::one("abc");
+            let dataset = DatasetBuilder::new(schema).build();
+            let view = dataset.reflect_1st();
+            let waterfall = Waterfall::new(&view);
+            let flat = waterfall.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    |Sum(abc)
+Total  |"#
+            );
+        }
+
+        #[test]
+        fn ordered() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((3, 2))
+                .add((1, 4))
+                .add((2, -3))
+                .add((1, 1))
+                .build();
+            let view = dataset.view_2nd();
+            let waterfall = Waterfall::new(&view);
+            let flat = waterfall.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    |Sum(def)
+3      |**
+1      |  *****
+2      |    ⊖⊖⊖
+Total  |****"#
+            );
+        }
+
+        #[test]
+        fn show_aggregate() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 4))
+                .add((1, 2))
+                .add((2, -1))
+                .add((3, 3))
+                .build();
+            let view = dataset.view_2nd();
+            let waterfall = Waterfall::new(&view);
+            let flat = waterfall.render(Render {
+                aggregate: Aggregate::Average,
+                show_aggregate: true,
+                widget_config: WaterfallConfig {
+                    total_label: "net".to_string(),
+                },
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc Running  |Average(def)
+1   [3]      |***
+2   [2]      |  ⊖
+3   [5]      |  ***
+net [5]      |*****"#
+            );
+        }
+
+        #[test]
+        fn negative_running() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 2))
+                .add((2, -5))
+                .add((3, 1))
+                .build();
+            let view = dataset.view_2nd();
+            let waterfall = Waterfall::new(&view);
+            let flat = waterfall.render(Render::default());
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    |Sum(def)
+1      |**
+2      |⊖⊖
+3      |
+Total  |"#
+            );
+
+            let waterfall = Waterfall::new(&view);
+            let flat = waterfall.render(Render {
+                diverging: true,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    |Sum(def)
+1      |   │**
+2      |⊖⊖⊖│⊖⊖
+3      |*  │
+Total  | ⊖⊖│"#
+            );
+        }
+
+        #[test]
+        fn scaled() {
+            let schema: Schema2<i64, i64> = Schemas::two("abc", "def");
+            let dataset = DatasetBuilder::new(schema)
+                .add((1, 100))
+                .add((2, 50))
+                .add((3, -30))
+                .build();
+            let view = dataset.view_2nd();
+            let waterfall = Waterfall::new(&view);
+            let flat = waterfall.render(Render {
+                width_hint: 20,
+                ..Render::default()
+            });
+            assert_eq!(
+                format!("\n{}", flat),
+                r#"
+abc    |Sum(def)
+1      |********
+2      |        ****
+3      |         ⊖⊖⊖
+Total  |*********"#
+            );
+        }
+    }
+}
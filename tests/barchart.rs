@@ -0,0 +1,139 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<String, bool, u32>> {
+        let schema = Schemas::three("animal", "stable", "length");
+        DatasetBuilder::new(schema)
+            .add(("whale".to_string(), true, 4u32))
+            .add(("shark".to_string(), false, 4u32))
+            .add(("shark".to_string(), false, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .build()
+    }
+
+    #[test]
+    fn barchart_3d() {
+        let dataset = dataset_3d();
+        let view = dataset.count();
+        let flat = BarChart::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal  |Sum(Count)
+shark   |*****
+tiger   |***********
+whale   |*"#
+        );
+    }
+
+    #[test]
+    fn barchart_3d_view() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = BarChart::new(&view).render(Render {
+            aggregate: Aggregate::Average,
+            show_aggregate: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal Average  |Average(length)
+shark  [1.6]    |**
+tiger  [3.7]    |****
+whale  [  4]    |****"#
+        );
+    }
+
+    #[test]
+    fn barchart_3d_breakdown() {
+        let dataset = dataset_3d();
+        let view = dataset.breakdown_3rd();
+        let flat = BarChart::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+         Sum(length)
+animal  |              1                              4                              5               |
+shark   |             ****                           ****                                            |
+tiger   |             ***                          ********            ******************************|
+whale   |                                            ****                                            |"#
+        );
+    }
+
+    #[test]
+    fn barchart_3d_breakdown_view() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = BarChart::new(&view).render(Render {
+            show_aggregate: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+              stable
+              Sum(length)
+animal Sum   |            false                           true             |
+shark  [ 8]  |            *****                           ***              |
+tiger  [41]  |         ***********           ******************************|
+whale  [ 4]  |                                            ****             |"#
+        );
+    }
+
+    #[test]
+    fn barchart_3d_breakdown_stacked() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = BarChart::new(&view).render(Render {
+            show_aggregate: true,
+            stack_breakdown: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+              stable
+              Sum(length)
+animal Sum   |* false  o true
+shark  [ 8]  |*****ooo
+tiger  [41]  |***********oooooooooooooooooooooooooooooo
+whale  [ 4]  |oooo"#
+        );
+    }
+
+    #[test]
+    fn barchart_3d_breakdown_pyramid() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = BarChart::new(&view).render(Render {
+            show_aggregate: true,
+            widget_config: BarChartConfig { pyramid: true },
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+stable
+Sum(length)
+                         false| animal Sum  |true
+                         *****| shark  [ 8] |***
+                   ***********| tiger  [41] |******************************
+                              | whale  [ 4] |****"#
+        );
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<String, bool, u32>> {
+        let schema = Schemas::three("animal", "stable", "length");
+        DatasetBuilder::new(schema)
+            .add(("whale".to_string(), true, 4u32))
+            .add(("shark".to_string(), false, 4u32))
+            .add(("shark".to_string(), false, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .build()
+    }
+
+    #[test]
+    fn boxplot_3d() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = BoxPlot::new(&view).render(Render {
+            width_hint: 40,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+stable    animal  |1      length       5
+false   - shark   ||──────────────┤
+true    ┘
+false   - tiger   |├───────[###########|
+true    ┘
+true    - whale   |               |"#
+        );
+    }
+
+    #[test]
+    fn boxplot_3d_breakdown() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = BoxPlot::new(&view).render(Render {
+            width_hint: 40,
+            show_aggregate: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal Sum   |1        length         5
+shark  [ 8]  ||─────────────────┤
+tiger  [41]  |├────────[##############|
+whale  [ 4]  |                  |"#
+        );
+    }
+
+    #[test]
+    fn boxplot_3d_narrow() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = BoxPlot::new(&view).render(Render {
+            width_hint: 10,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+stable    animal  |1      5
+false   - shark   ||────┤
+true    ┘
+false   - tiger   |├──[###|
+true    ┘
+true    - whale   |     |"#
+        );
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<String, bool, u32>> {
+        let schema = Schemas::three("animal", "stable", "length");
+        DatasetBuilder::new(schema)
+            .add(("whale".to_string(), true, 4u32))
+            .add(("shark".to_string(), false, 4u32))
+            .add(("shark".to_string(), false, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .build()
+    }
+
+    #[test]
+    fn bulletchart_3d() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = BulletChart::new(&view).render(Render {
+            width_hint: 40,
+            widget_config: BulletChartConfig {
+                target: Some(30.0),
+                bands: vec![10.0, 20.0],
+                ..BulletChartConfig::default()
+            },
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal  |0           length           41
+shark   |███████▓▒▒▒▒▒▒▒░░░░░░░|░░░░░░░░
+tiger   |██████████████████████|████████
+whale   |████▓▓▓▓▒▒▒▒▒▒▒░░░░░░░|░░░░░░░░"#
+        );
+    }
+
+    #[test]
+    fn bulletchart_3d_breakdown() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = BulletChart::new(&view).render(Render {
+            width_hint: 40,
+            aggregate: Aggregate::Average,
+            show_aggregate: true,
+            widget_config: BulletChartConfig {
+                minimum: Some(1.0),
+                target: Some(4.0),
+                bands: vec![2.0, 3.0],
+            },
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal Average  |1      length       4
+shark  [1.6]    |█████▓▓▒▒▒▒▒▒▒░░░░░░|
+tiger  [3.7]    |███████████████████░|
+whale  [  4]    |████████████████████|"#
+        );
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<YearMonthDay, String, u32>> {
+        let schema = Schemas::three("date", "author", "commits");
+        let mut builder = DatasetBuilder::new(schema);
+
+        for day in 1..=30 {
+            builder.update((YearMonthDay::new(2024, 4, day), "ana".to_string(), day % 4));
+            builder.update((YearMonthDay::new(2024, 5, day), "ana".to_string(), day % 7));
+        }
+
+        for day in [3, 10, 17, 24] {
+            builder.update((YearMonthDay::new(2024, 5, day), "bo".to_string(), 9));
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn calendarheatmap_3d() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = CalendarHeatmap::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Sum(commits)
+    Apr     May
+Mon ░░  ░░░░░░▒▒▒▒▒▒▒▒
+Tue ░░░░  ░░░░
+Wed ░░░░░░  ░░░░░░░░░░
+Thu   ░░░░░░░░░░░░░░░░
+Fri ░░  ░░░░████████
+Sat ░░░░  ░░▒▒▒▒▒▒▒▒
+Sun ░░░░░░  ▒▒▒▒▒▒▒▒"#
+        );
+    }
+
+    #[test]
+    fn calendarheatmap_3d_breakdown() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = CalendarHeatmap::new(&view).render(Render {
+            aggregate: Aggregate::Max,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Max(commits)
+    Apr     May
+Mon ░░  ▒▒░░░░▓▓▓▓▓▓▓▓
+Tue ░░░░  ▒▒░░
+Wed ▒▒░░░░  ░░░░░░░░░░
+Thu   ▒▒░░░░░░░░░░░░░░
+Fri ░░  ▒▒░░████████
+Sat ░░░░  ▒▒▒▒▒▒▒▒▒▒
+Sun ▒▒░░░░  ▓▓▓▓▓▓▓▓"#
+        );
+    }
+
+    #[test]
+    fn calendarheatmap_days_since_epoch() {
+        let schema: Schema1<u32> = Schemas::one("day");
+        let mut builder = DatasetBuilder::new(schema);
+
+        for day in 19_800..19_900 {
+            for _ in 0..(day % 9) {
+                builder.update((day,));
+            }
+        }
+
+        let dataset = builder.build();
+        let view = dataset.count();
+        let flat = CalendarHeatmap::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Sum(Count)
+    Mar Apr     May     Jun
+Mon   ██▓▓▒▒░░██▓▓▒▒░░  ██▓▓▒▒░░██
+Tue ░░██▓▓▒▒░░  ██▓▓▒▒░░██▓▓▒▒░░
+Wed ░░  ██▓▓▒▒░░██▓▓▒▒░░  ██▓▓▒▒
+Thu ▒▒░░██▓▓▒▒░░  ██▓▓▒▒░░██▓▓▒▒
+Fri ▒▒░░  ██▓▓▒▒░░██▓▓▒▒░░  ██▓▓
+Sat ▓▓▒▒░░██▓▓▒▒░░  ██▓▓▒▒░░██▓▓
+Sun ▓▓▒▒░░  ██▓▓▒▒░░██▓▓▒▒░░  ██"#
+        );
+    }
+}
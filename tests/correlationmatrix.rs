@@ -0,0 +1,56 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_4d() -> Dataset<Schema4<f64, f64, f64, f64>> {
+        let schema = Schemas::four("sepal_length", "sepal_width", "petal_length", "petal_width");
+        DatasetBuilder::new(schema)
+            .add((5.1, 3.5, 1.4, 0.2))
+            .add((4.9, 3.0, 1.4, 0.2))
+            .add((5.0, 3.6, 1.4, 0.2))
+            .add((7.0, 3.2, 4.7, 1.4))
+            .add((6.4, 3.2, 4.5, 1.5))
+            .add((5.5, 2.3, 4.0, 1.3))
+            .add((6.3, 3.3, 6.0, 2.5))
+            .add((5.8, 2.7, 5.1, 1.9))
+            .add((7.1, 3.0, 5.9, 2.1))
+            .build()
+    }
+
+    #[test]
+    fn correlationmatrix_4d() {
+        let dataset = dataset_4d();
+        let view = dataset.matrix();
+        let flat = CorrelationMatrix::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Pearson       sepal_length     sepal_width     petal_length     petal_width
+sepal_length          1.00 ██
+sepal_width          -0.07 ░░         1.00 ██
+petal_length          0.85 ██        -0.36 ▒▒          1.00 ██
+petal_width           0.77 ██        -0.33 ▒▒          0.99 ██         1.00 ██"#
+        );
+    }
+
+    #[test]
+    fn correlationmatrix_4d_spearman() {
+        let dataset = dataset_4d();
+        let view = dataset.matrix();
+        let flat = CorrelationMatrix::new(&view).render(Render {
+            widget_config: CorrelationMatrixConfig {
+                correlation: Correlation::Spearman,
+            },
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Spearman      sepal_length     sepal_width     petal_length     petal_width
+sepal_length          1.00 ██
+sepal_width          -0.16 ░░         1.00 ██
+petal_length          0.80 ██        -0.25 ░░          1.00 ██
+petal_width           0.78 ██        -0.25 ░░          0.98 ██         1.00 ██"#
+        );
+    }
+}
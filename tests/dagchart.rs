@@ -686,6 +686,27 @@ true    - whale   |                                            ****
         );
     }
 
+    #[test]
+    fn dagchart_3d_breakdown_stacked() {
+        let dataset = dataset_3d();
+        let view = dataset.breakdown_3rd();
+        let flat = DagChart::new(&view).render(Render {
+            stack_breakdown: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+                   Sum(length)
+stable    animal  |* 1  o 4  + 5
+false   - shark   |****oooo
+true    ┘
+false   - tiger   |***oooooooo++++++++++++++++++++++++++++++
+true    ┘
+true    - whale   |oooo"#
+        );
+    }
+
     #[test]
     fn dagchart_3d_breakdown_view() {
         let dataset = dataset_3d();
@@ -789,4 +810,52 @@ whalewhale..  - tige..  |*
 whalewhale..  - whal..  |*"#
         );
     }
+
+    fn dataset_profit() -> Dataset<Schema3<String, String, i32>> {
+        let schema = Schemas::three("region", "quarter", "profit");
+        DatasetBuilder::new(schema)
+            .add(("north".to_string(), "q1".to_string(), 4))
+            .add(("north".to_string(), "q2".to_string(), -3))
+            .add(("south".to_string(), "q1".to_string(), -5))
+            .add(("south".to_string(), "q2".to_string(), 2))
+            .build()
+    }
+
+    #[test]
+    fn dagchart_3d_diverging() {
+        let dataset = dataset_profit();
+        let view = dataset.view_3rd();
+        let flat = DagChart::new(&view).render(Render {
+            diverging: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+quarter    region  |Sum(profit)
+q1       - north   |   │*
+q2       ┘
+q1       - south   |⊖⊖⊖│
+q2       ┘"#
+        );
+    }
+
+    #[test]
+    fn dagchart_3d_diverging_breakdown() {
+        let dataset = dataset_profit();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = DagChart::new(&view).render(Render {
+            diverging: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+         quarter
+         Sum(profit)
+region  |    q1         q2    |
+north   |     │****   ⊖⊖⊖│    |
+south   |⊖⊖⊖⊖⊖│          │**  |"#
+        );
+    }
 }
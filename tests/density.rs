@@ -0,0 +1,77 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_2d() -> Dataset<Schema2<f64, String>> {
+        let schema = Schemas::two("length", "species");
+        DatasetBuilder::new(schema)
+            .add((4.3, "setosa".to_string()))
+            .add((4.9, "setosa".to_string()))
+            .add((5.0, "setosa".to_string()))
+            .add((5.1, "setosa".to_string()))
+            .add((5.4, "setosa".to_string()))
+            .add((5.7, "versicolor".to_string()))
+            .add((6.0, "versicolor".to_string()))
+            .add((6.4, "versicolor".to_string()))
+            .add((6.9, "versicolor".to_string()))
+            .add((7.0, "versicolor".to_string()))
+            .add((6.3, "virginica".to_string()))
+            .add((7.7, "virginica".to_string()))
+            .build()
+    }
+
+    #[test]
+    fn density_2d() {
+        let dataset = dataset_2d();
+        let view = dataset.count();
+        let flat = Density::new(&view).render(Render {
+            width_hint: 30,
+            height_hint: 10,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Density(length)
+0.3 │         ⡠⠊⠉⠉⠒⠤⡀
+    │        ⡜      ⠈⠢⡀
+    │       ⡜         ⠈⢆
+    │      ⡜            ⠣⡀
+    │    ⢀⠎              ⠈⢆
+  0 │⣀⣀⠤⠔⠁                 ⠑⠢⢄⣀⣀
+    └───────────────────────────
+     2.6                     9.4
+     length"#
+        );
+    }
+
+    #[test]
+    fn density_2d_breakdown() {
+        let dataset = dataset_2d();
+        let view = dataset.count_breakdown_2nd();
+        let flat = Density::new(&view).render(Render {
+            width_hint: 40,
+            height_hint: 12,
+            widget_config: DensityConfig {
+                bandwidth: Some(0.5),
+            },
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Density(length)
+0.6 │           ****
+    │          **  **   oooo
+    │         *     **oo+++ooo +++
+    │        *      oo*+  ++++++  ++
+    │      **      oo++*       oo  ++
+    │    ***     oo+++ **       ooo  ++
+  0 │+++++++++++++++     *********ooooo+++
+    └─────────────────────────────────────
+     2.8                               9.2
+     length
+     species: * setosa  o versicolor  + virginica"#
+        );
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+    use std::fmt::{Display, Formatter};
+    use std::ops::{Add, Sub};
+
+    #[derive(Clone, Debug, PartialEq, PartialOrd)]
+    struct Millis(u32);
+
+    impl Display for Millis {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}ms", self.0)
+        }
+    }
+
+    impl Add for Millis {
+        type Output = Millis;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Millis(self.0 + rhs.0)
+        }
+    }
+
+    impl Sub for Millis {
+        type Output = Millis;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            Millis(self.0 - rhs.0)
+        }
+    }
+
+    impl Binnable for Millis {
+        fn multiply(&self, rhs: usize) -> Self {
+            Millis(self.0 * rhs as u32)
+        }
+
+        fn divide(&self, rhs: usize) -> Self {
+            Millis(self.0.div_ceil(rhs as u32))
+        }
+    }
+
+    fn dataset_2d() -> Dataset<Schema2<f64, String>> {
+        let schema = Schemas::two("length", "species");
+        DatasetBuilder::new(schema)
+            .add((4.3, "setosa".to_string()))
+            .add((4.9, "setosa".to_string()))
+            .add((5.0, "setosa".to_string()))
+            .add((5.1, "setosa".to_string()))
+            .add((5.4, "setosa".to_string()))
+            .add((5.7, "versicolor".to_string()))
+            .add((6.0, "versicolor".to_string()))
+            .add((6.4, "versicolor".to_string()))
+            .add((6.9, "versicolor".to_string()))
+            .add((7.0, "versicolor".to_string()))
+            .add((6.3, "virginica".to_string()))
+            .add((7.7, "virginica".to_string()))
+            .build()
+    }
+
+    #[test]
+    fn ecdf_2d() {
+        let dataset = dataset_2d();
+        let view = dataset.count();
+        let flat = Ecdf::new(&view).render(Render {
+            width_hint: 30,
+            height_hint: 10,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Fraction(≤ length)
+1 │                     ⡤⠤⠤⠤⠤⠼
+  │                ⢠⠤⠤⠤⠼⠁
+  │             ⠠⠤⠼⠉
+  │      ⣀⣀⣰⠒⠒⠋⠉⠉
+  │    ⢀⣰⠃
+0 │⡖⠒⠒⠒⠚
+  └───────────────────────────
+   4.3                     7.7
+   length"#
+        );
+    }
+
+    #[test]
+    fn ecdf_2d_breakdown() {
+        let dataset = dataset_2d();
+        let view = dataset.count_breakdown_2nd();
+        let flat = Ecdf::new(&view).render(Render {
+            width_hint: 30,
+            height_hint: 10,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Fraction(≤ length)
+1 │        *            o    +
+  │      ***           oo    +
+  │    ***      oo++++++++++++
+  │*****      ooo +
+0 │*          o   +
+  └───────────────────────────
+   4.3                     7.7
+   length
+   species: * setosa  o versicolor  + virginica"#
+        );
+    }
+
+    #[test]
+    fn ecdf_2d_quantiles() {
+        let dataset = dataset_2d();
+        let view = dataset.count_breakdown_2nd();
+        let flat = Ecdf::new(&view).render(Render {
+            widget_config: EcdfConfig {
+                quantiles: Some(vec![0.25, 0.5, 0.75]),
+            },
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+          species
+Quantile  setosa  versicolor  virginica
+p25          4.9           6        6.3
+p50            5         6.4        6.3
+p75          5.1         6.9        7.7"#
+        );
+    }
+
+    #[test]
+    fn ecdf_binnable() {
+        let schema: Schema1<Millis> = Schemas::one("latency");
+        let dataset = DatasetBuilder::new(schema)
+            .add((Millis(10),))
+            .add((Millis(12),))
+            .add((Millis(15),))
+            .add((Millis(40),))
+            .build();
+        let view = dataset.count();
+        let flat = Ecdf::new(&view).render(Render {
+            width_hint: 20,
+            height_hint: 8,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Fraction(≤ latency)
+1 │                ⢸
+  │   ⠏⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉
+  │⣀⡏⠉⠁
+0 │⡇
+  └─────────────────
+   10ms         40ms
+   latency"#
+        );
+    }
+}
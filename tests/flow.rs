@@ -0,0 +1,64 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<String, bool, u32>> {
+        let schema = Schemas::three("animal", "stable", "length");
+        DatasetBuilder::new(schema)
+            .add(("whale".to_string(), true, 4u32))
+            .add(("shark".to_string(), false, 4u32))
+            .add(("shark".to_string(), false, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .build()
+    }
+
+    #[test]
+    fn flow_3d() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = Flow::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal Sum      stable Sum   length  |Sum(length)
+shark  [ 8] ─┬→ false  [16]       5  |*****
+             └→ true   [37]       3  |***
+tiger  [41] ─┬→ false  [16]      11  |***********
+             └→ true   [37]      30  |******************************
+whale  [ 4] ──→ true   [37]       4  |****"#
+        );
+    }
+
+    #[test]
+    fn flow_3d_average() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = Flow::new(&view).render(Render {
+            aggregate: Aggregate::Average,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal Average     stable Average  length  |Average(length)
+shark  [1.6]   ─┬→ false  [2.3]       2.5  |***
+                └→ true   [3.7]         1  |*
+tiger  [3.7]   ─┬→ false  [2.3]       2.2  |**
+                └→ true   [3.7]         5  |*****
+whale  [  4]   ──→ true   [3.7]         4  |****"#
+        );
+    }
+}
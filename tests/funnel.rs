@@ -0,0 +1,76 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<String, bool, u32>> {
+        let schema = Schemas::three("animal", "stable", "length");
+        DatasetBuilder::new(schema)
+            .add(("whale".to_string(), true, 4u32))
+            .add(("shark".to_string(), false, 4u32))
+            .add(("shark".to_string(), false, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .build()
+    }
+
+    #[test]
+    fn funnel_3d() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = Funnel::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal  Previous   First  |Sum(length)
+shark     100.0%  100.0%  |                ********
+tiger     512.5%  512.5%  |*****************************************
+whale       9.8%   50.0%  |                  ****"#
+        );
+    }
+
+    #[test]
+    fn funnel_3d_show_aggregate() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = Funnel::new(&view).render(Render {
+            aggregate: Aggregate::Average,
+            show_aggregate: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal Average  Previous   First  |Average(length)
+shark  [1.6]      100.0%  100.0%  | **
+tiger  [3.7]      233.0%  233.0%  |****
+whale  [  4]      107.3%  250.0%  |****"#
+        );
+    }
+
+    #[test]
+    fn funnel_3d_breakdown() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = Funnel::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal  Previous   First  |Sum(length)
+shark     100.0%  100.0%  |                ********
+tiger     512.5%  512.5%  |*****************************************
+whale       9.8%   50.0%  |                  ****"#
+        );
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<String, bool, u32>> {
+        let schema = Schemas::three("animal", "stable", "length");
+        DatasetBuilder::new(schema)
+            .add(("whale".to_string(), true, 4u32))
+            .add(("shark".to_string(), false, 4u32))
+            .add(("shark".to_string(), false, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .build()
+    }
+
+    #[test]
+    fn heatmap_3d_breakdown() {
+        let dataset = dataset_3d();
+        let view = dataset.breakdown_3rd();
+        let flat = Heatmap::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+         Sum(length)
+animal  |1 4 5|
+shark   |░ ░  |
+tiger   |░ ▒ █|
+whale   |  ░  |"#
+        );
+    }
+
+    #[test]
+    fn heatmap_3d_breakdown_view() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = Heatmap::new(&view).render(Render {
+            show_aggregate: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+              stable
+              Sum(length)
+animal Sum   |false true |
+shark  [ 8]  |░░░░░ ░░░░░|
+tiger  [41]  |▒▒▒▒▒ █████|
+whale  [ 4]  |      ░░░░░|"#
+        );
+    }
+}
@@ -250,4 +250,63 @@ length                    |  kipp     orville    ralf   |
 [7.4, 9]                  |          ********  *********|"#
         );
     }
+
+    #[test]
+    fn histogram_count_breakdown_stacked() {
+        let pets = ["ralf", "kipp", "orville"];
+        let schema = Schemas::two("length", "pet");
+        let mut builder = DatasetBuilder::new(schema);
+
+        for i in 0..10 {
+            for _ in 0..i {
+                builder.update(((i % 10) as f64, pets[i % 3]));
+            }
+        }
+
+        let dataset = builder.build();
+        let view = dataset.count_breakdown_2nd();
+        let flat = Histogram::new(&view, 5).render(Render {
+            width_hint: 40,
+            stack_breakdown: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+                           pet
+                           Sum(Count)
+length                    |* kipp  o orville  + ralf
+[1, 2.6)                  |oo
+[2.6, 4.2)                |***++
+[4.2, 5.800000000000001)  |ooo
+[5.800000000000001, 7.4)  |*****++++
+[7.4, 9]                  |oooooo+++++++"#
+        );
+    }
+
+    #[test]
+    fn histogram_diverging() {
+        let schema: Schema2<i64, i64> = Schemas::two("day", "delta");
+        let dataset = DatasetBuilder::new(schema)
+            .add((1, -4))
+            .add((2, -2))
+            .add((3, 1))
+            .add((4, 3))
+            .add((5, 6))
+            .add((6, 2))
+            .build();
+        let view = dataset.view_2nd();
+        let flat = Histogram::new(&view, 3).render(Render {
+            diverging: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+day     |Sum(delta)
+[1, 3)  |⊖⊖⊖⊖⊖⊖│
+[3, 5)  |      │****
+[5, 7]  |      │********"#
+        );
+    }
 }
@@ -0,0 +1,56 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<f64, f64, u32>> {
+        let schema = Schemas::three("length", "width", "petals");
+        DatasetBuilder::new(schema)
+            .add((5.1, 3.5, 5))
+            .add((4.9, 3.0, 5))
+            .add((4.7, 3.2, 5))
+            .add((6.4, 3.2, 6))
+            .add((6.9, 3.1, 6))
+            .add((5.5, 2.3, 4))
+            .add((6.5, 2.8, 5))
+            .add((7.1, 3.0, 6))
+            .add((6.3, 2.9, 6))
+            .add((5.8, 2.7, 4))
+            .build()
+    }
+
+    #[test]
+    fn histogram2d_count() {
+        let dataset = dataset_3d();
+        let view = dataset.count_breakdown_3rd();
+        let flat = Histogram2d::new(&view, 4, 3).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+         length
+         Sum(Count)
+petals  |[4.7, 5.3) [5.3, 5.9) [5.9, 6.5) [6.5, 7.1]|
+[4, 5)  |           ▓▓▓▓▓▓▓▓▓▓                      |
+[5, 6)  |██████████                       ▒▒▒▒▒▒▒▒▒▒|
+[6, 7]  |                      ▓▓▓▓▓▓▓▓▓▓ ▓▓▓▓▓▓▓▓▓▓|"#
+        );
+    }
+
+    #[test]
+    fn histogram2d_value() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = Histogram2d::new(&view, 2, 2).render(Render {
+            aggregate: Aggregate::Max,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+             length
+             Max(petals)
+width       |[4.7, 5.9) [5.9, 7.1]|
+[2.3, 2.9)  |▓▓▓▓▓▓▓▓▓▓ ██████████|
+[2.9, 3.5]  |██████████ ██████████|"#
+        );
+    }
+}
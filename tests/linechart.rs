@@ -0,0 +1,70 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<u32, String, f64>> {
+        let schema = Schemas::three("day", "city", "rainfall");
+        DatasetBuilder::new(schema)
+            .add((1, "paris".to_string(), 2.0))
+            .add((1, "paris".to_string(), 1.0))
+            .add((2, "paris".to_string(), 4.0))
+            .add((3, "paris".to_string(), 6.5))
+            .add((4, "paris".to_string(), 1.0))
+            .add((1, "tokyo".to_string(), 0.5))
+            .add((2, "tokyo".to_string(), 0.0))
+            .add((3, "tokyo".to_string(), 2.0))
+            .add((4, "tokyo".to_string(), 5.0))
+            .build()
+    }
+
+    #[test]
+    fn linechart_3d_view() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = LineChart::new(&view).render(Render {
+            width_hint: 30,
+            height_hint: 10,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Sum(rainfall)
+8.5 │               ⡠⠊⠒⢄
+    │             ⢀⠔⠁   ⠉⠢⢄
+    │            ⡠⠃        ⠑⠢⡀
+    │          ⢀⠜            ⠈
+    │         ⡰⠁
+3.5 │⣀⣀⠤⠤⠤⠔⠒⠒⠊
+    └─────────────────────────
+     1                       4
+     day"#
+        );
+    }
+
+    #[test]
+    fn linechart_3d_breakdown_view() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = LineChart::new(&view).render(Render {
+            aggregate: Aggregate::Average,
+            width_hint: 30,
+            height_hint: 10,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+Average(rainfall)
+6.5 │              ****
+    │         *****    **  ooo
+    │    *****         oooo
+    │****        oooooo    ***
+  0 │oooooooooooo
+    └─────────────────────────
+     1                       4
+     day
+     city: * paris  o tokyo"#
+        );
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<String, bool, u32>> {
+        let schema = Schemas::three("animal", "stable", "length");
+        DatasetBuilder::new(schema)
+            .add(("whale".to_string(), true, 4u32))
+            .add(("shark".to_string(), false, 4u32))
+            .add(("shark".to_string(), false, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .build()
+    }
+
+    #[test]
+    fn pareto_3d_count() {
+        let dataset = dataset_3d();
+        let view = dataset.count();
+        let flat = Pareto::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+         Sum(Count)
+animal  |             Cumulative
+tiger   |***********       64.7%
+shark   |*****             94.1%
+whale   |*                100.0%"#
+        );
+    }
+
+    #[test]
+    fn pareto_3d_breakdown() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = Pareto::new(&view).render(Render {
+            show_aggregate: true,
+            widget_config: ParetoConfig {
+                threshold: Some(0.5),
+            },
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+              Sum(length)
+animal Sum   |                                           Cumulative
+tiger  [41]  |*****************************************       77.4% ← 50%
+shark  [ 8]  |********                                        92.5%
+whale  [ 4]  |****                                           100.0%"#
+        );
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<String, bool, u32>> {
+        let schema = Schemas::three("animal", "stable", "length");
+        DatasetBuilder::new(schema)
+            .add(("whale".to_string(), true, 4u32))
+            .add(("shark".to_string(), false, 4u32))
+            .add(("shark".to_string(), false, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .build()
+    }
+
+    #[test]
+    fn pivottable_3d_breakdown() {
+        let dataset = dataset_3d();
+        let view = dataset.count_breakdown_3rd();
+        let flat = PivotTable::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+        length
+        Sum(Count)
+animal  1  4  5  Total
+shark   4  1         5
+tiger   3  2  6     11
+whale      1         1
+Total   7  4  6     17"#
+        );
+    }
+
+    #[test]
+    fn pivottable_3d_breakdown_view() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = PivotTable::new(&view).render(Render {
+            aggregate: Aggregate::Max,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+        stable
+        Max(length)
+animal  false  true  Total
+shark       4     1      4
+tiger       4     5      5
+whale             4      4
+Total       4     5      5"#
+        );
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<f64, String, f64>> {
+        let schema = Schemas::three("length", "animal", "weight");
+        DatasetBuilder::new(schema)
+            .add((4.0, "whale".to_string(), 9.5))
+            .add((3.5, "whale".to_string(), 8.0))
+            .add((1.0, "shark".to_string(), 2.0))
+            .add((1.5, "shark".to_string(), 2.5))
+            .add((2.0, "shark".to_string(), 3.5))
+            .add((2.0, "tiger".to_string(), 2.0))
+            .add((2.5, "tiger".to_string(), 2.5))
+            .build()
+    }
+
+    #[test]
+    fn scatterplot_3d_view() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = ScatterPlot::new(&view).render(Render {
+            width_hint: 30,
+            height_hint: 10,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+weight
+9.5 │                        ⠈
+    │                    ⠐
+    │
+    │
+    │        ⠄
+  2 │⡀   ⠂   ⡀   ⠐
+    └─────────────────────────
+     1                       4
+     length"#
+        );
+    }
+
+    #[test]
+    fn scatterplot_3d_breakdown_view() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = ScatterPlot::new(&view).render(Render {
+            width_hint: 30,
+            height_hint: 10,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+weight
+9.5 │                        +
+    │                    +
+    │
+    │        *
+  2 │*   *   o   o
+    └─────────────────────────
+     1                       4
+     length
+     animal: * shark  o tiger  + whale"#
+        );
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_2d() -> Dataset<Schema2<f64, String>> {
+        let schema = Schemas::two("length", "species");
+        DatasetBuilder::new(schema)
+            .add((4.3, "setosa".to_string()))
+            .add((4.9, "setosa".to_string()))
+            .add((5.0, "setosa".to_string()))
+            .add((5.1, "setosa".to_string()))
+            .add((5.4, "setosa".to_string()))
+            .add((5.7, "versicolor".to_string()))
+            .add((6.0, "versicolor".to_string()))
+            .add((6.4, "versicolor".to_string()))
+            .add((6.9, "versicolor".to_string()))
+            .add((7.0, "versicolor".to_string()))
+            .add((6.3, "virginica".to_string()))
+            .add((7.7, "virginica".to_string()))
+            .build()
+    }
+
+    #[test]
+    fn stemleaf_2d() {
+        let dataset = dataset_2d();
+        let view = dataset.count();
+        let flat = StemLeaf::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+length
+4 | 3 9
+5 | 0 1 4 7
+6 | 0 3 4 9
+7 | 0 7
+Key: 1 | 2 = 1.2"#
+        );
+    }
+
+    #[test]
+    fn stemleaf_2d_stem_unit() {
+        let dataset = dataset_2d();
+        let view = dataset.count();
+        let flat = StemLeaf::new(&view).render(Render {
+            widget_config: StemLeafConfig {
+                stem_unit: Some(10.0),
+            },
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+length
+0 | 4 4 5 5 5 5 6 6 6 6 7 7
+Key: 1 | 2 = 12"#
+        );
+    }
+
+    #[test]
+    fn stemleaf_2d_breakdown() {
+        let dataset = dataset_2d();
+        let view = dataset.count_breakdown_2nd();
+        let flat = StemLeaf::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+length
+setosa       versicolor  virginica
+   9 3 | 4 |           |
+ 4 1 0 | 5 | 7         |
+       | 6 | 0 4 9     | 3
+       | 7 | 0         | 7
+Key: 1 | 2 = 1.2"#
+        );
+    }
+}
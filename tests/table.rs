@@ -0,0 +1,89 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<String, bool, u32>> {
+        let schema = Schemas::three("animal", "stable", "length");
+        DatasetBuilder::new(schema)
+            .add(("whale".to_string(), true, 4u32))
+            .add(("shark".to_string(), false, 4u32))
+            .add(("shark".to_string(), false, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .build()
+    }
+
+    #[test]
+    fn table_3d() {
+        let dataset = dataset_3d();
+        let flat = Table::dataset(&dataset).render(Render {
+            widget_config: TableConfig {
+                row_limit: Some(5),
+                ..TableConfig::default()
+            },
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal  stable  length
+whale   true    4
+shark   false   4
+shark   false   1
+shark   true    1
+shark   true    1
+... 12 more rows"#
+        );
+    }
+
+    #[test]
+    fn table_3d_view() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = Table::new(&view).render(Render {
+            aggregate: Aggregate::Average,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal  stable  Average(length)
+shark   false               2.5
+shark   true                  1
+tiger   false               2.2
+tiger   true                  5
+whale   true                  4"#
+        );
+    }
+
+    #[test]
+    fn table_3d_breakdown() {
+        let dataset = dataset_3d();
+        let view = dataset.count_breakdown_3rd();
+        let flat = Table::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+                length
+                Sum(Count)
+animal  stable  1  4  5
+shark   false   1  1  
+shark   true    3     
+tiger   false   3  2  
+tiger   true          6
+whale   true       1  "#
+        );
+    }
+}
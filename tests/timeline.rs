@@ -0,0 +1,85 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_4d() -> Dataset<Schema4<String, f64, f64, String>> {
+        let schema = Schemas::four("task", "start", "end", "worker");
+        DatasetBuilder::new(schema)
+            .add(("checkout".to_string(), 0.0, 1.5, "alpha".to_string()))
+            .add(("compile".to_string(), 1.5, 9.0, "alpha".to_string()))
+            .add(("package".to_string(), 9.0, 10.0, "alpha".to_string()))
+            .add(("lint".to_string(), 0.0, 2.0, "beta".to_string()))
+            .add(("unit tests".to_string(), 3.0, 7.5, "beta".to_string()))
+            .add(("docs".to_string(), 1.0, 4.0, "gamma".to_string()))
+            .build()
+    }
+
+    #[test]
+    fn timeline_4d() {
+        let dataset = dataset_4d();
+        let view = dataset.interval_2nd_3rd();
+        let flat = Timeline::new(&view).render(Render {
+            width_hint: 50,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+task          worker  |0      (start, end)      10
+checkout    ┐         |▒▒▒▒▒
+compile     - alpha   |    ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒
+package     ┘         |                       ▒▒▒▒
+lint        - beta    |▒▒▒▒▒▒
+unit tests  ┘         |        ▒▒▒▒▒▒▒▒▒▒▒▒▒
+docs        - gamma   |   ▒▒▒▒▒▒▒▒"#
+        );
+    }
+
+    #[test]
+    fn timeline_4d_show_aggregate() {
+        let dataset = dataset_4d();
+        let view = dataset.interval_2nd_3rd();
+        let flat = Timeline::new(&view).render(Render {
+            aggregate: Aggregate::Max,
+            width_hint: 50,
+            show_aggregate: true,
+            widget_config: TimelineConfig { abbreviate: true },
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+task      worker Max    |0     (start, end)     10
+chec..  ┐               |▒▒▒▒▒
+comp..  - alpha  [7.5]  |    ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒
+pack..  ┘               |                      ▒▒▒
+lint    - beta   [4.5]  |▒▒▒▒▒▒
+unit..  ┘               |       ▒▒▒▒▒▒▒▒▒▒▒▒
+docs    - gamma  [  3]  |  ▒▒▒▒▒▒▒▒▒"#
+        );
+    }
+
+    #[test]
+    fn timeline_3d() {
+        let schema: Schema3<String, u32, u32> = Schemas::three("task", "start", "end");
+        let dataset = DatasetBuilder::new(schema)
+            .add(("checkout".to_string(), 0, 2))
+            .add(("compile".to_string(), 2, 9))
+            .add(("package".to_string(), 9, 10))
+            .add(("compile".to_string(), 12, 14))
+            .build();
+        let view = dataset.interval_2nd_3rd();
+        let flat = Timeline::new(&view).render(Render {
+            width_hint: 30,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+task      |0  (start, end)  14
+checkout  |▒▒▒▒
+compile   |   ▒▒▒▒▒▒▒▒▒▒  ▒▒▒▒
+package   |            ▒▒"#
+        );
+    }
+}
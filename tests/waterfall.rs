@@ -0,0 +1,79 @@
+#[cfg(feature = "primitive_impls")]
+mod tests {
+    use flat::*;
+
+    fn dataset_3d() -> Dataset<Schema3<String, bool, u32>> {
+        let schema = Schemas::three("animal", "stable", "length");
+        DatasetBuilder::new(schema)
+            .add(("whale".to_string(), true, 4u32))
+            .add(("shark".to_string(), false, 4u32))
+            .add(("shark".to_string(), false, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("shark".to_string(), true, 1u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), false, 4u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), true, 5u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .add(("tiger".to_string(), false, 1u32))
+            .build()
+    }
+
+    #[test]
+    fn waterfall_3d() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = Waterfall::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal  |Sum(length)
+whale   |****
+shark   |    ********
+tiger   |            *****************************************
+Total   |*****************************************************"#
+        );
+    }
+
+    #[test]
+    fn waterfall_3d_show_aggregate() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd();
+        let flat = Waterfall::new(&view).render(Render {
+            aggregate: Aggregate::Average,
+            show_aggregate: true,
+            ..Render::default()
+        });
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal Running  |Average(length)
+whale  [  4]    |****
+shark  [5.6]    |    **
+tiger  [9.3]    |      ***
+Total  [9.3]    |*********"#
+        );
+    }
+
+    #[test]
+    fn waterfall_3d_breakdown() {
+        let dataset = dataset_3d();
+        let view = dataset.view_3rd_breakdown_2nd();
+        let flat = Waterfall::new(&view).render(Render::default());
+        assert_eq!(
+            format!("\n{}", flat),
+            r#"
+animal  |Sum(length)
+whale   |****
+shark   |    ********
+tiger   |            *****************************************
+Total   |*****************************************************"#
+        );
+    }
+}